@@ -0,0 +1,134 @@
+//! Frame time and shape count of the tessellation-heavy widgets.
+//!
+//! Every benchmark runs full headless egui frames (layout, painting and tessellation).
+//! The number of shapes emitted per frame is printed and checked against a budget,
+//! so regressions in shape count are caught even when they don't show up in timings.
+//! The budgets leave about 10% headroom over the counts measured when they were set.
+
+use std::f32::consts::TAU;
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use egui::{pos2, vec2, CentralPanel, Context, RawInput, Rect, Ui};
+use epaint::{ClippedShape, Shape};
+
+use egui_extras_xt::compasses::{CompassMarker, LinearCompass, PolarCompass};
+use egui_extras_xt::displays::{DisplayKind, SegmentedDisplayWidget};
+use egui_extras_xt::knobs::AudioKnob;
+
+// ----------------------------------------------------------------------------
+
+/// Number of shapes emitted in a frame, counting the members of `Shape::Vec` one by one.
+fn count_shapes(clipped_shapes: &[ClippedShape]) -> usize {
+    fn count(shape: &Shape) -> usize {
+        match shape {
+            Shape::Noop => 0,
+            Shape::Vec(shapes) => shapes.iter().map(count).sum(),
+            _ => 1,
+        }
+    }
+
+    clipped_shapes
+        .iter()
+        .map(|ClippedShape(_clip_rect, shape)| count(shape))
+        .sum()
+}
+
+fn bench_frame(
+    c: &mut Criterion,
+    name: &str,
+    shape_budget: usize,
+    mut add_contents: impl FnMut(&mut Ui),
+) {
+    let ctx = Context::default();
+
+    let mut run_frame = || {
+        let raw_input = RawInput {
+            screen_rect: Some(Rect::from_min_size(pos2(0.0, 0.0), vec2(1920.0, 1080.0))),
+            ..Default::default()
+        };
+
+        let full_output = ctx.run(raw_input, |ctx| {
+            CentralPanel::default().show(ctx, |ui| add_contents(ui));
+        });
+
+        let shape_count = count_shapes(&full_output.shapes);
+        ctx.tessellate(full_output.shapes);
+        shape_count
+    };
+
+    // The first frame also loads the fonts
+    run_frame();
+
+    let shape_count = run_frame();
+    println!("{name}: {shape_count} shapes per frame");
+    assert!(
+        shape_count <= shape_budget,
+        "{name} emitted {shape_count} shapes, the budget is {shape_budget}"
+    );
+
+    c.bench_function(name, |b| b.iter(|| black_box(run_frame())));
+}
+
+fn markers(count: usize) -> Vec<CompassMarker<'static>> {
+    (0..count)
+        .map(|index| {
+            CompassMarker::new(index as f32 * (TAU / count as f32) * 7.0)
+                .distance((index % 1000) as f32 * 10.0)
+        })
+        .collect()
+}
+
+// ----------------------------------------------------------------------------
+
+fn polar_compass(c: &mut Criterion) {
+    let markers = markers(10_000);
+    let mut value = 0.0;
+
+    bench_frame(c, "PolarCompass, 10k markers", 225_000, |ui| {
+        ui.add(PolarCompass::new(&mut value).markers(&markers));
+    });
+}
+
+fn linear_compass(c: &mut Criterion) {
+    let markers = markers(5_000);
+    let mut value = 0.0;
+
+    bench_frame(c, "LinearCompass, 5k markers, 720° spread", 14_000, |ui| {
+        ui.add(
+            LinearCompass::new(&mut value)
+                .spread(720.0f32.to_radians())
+                .markers(&markers),
+        );
+    });
+}
+
+fn segmented_display(c: &mut Criterion) {
+    let text = "0123456789ABCDEF".repeat(4);
+
+    bench_frame(c, "SegmentedDisplayWidget, 64 digits", 1_400, |ui| {
+        ui.add(SegmentedDisplayWidget::new(DisplayKind::SixteenSegment).push_string(&text));
+    });
+}
+
+fn audio_knobs(c: &mut Criterion) {
+    let mut values = (0..500)
+        .map(|index| index as f32 / 500.0)
+        .collect::<Vec<_>>();
+
+    bench_frame(c, "AudioKnob, 500 knobs", 36_000, |ui| {
+        ui.horizontal_wrapped(|ui| {
+            for value in &mut values {
+                ui.add(AudioKnob::new(value));
+            }
+        });
+    });
+}
+
+criterion_group!(
+    benches,
+    polar_compass,
+    linear_compass,
+    segmented_display,
+    audio_knobs
+);
+criterion_main!(benches);
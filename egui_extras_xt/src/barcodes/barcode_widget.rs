@@ -103,7 +103,7 @@ pub struct BarcodeWidget<'a> {
     vertical_padding: f32,
     label: Option<&'a str>,
     label_height: f32,
-    label_top_margin: f32,
+    label_top_margin: Option<f32>,
     foreground_color: Color32,
     background_color: Color32,
 }
@@ -119,7 +119,7 @@ impl<'a> BarcodeWidget<'a> {
             vertical_padding: 10.0,
             label: None,
             label_height: 20.0,
-            label_top_margin: 4.0,
+            label_top_margin: None,
             foreground_color: Color32::BLACK,
             background_color: Color32::WHITE,
         }
@@ -160,8 +160,9 @@ impl<'a> BarcodeWidget<'a> {
         self
     }
 
+    /// Gap between the bars and the label, defaults to `ui.spacing().item_spacing`.
     pub fn label_top_margin(mut self, label_top_margin: impl Into<f32>) -> Self {
-        self.label_top_margin = label_top_margin.into();
+        self.label_top_margin = Some(label_top_margin.into());
         self
     }
 
@@ -191,7 +192,10 @@ impl<'a> Widget for BarcodeWidget<'a> {
                     + vec2(self.horizontal_padding, self.vertical_padding) * 2.0;
 
                 if self.label.is_some() {
-                    size += vec2(0.0, self.label_height + self.label_top_margin);
+                    let label_top_margin =
+                        self.label_top_margin.unwrap_or(ui.spacing().item_spacing.y);
+
+                    size += vec2(0.0, self.label_height + label_top_margin);
                 }
 
                 size
@@ -1,15 +1,20 @@
 use std::f32::consts::TAU;
 
-use egui::{Align2, FontId, Painter, Rect, Ui};
-use emath::{almost_equal, lerp, Pos2, Rot2, Vec2};
+use egui::{
+    Align2, Context, FontId, Id, Painter, PointerButton, Rect, Response, Ui, WidgetInfo, WidgetText,
+};
+use emath::{almost_equal, lerp, normalized_angle, Pos2, Rot2, Vec2};
 use epaint::{Color32, Shape, Stroke, TextShape};
 
 use itertools::Itertools;
 use strum::{Display, EnumIter};
 
+use crate::XtWidgetState;
+
 // ----------------------------------------------------------------------------
 
 #[derive(Clone, Copy, Debug, PartialEq, Display)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub enum Orientation {
     #[strum(to_string = "Top")]
     Top,
@@ -36,11 +41,22 @@ impl Orientation {
             Self::Custom(angle) => Rot2::from_angle(angle),
         }
     }
+
+    pub(crate) fn mirrored(self, mirrored: bool) -> Self {
+        match (self, mirrored) {
+            (_, false) => self,
+            (Self::Left, true) => Self::Right,
+            (Self::Right, true) => Self::Left,
+            (Self::Top | Self::Bottom, true) => self,
+            (Self::Custom(angle), true) => Self::Custom(TAU / 2.0 - angle),
+        }
+    }
 }
 
 // ----------------------------------------------------------------------------
 
 #[derive(Clone, Copy, Debug, Display, EnumIter, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub enum Winding {
     #[strum(to_string = "Clockwise")]
     Clockwise,
@@ -56,11 +72,20 @@ impl Winding {
             Self::Counterclockwise => -1.0,
         }
     }
+
+    pub(crate) fn mirrored(self, mirrored: bool) -> Self {
+        match (self, mirrored) {
+            (_, false) => self,
+            (Self::Clockwise, true) => Self::Counterclockwise,
+            (Self::Counterclockwise, true) => Self::Clockwise,
+        }
+    }
 }
 
 // ----------------------------------------------------------------------------
 
 #[derive(Clone, Copy, Debug, Display, EnumIter, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub enum WrapMode {
     #[strum(to_string = "None")]
     None,
@@ -72,6 +97,17 @@ pub enum WrapMode {
     Unsigned,
 }
 
+impl WrapMode {
+    /// Wraps `angle` the way values of a widget in this mode are wrapped.
+    pub(crate) fn wrap_angle(self, angle: f32) -> f32 {
+        match self {
+            Self::None => angle,
+            Self::Signed => normalized_angle(angle),
+            Self::Unsigned => normalized_angle_unsigned_excl(angle),
+        }
+    }
+}
+
 // ----------------------------------------------------------------------------
 
 #[non_exhaustive]
@@ -197,33 +233,49 @@ impl WidgetShape {
         //   the window when it tries to render degenerate polygons:
         //     ∃(P1,P2) ∈ Poly (dist(P1,P2) ≈ 0)
 
+        // The thickness of the arc is measured inwards from the shape boundary,
+        // so thick arcs keep an even width along the corners of the shape.
+        let arc_radii = |angle: f32| {
+            let outer_shape_radius =
+                outer_radius * self.eval(angle - (rotation * Vec2::RIGHT).angle());
+
+            let inner_shape_radius = if inner_radius > 0.0 {
+                (outer_shape_radius - (outer_radius - inner_radius)).max(0.0)
+            } else {
+                0.0
+            };
+
+            (inner_shape_radius, outer_shape_radius)
+        };
+
         // HACK: convex_polygon() workaround
         if almost_equal(start_angle, end_angle, 0.001) {
-            let shape_radius = self.eval(start_angle - (rotation * Vec2::RIGHT).angle());
+            let (inner_shape_radius, outer_shape_radius) = arc_radii(start_angle);
 
             ui.painter().add(Shape::line_segment(
                 [
-                    center + Vec2::angled(start_angle) * inner_radius * shape_radius,
-                    center + Vec2::angled(start_angle) * outer_radius * shape_radius,
+                    center + Vec2::angled(start_angle) * inner_shape_radius,
+                    center + Vec2::angled(start_angle) * outer_shape_radius,
                 ],
                 stroke,
             ));
             return;
         }
 
-        let generate_arc_points = |radius| {
-            (0..=Self::RESOLUTION).map(move |i| {
-                let angle = lerp(start_angle..=end_angle, i as f32 / Self::RESOLUTION as f32);
-                let shape_radius = self.eval(angle - (rotation * Vec2::RIGHT).angle());
-                center + Vec2::angled(angle) * radius * shape_radius
-            })
-        };
+        let arc_angles = (0..=Self::RESOLUTION)
+            .map(|i| lerp(start_angle..=end_angle, i as f32 / Self::RESOLUTION as f32));
 
-        // HACK: convex_polygon() workaround
-        let inner_radius = inner_radius.max(0.1);
+        let (inner_arc, outer_arc): (Vec<_>, Vec<_>) = arc_angles
+            .map(|angle| {
+                let (inner_shape_radius, outer_shape_radius) = arc_radii(angle);
 
-        let outer_arc = generate_arc_points(outer_radius).collect::<Vec<_>>();
-        let inner_arc = generate_arc_points(inner_radius).collect::<Vec<_>>();
+                (
+                    // HACK: convex_polygon() workaround
+                    center + Vec2::angled(angle) * inner_shape_radius.max(0.1),
+                    center + Vec2::angled(angle) * outer_shape_radius,
+                )
+            })
+            .unzip();
 
         // https://github.com/emilk/egui/issues/513
         outer_arc
@@ -278,6 +330,25 @@ pub(crate) fn paint_ellipse(
 
 // ----------------------------------------------------------------------------
 
+/// Soft halo extending `width` points outside of a circle of `radius`,
+/// stacked from additive translucent circles.
+pub(crate) fn paint_glow(ui: &Ui, center: Pos2, radius: f32, width: f32, color: Color32) {
+    const GLOW_STEPS: usize = 4;
+
+    for step in (1..=GLOW_STEPS).rev() {
+        ui.painter().circle_filled(
+            center,
+            radius + width * (step as f32 / GLOW_STEPS as f32),
+            color.linear_multiply(1.0 / GLOW_STEPS as f32).additive(),
+        );
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// Default `shift_snap` of the angular widgets, in radians.
+pub const DEFAULT_ANGLE_SHIFT_SNAP: f32 = 15.0 * std::f32::consts::PI / 180.0;
+
 pub(crate) fn snap_wrap_constrain_angle(
     prev_value: f32,
     mut new_value: f32,
@@ -320,6 +391,189 @@ pub(crate) fn snap_wrap_constrain_angle(
     new_value
 }
 
+/// The angle of `angles` nearest to `angle` around the circle, `None` when there are none.
+///
+/// Distances are measured the short way around, so angles on either side of ±π are close.
+pub fn nearest_angle(angles: &[f32], angle: f32) -> Option<f32> {
+    angles.iter().copied().min_by(|a, b| {
+        normalized_angle(a - angle)
+            .abs()
+            .total_cmp(&normalized_angle(b - angle).abs())
+    })
+}
+
+// ----------------------------------------------------------------------------
+
+/// Number of snap detents crossed between two values, negative when moving backwards.
+///
+/// The values must be unwrapped (continuous), otherwise crossing the wrap
+/// boundary would be reported as a full turn worth of detents.
+pub(crate) fn detent_crossings(prev_value: f32, new_value: f32, detent: f32) -> i32 {
+    assert!(detent > 0.0, "non-positive detent sizes are not supported");
+    ((new_value / detent).round() - (prev_value / detent).round()) as i32
+}
+
+// ----------------------------------------------------------------------------
+
+/// Appends a `(time, value)` sample, replacing the last one when it was taken in the same frame.
+pub(crate) fn record_sample(recording: &mut Vec<(f64, f32)>, time: f64, value: f32) {
+    match recording.last_mut() {
+        Some(last_sample) if last_sample.0 == time => last_sample.1 = value,
+        _ => recording.push((time, value)),
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// Moves the `deflection` of a spring-loaded knob back towards zero
+/// by at most `speed` units per second, returning the new deflection.
+pub(crate) fn spring_back(ui: &Ui, deflection: f32, speed: f32) -> f32 {
+    let step = speed * ui.input(|input| input.stable_dt);
+    ui.ctx().request_repaint();
+    deflection - deflection.clamp(-step, step)
+}
+
+/// Keeps the deflection of larger magnitude, for reporting the peak of a spring-loaded knob.
+pub(crate) fn peak_deflection(peak: Option<f32>, deflection: f32) -> f32 {
+    match peak {
+        Some(peak) if peak.abs() >= deflection.abs() => peak,
+        _ => deflection,
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// Wraps the getter-setter of an indeterminate widget, so reads return `placeholder`
+/// until a value is written.
+pub(crate) fn indeterminate_get_set<'a>(
+    mut get_set_value: Box<dyn 'a + FnMut(Option<f32>) -> f32>,
+    placeholder: f32,
+) -> Box<dyn 'a + FnMut(Option<f32>) -> f32> {
+    let mut resolved = false;
+
+    Box::new(move |value| {
+        if value.is_some() {
+            resolved = true;
+        }
+
+        if resolved {
+            get_set_value(value)
+        } else {
+            placeholder
+        }
+    })
+}
+
+// ----------------------------------------------------------------------------
+
+/// Animates the displayed value of the widget `id` towards `target` in `animation_time` seconds,
+/// with the linear progress shaped by `easing`. Retargeting during an animation continues
+/// from the value shown, repaints are requested until the animation settles.
+pub(crate) fn animate_value(
+    ctx: &Context,
+    id: Id,
+    target: f32,
+    animation_time: f32,
+    easing: Option<&dyn Fn(f32) -> f32>,
+) -> f32 {
+    let time = ctx.input(|input| input.time);
+
+    let evaluate = |(from, to, start_time): (f32, f32, f64)| {
+        let progress = if animation_time > 0.0 {
+            (((time - start_time) as f32) / animation_time).clamp(0.0, 1.0)
+        } else {
+            1.0
+        };
+        let eased_progress = easing.map_or(progress, |easing| easing(progress));
+        (
+            lerp(from..=to, eased_progress),
+            from != to && progress < 1.0,
+        )
+    };
+
+    let (value, in_progress) = XtWidgetState::update(ctx, id, |state| {
+        let animation = match state.animation {
+            Some(animation) if animation.1 == target => animation,
+            Some(animation) => (evaluate(animation).0, target, time),
+            None => (target, target, time),
+        };
+        state.animation = Some(animation);
+        evaluate(animation)
+    });
+
+    if in_progress {
+        ctx.request_repaint();
+    }
+
+    value
+}
+
+/// Ends the animation of the widget `id` at `value`, the next animation starts from there.
+pub(crate) fn settle_animation(ctx: &Context, id: Id, value: f32) {
+    XtWidgetState::update(ctx, id, |state| {
+        state.animation = Some((value, value, 0.0));
+    });
+}
+
+// ----------------------------------------------------------------------------
+
+/// Reports the pointer held down on `response` for `duration` seconds as a secondary click,
+/// so the right-click menus of the widgets can be reached on touch screens.
+///
+/// Moving the pointer further than a click would allow cancels the long-press,
+/// the radial progress indicator around the touch point shows how long is left.
+pub(crate) fn long_press_as_secondary_click(ui: &Ui, response: &mut Response, duration: f32) {
+    const LONG_PRESS_SLOP: f32 = 6.0;
+
+    if !response.is_pointer_button_down_on() {
+        return;
+    }
+
+    let (press_origin, elapsed, previous_elapsed, pointer_pos) = ui.input(|input| {
+        let elapsed = input.time - input.pointer.press_start_time().unwrap_or(input.time);
+        (
+            input.pointer.press_origin(),
+            elapsed as f32,
+            (elapsed as f32) - input.unstable_dt,
+            input.pointer.interact_pos(),
+        )
+    });
+
+    let (Some(press_origin), Some(pointer_pos)) = (press_origin, pointer_pos) else {
+        return;
+    };
+
+    if press_origin.distance(pointer_pos) > LONG_PRESS_SLOP || previous_elapsed >= duration {
+        return;
+    }
+
+    if elapsed >= duration {
+        response.clicked[PointerButton::Secondary as usize] = true;
+        return;
+    }
+
+    // Don't flash the indicator on ordinary clicks
+    let progress = elapsed / duration;
+    if progress > 0.2 {
+        let indicator_radius = 24.0;
+        let points = (0..=32)
+            .map(|i| {
+                let angle = lerp(0.0..=(progress * TAU), i as f32 / 32.0) - (TAU / 4.0);
+                press_origin + Vec2::angled(angle) * indicator_radius
+            })
+            .collect_vec();
+
+        ui.painter()
+            .with_clip_rect(ui.ctx().screen_rect())
+            .add(Shape::line(
+                points,
+                ui.visuals().selection.stroke, // TODO: Semantically correct color
+            ));
+    }
+
+    ui.ctx().request_repaint();
+}
+
 // ----------------------------------------------------------------------------
 
 /// Wrap angle to `(0..TAU)` range.
@@ -342,6 +596,9 @@ pub(crate) fn normalized_angle_unsigned_incl(angle: f32) -> f32 {
 
 pub(crate) trait SymLog {
     fn symlog(&self, base: Self) -> Self;
+
+    /// Inverse of [`SymLog::symlog`].
+    fn symexp(&self, base: Self) -> Self;
 }
 
 impl SymLog for f32 {
@@ -352,6 +609,14 @@ impl SymLog for f32 {
             self.abs().log(base) * self.signum()
         }
     }
+
+    fn symexp(&self, base: Self) -> Self {
+        if self.abs() < 1.0 {
+            self * base
+        } else {
+            base.powf(self.abs()) * self.signum()
+        }
+    }
 }
 
 // ----------------------------------------------------------------------------
@@ -396,3 +661,38 @@ impl RotatedText for Painter {
         rect
     }
 }
+
+// ----------------------------------------------------------------------------
+
+/// Reports a slider-like widget to assistive technologies, named after `label`.
+/// The limits and the step are only reported when egui is built with AccessKit.
+pub(crate) fn slider_widget_info(
+    ui: &Ui,
+    response: &Response,
+    label: Option<&WidgetText>,
+    value: f64,
+    min: Option<f64>,
+    max: Option<f64>,
+    step: Option<f64>,
+) {
+    let label = label.map(WidgetText::text).unwrap_or_default();
+    response.widget_info(|| WidgetInfo::slider(value, label));
+
+    #[cfg(feature = "accesskit")]
+    ui.ctx().accesskit_node_builder(response.id, |builder| {
+        if let Some(min) = min {
+            builder.set_min_numeric_value(min);
+        }
+
+        if let Some(max) = max {
+            builder.set_max_numeric_value(max);
+        }
+
+        if let Some(step) = step {
+            builder.set_numeric_value_step(step);
+        }
+    });
+
+    #[cfg(not(feature = "accesskit"))]
+    let _ = (ui, min, max, step);
+}
@@ -1,27 +1,84 @@
 pub struct CompassAxisLabels {
-    pub(crate) inner: [String; 4],
+    pub(crate) inner: Vec<String>,
 }
 
 impl From<[&str; 4]> for CompassAxisLabels {
     fn from(source: [&str; 4]) -> Self {
-        CompassAxisLabels {
-            inner: source.map(String::from),
-        }
+        CompassAxisLabels::from_slice(&source)
+    }
+}
+
+impl From<[&str; 8]> for CompassAxisLabels {
+    fn from(source: [&str; 8]) -> Self {
+        CompassAxisLabels::from_slice(&source)
+    }
+}
+
+impl From<[&str; 16]> for CompassAxisLabels {
+    fn from(source: [&str; 16]) -> Self {
+        CompassAxisLabels::from_slice(&source)
+    }
+}
+
+/// Collects labels from any strings, e.g. translated at runtime.
+///
+/// # Panics
+///
+/// Panics if there aren't 4, 8 or 16 labels, see [`CompassAxisLabels::from_slice`].
+impl<T: ToString> FromIterator<T> for CompassAxisLabels {
+    fn from_iter<I: IntoIterator<Item = T>>(source: I) -> Self {
+        CompassAxisLabels::from_slice(&source.into_iter().collect::<Vec<_>>())
     }
 }
 
 impl CompassAxisLabels {
+    /// Creates labels for the 4 cardinal (N, E, S, W), the 8 cardinal and
+    /// intercardinal (N, NE, E, SE, ...) or the 16 wind (N, NNE, NE, ENE, ...)
+    /// directions, starting from north.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `source` doesn't have 4, 8 or 16 elements.
     pub fn from_slice<T>(source: &[T]) -> CompassAxisLabels
     where
         T: ToString,
     {
+        assert!(
+            matches!(source.len(), 4 | 8 | 16),
+            "compass axis labels need 4, 8 or 16 elements, got {}",
+            source.len()
+        );
+
         CompassAxisLabels {
-            inner: source
-                .iter()
-                .map(T::to_string)
-                .collect::<Vec<_>>()
-                .try_into()
-                .unwrap(),
+            inner: source.iter().map(T::to_string).collect(),
+        }
+    }
+
+    /// Angle between neighbouring labels, in degrees.
+    pub(crate) fn spacing_degrees(&self) -> f32 {
+        360.0 / self.inner.len() as f32
+    }
+
+    /// Angle between major ticks, in degrees. Intercardinal directions are
+    /// majors as soon as they are labelled.
+    pub(crate) fn major_spacing_degrees(&self) -> isize {
+        if self.inner.len() >= 8 {
+            45
+        } else {
+            90
+        }
+    }
+
+    /// Font scale of the label at `index`, intermediate winds are drawn smaller.
+    pub(crate) fn label_scale(&self, index: usize) -> f32 {
+        let cardinal_stride = self.inner.len() / 4;
+
+        if index.is_multiple_of(cardinal_stride) {
+            1.0
+        } else if index.is_multiple_of((cardinal_stride / 2).max(1)) {
+            0.8
+        } else {
+            0.65
         }
     }
 }
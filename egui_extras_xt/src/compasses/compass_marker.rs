@@ -1,12 +1,16 @@
+use std::borrow::Cow;
 use std::f32::consts::TAU;
 
 use ecolor::Hsva;
-use egui::{vec2, Align2, Color32, FontFamily, FontId, Rect, Shape, Stroke, Ui, Vec2};
+use egui::{
+    pos2, vec2, Align2, Color32, FontFamily, FontId, Rect, Shape, Stroke, TextureId, Ui, Vec2,
+};
 use itertools::Itertools;
 use strum::Display;
 
 use crate::common::normalized_angle_unsigned_excl;
 use crate::hash::PearsonHash;
+use crate::xt_colors;
 
 // ----------------------------------------------------------------------------
 
@@ -17,6 +21,9 @@ pub enum DefaultCompassMarkerColor {
     #[strum(to_string = "System")]
     System,
 
+    #[strum(to_string = "Palette")]
+    Palette,
+
     #[strum(to_string = "Fixed")]
     Fixed(Color32),
 
@@ -39,7 +46,12 @@ impl DefaultCompassMarkerColor {
     #[must_use]
     pub(crate) fn color(&self, ui: &Ui, marker: &CompassMarker) -> Color32 {
         match *self {
-            DefaultCompassMarkerColor::System => ui.style().visuals.text_color(),
+            DefaultCompassMarkerColor::System => xt_colors(ui).neutral,
+            DefaultCompassMarkerColor::Palette => {
+                let palette = xt_colors(ui).categorical();
+                let marker_label = marker.label.as_deref().unwrap_or("");
+                palette[marker_label.pearson_hash() as usize % palette.len()]
+            }
             DefaultCompassMarkerColor::Fixed(color) => color,
             DefaultCompassMarkerColor::HsvByAngle {
                 hue_phase,
@@ -55,7 +67,7 @@ impl DefaultCompassMarkerColor {
                 saturation,
                 value,
             } => {
-                let marker_label = marker.label.unwrap_or("");
+                let marker_label = marker.label.as_deref().unwrap_or("");
                 let hue_raw = marker_label.pearson_hash() as f32 / 255.0;
                 let hue = (hue_raw + hue_phase).rem_euclid(1.0);
                 Color32::from(Hsva::new(hue, saturation, value, 1.0))
@@ -71,7 +83,7 @@ pub struct CompassMarker<'a> {
     pub(crate) angle: f32,
     pub(crate) distance: Option<f32>,
     pub(crate) shape: Option<CompassMarkerShape>,
-    pub(crate) label: Option<&'a str>,
+    pub(crate) label: Option<Cow<'a, str>>,
     pub(crate) color: Option<Color32>,
 }
 
@@ -96,8 +108,9 @@ impl<'a> CompassMarker<'a> {
         self
     }
 
-    pub fn label(mut self, label: &'a str) -> Self {
-        self.label = Some(label);
+    /// Accepts borrowed and owned strings, e.g. distances formatted every frame.
+    pub fn label(mut self, label: impl Into<Cow<'a, str>>) -> Self {
+        self.label = Some(label.into());
         self
     }
 
@@ -138,6 +151,11 @@ pub enum CompassMarkerShape {
 
     #[strum(to_string = "Emoji")]
     Emoji(char),
+
+    /// Texture fitted inside the marker, keeping its aspect ratio. When `tint` is set,
+    /// the texture is multiplied by the marker color.
+    #[strum(to_string = "Image")]
+    Image { texture_id: TextureId, tint: bool },
 }
 
 impl CompassMarkerShape {
@@ -251,6 +269,26 @@ impl CompassMarkerShape {
                     fill,
                 );
             }
+            CompassMarkerShape::Image { texture_id, tint } => {
+                // User textures have no known size, those are stretched to a square
+                let texture_size = ui
+                    .ctx()
+                    .tex_manager()
+                    .read()
+                    .meta(texture_id)
+                    .map(|meta| vec2(meta.size[0] as f32, meta.size[1] as f32))
+                    .filter(|size| size.x > 0.0 && size.y > 0.0)
+                    .unwrap_or(Vec2::splat(1.0));
+
+                let scale = (rect.width() / texture_size.x).min(rect.height() / texture_size.y);
+
+                ui.painter().image(
+                    texture_id,
+                    Rect::from_center_size(rect.center(), texture_size * scale),
+                    Rect::from_min_max(pos2(0.0, 0.0), pos2(1.0, 1.0)),
+                    if tint { fill } else { Color32::WHITE },
+                );
+            }
         }
     }
 }
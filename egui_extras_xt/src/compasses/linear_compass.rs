@@ -1,14 +1,22 @@
 use std::f32::consts::TAU;
 
 use ecolor::tint_color_towards;
-use egui::{self, Response, Sense, Ui, Widget};
-use emath::{normalized_angle, pos2, vec2, Align2, Rect, Vec2};
-use epaint::{Color32, FontFamily, FontId, Stroke};
+use egui::{self, show_tooltip_at_pointer, Key, Response, Sense, Ui, Widget, WidgetText};
+use emath::{lerp, normalized_angle, pos2, remap_clamp, vec2, Align2, Pos2, Rect, Vec2};
+use epaint::{Color32, FontFamily, FontId, Shape, Stroke};
 
-use crate::common::{normalized_angle_unsigned_incl, Winding, WrapMode};
+use itertools::Itertools;
+use strum::Display;
+
+use crate::common::{
+    animate_value, long_press_as_secondary_click, normalized_angle_unsigned_excl,
+    normalized_angle_unsigned_incl, record_sample, settle_animation, slider_widget_info, Winding,
+    WrapMode, DEFAULT_ANGLE_SHIFT_SNAP,
+};
 use crate::compasses::{
-    CompassAxisLabels, CompassMarker, CompassMarkerShape, DefaultCompassMarkerColor,
+    CompassAxisLabels, CompassMarker, CompassMarkerShape, DefaultCompassMarkerColor, TickSpec,
 };
+use crate::{xt_colors, XtWidgetState};
 
 // ----------------------------------------------------------------------------
 
@@ -24,29 +32,118 @@ fn set(get_set_value: &mut GetSetValue<'_>, value: f32) {
     (get_set_value)(Some(value));
 }
 
+type Easing<'a> = Box<dyn 'a + Fn(f32) -> f32>;
+
+type ValueFormatter<'a> = Box<dyn 'a + Fn(f32) -> String>;
+
+// ----------------------------------------------------------------------------
+
+/// What moves when the value changes.
+#[derive(Clone, Copy, Debug, Display, PartialEq)]
+pub enum CompassCursorMode {
+    /// The tape pans under a cursor fixed in the middle.
+    #[strum(to_string = "Centered cursor")]
+    CenteredCursor,
+
+    /// The tape shows `start..start + spread` and the cursor slides along it.
+    #[strum(to_string = "Fixed tape")]
+    FixedTape { start: f32 },
+}
+
+impl CompassCursorMode {
+    /// Angle shown in the middle of the widget.
+    fn view_center(&self, value: f32, spread: f32) -> f32 {
+        match *self {
+            CompassCursorMode::CenteredCursor => value,
+            CompassCursorMode::FixedTape { start } => start + spread.abs() / 2.0,
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// Part of the compass moved by a drag.
+#[derive(Clone, Copy, Debug, Display, Eq, PartialEq)]
+pub enum LinearCompassElement {
+    #[strum(to_string = "Value")]
+    Value,
+
+    #[strum(to_string = "Target")]
+    Target,
+}
+
+// ----------------------------------------------------------------------------
+
+/// Response of [`LinearCompass::show`].
+pub struct LinearCompassResponse {
+    pub response: Response,
+
+    /// Angular interval selected by Ctrl-dragging, see [`LinearCompass::selection`].
+    pub selection: Option<(f32, f32)>,
+
+    /// Indices of the markers inside the selected interval.
+    pub selected_markers: Vec<usize>,
+
+    /// Screen position of the center of each marker shape as drawn in this frame,
+    /// nearest to the cursor when repeated, `None` for markers outside of the widget.
+    pub marker_positions: Vec<Option<Pos2>>,
+
+    /// Index of the marker under the pointer.
+    pub hovered_marker: Option<usize>,
+
+    /// Index of the marker clicked in this frame.
+    pub clicked_marker: Option<usize>,
+
+    /// Target angle, moved when the target bug was dragged in this frame,
+    /// see [`LinearCompass::target_interactive`].
+    pub target: Option<f32>,
+
+    /// Part of the compass dragged in this frame, `None` when selecting an interval.
+    pub dragged_element: Option<LinearCompassElement>,
+}
+
 // ----------------------------------------------------------------------------
 
 #[must_use = "You should put this widget in an ui with `ui.add(widget);`"]
 pub struct LinearCompass<'a> {
     get_set_value: GetSetValue<'a>,
     interactive: bool,
+    label: Option<WidgetText>,
     wrap: WrapMode,
     winding: Winding,
+    mirrored: bool,
     width: f32,
-    height: f32,
+    height: Option<f32>,
     spread: f32,
+    cursor_mode: CompassCursorMode,
+    drag_acceleration: Option<f32>,
+    click_to_set: bool,
     axis_labels: CompassAxisLabels,
     snap: Option<f32>,
     shift_snap: Option<f32>,
     min: Option<f32>,
     max: Option<f32>,
     animated: bool,
+    animation_time: Option<f32>,
+    easing: Option<Easing<'a>>,
     show_cursor: bool,
+    value_formatter: Option<ValueFormatter<'a>>,
+    ticks: TickSpec<'a>,
     show_ticks: bool,
     show_axes: bool,
+    show_overflow_markers: bool,
+    hover_highlight: bool,
+    cluster_markers: Option<f32>,
+    target: Option<f32>,
+    target_color: Option<Color32>,
+    target_interactive: bool,
     markers: &'a [CompassMarker<'a>],
+    selection: Option<&'a mut Option<(f32, f32)>>,
     default_marker_color: DefaultCompassMarkerColor,
     default_marker_shape: CompassMarkerShape,
+    long_press_duration: Option<f32>,
+    recording: Option<&'a mut Vec<(f64, f32)>>,
+    reset_state: bool,
 }
 
 impl<'a> LinearCompass<'a> {
@@ -63,35 +160,58 @@ impl<'a> LinearCompass<'a> {
         Self {
             get_set_value: Box::new(get_set_value),
             interactive: true,
+            label: None,
             wrap: WrapMode::Unsigned,
             winding: Winding::Clockwise,
+            mirrored: false,
             width: 512.0,
-            height: 48.0,
+            height: None,
             spread: 180.0f32.to_radians(),
+            cursor_mode: CompassCursorMode::CenteredCursor,
+            drag_acceleration: None,
+            click_to_set: false,
             axis_labels: ["N", "E", "S", "W"].into(),
             snap: None,
-            shift_snap: Some(10.0f32.to_radians()),
+            shift_snap: Some(DEFAULT_ANGLE_SHIFT_SNAP),
             min: None,
             max: None,
             animated: false,
+            animation_time: None,
+            easing: None,
             show_cursor: true,
+            value_formatter: None,
+            ticks: TickSpec::default(),
             show_ticks: true,
             show_axes: true,
+            show_overflow_markers: false,
+            hover_highlight: false,
+            cluster_markers: None,
+            target: None,
+            target_color: None,
+            target_interactive: false,
             markers: &[],
-            default_marker_color: DefaultCompassMarkerColor::HsvByAngle {
-                hue_phase: 0.0,
-                saturation: 1.0,
-                value: 1.0,
-            },
+            selection: None,
+            default_marker_color: DefaultCompassMarkerColor::Palette,
             default_marker_shape: CompassMarkerShape::Square,
+            long_press_duration: Some(0.6),
+            recording: None,
+            reset_state: false,
         }
     }
 
+    /// Read-only when `false`: only hovering is sensed and the widget is painted with
+    /// the noninteractive visuals. Defaults to `true`.
     pub fn interactive(mut self, interactive: bool) -> Self {
         self.interactive = interactive;
         self
     }
 
+    /// Name of the widget announced by screen readers, e.g. "Heading".
+    pub fn label(mut self, label: impl Into<WidgetText>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
     pub fn wrap(mut self, wrap: WrapMode) -> Self {
         self.wrap = wrap;
         self
@@ -102,13 +222,20 @@ impl<'a> LinearCompass<'a> {
         self
     }
 
+    /// Mirrors the widget horizontally, e.g. for right-to-left layouts.
+    pub fn mirrored(mut self, mirrored: bool) -> Self {
+        self.mirrored = mirrored;
+        self
+    }
+
     pub fn width(mut self, width: impl Into<f32>) -> Self {
         self.width = width.into();
         self
     }
 
+    /// Defaults to 2.4 times the height of `ui.spacing().interact_size`.
     pub fn height(mut self, height: impl Into<f32>) -> Self {
-        self.height = height.into();
+        self.height = Some(height.into());
         self
     }
 
@@ -117,6 +244,27 @@ impl<'a> LinearCompass<'a> {
         self
     }
 
+    /// Whether the tape pans under a centered cursor or the cursor slides along a fixed
+    /// tape. Dragging a fixed tape moves the cursor to the pointer.
+    pub fn cursor_mode(mut self, cursor_mode: CompassCursorMode) -> Self {
+        self.cursor_mode = cursor_mode;
+        self
+    }
+
+    /// Pans up to `drag_acceleration` times faster while dragging in the outer thirds
+    /// of the strip, growing towards the edges. Holding Shift keeps the normal speed.
+    pub fn drag_acceleration(mut self, drag_acceleration: Option<f32>) -> Self {
+        self.drag_acceleration = drag_acceleration;
+        self
+    }
+
+    /// Double-clicking the strip sets the value to the angle under the pointer,
+    /// animated when `animated` is set. Single clicks leave the value alone.
+    pub fn click_to_set(mut self, click_to_set: bool) -> Self {
+        self.click_to_set = click_to_set;
+        self
+    }
+
     pub fn axis_labels(mut self, axis_labels: CompassAxisLabels) -> Self {
         self.axis_labels = axis_labels;
         self
@@ -132,13 +280,17 @@ impl<'a> LinearCompass<'a> {
         self
     }
 
-    pub fn snap(mut self, snap: Option<f32>) -> Self {
-        self.snap = snap;
+    /// Snaps the value when a drag is released. Also the step of scroll wheel notches
+    /// and arrow key presses, which default to 1° without snapping.
+    pub fn snap(mut self, snap: impl Into<Option<f32>>) -> Self {
+        self.snap = snap.into();
         self
     }
 
-    pub fn shift_snap(mut self, shift_snap: Option<f32>) -> Self {
-        self.shift_snap = shift_snap;
+    /// Snaps the angle while Shift is held, in radians. Also the step of Shift + scroll
+    /// and arrow keys. Defaults to 15°, pass `None` to disable it.
+    pub fn shift_snap(mut self, shift_snap: impl Into<Option<f32>>) -> Self {
+        self.shift_snap = shift_snap.into();
         self
     }
 
@@ -147,11 +299,38 @@ impl<'a> LinearCompass<'a> {
         self
     }
 
+    /// Duration of the animations in seconds, defaults to the animation time of the style.
+    pub fn animation_time(mut self, animation_time: impl Into<f32>) -> Self {
+        self.animation_time = Some(animation_time.into());
+        self
+    }
+
+    /// Shapes the animations, mapping their linear progress to the eased progress,
+    /// both within `0.0..=1.0`.
+    pub fn easing(mut self, easing: impl 'a + Fn(f32) -> f32) -> Self {
+        self.easing = Some(Box::new(easing));
+        self
+    }
+
     pub fn show_cursor(mut self, show_cursor: bool) -> Self {
         self.show_cursor = show_cursor;
         self
     }
 
+    /// Formats the value shown on the cursor and in marker tooltips, e.g. as mils or as
+    /// a zero-padded bearing. The value is wrapped according to [`Self::wrap`].
+    pub fn value_formatter(mut self, value_formatter: impl 'a + Fn(f32) -> String) -> Self {
+        self.value_formatter = Some(Box::new(value_formatter));
+        self
+    }
+
+    /// Tick spacing of the scale. Its formatter is used for readouts unless
+    /// [`Self::value_formatter`] is set.
+    pub fn ticks(mut self, ticks: TickSpec<'a>) -> Self {
+        self.ticks = ticks;
+        self
+    }
+
     pub fn show_ticks(mut self, show_ticks: bool) -> Self {
         self.show_ticks = show_ticks;
         self
@@ -162,11 +341,60 @@ impl<'a> LinearCompass<'a> {
         self
     }
 
+    /// Points at markers outside of the visible spread with chevrons on the nearer edge,
+    /// counting them when several markers share an edge.
+    pub fn show_overflow_markers(mut self, show_overflow_markers: bool) -> Self {
+        self.show_overflow_markers = show_overflow_markers;
+        self
+    }
+
+    /// Highlights the tick or axis label nearest to the pointer while hovering.
+    pub fn hover_highlight(mut self, hover_highlight: bool) -> Self {
+        self.hover_highlight = hover_highlight;
+        self
+    }
+
+    /// Merges markers closer than `cluster_markers` points on screen into a circle
+    /// showing their count, colored like the first of them. Hovering a cluster lists
+    /// its markers.
+    pub fn cluster_markers(mut self, cluster_markers: Option<f32>) -> Self {
+        self.cluster_markers = cluster_markers;
+        self
+    }
+
+    /// Selected target angle, shown as a hollow notch ("heading bug") on the tape.
+    pub fn target(mut self, target: Option<f32>) -> Self {
+        self.target = target;
+        self
+    }
+
+    /// Defaults to the info color of [`crate::XtColors`].
+    pub fn target_color(mut self, target_color: Color32) -> Self {
+        self.target_color = Some(target_color);
+        self
+    }
+
+    /// Lets the target bug be dragged, snapped like the value. The moved target is
+    /// returned in [`LinearCompassResponse::target`], `response.changed()` keeps
+    /// reporting changes of the value only.
+    pub fn target_interactive(mut self, target_interactive: bool) -> Self {
+        self.target_interactive = target_interactive;
+        self
+    }
+
     pub fn markers(mut self, markers: &'a [CompassMarker]) -> Self {
         self.markers = markers;
         self
     }
 
+    /// Lets the application own the interval selected by Ctrl-dragging (Cmd on macOS),
+    /// otherwise it is kept in the widget state until cleared by a click or Escape.
+    /// Shift is left to `shift_snap` while dragging the compass.
+    pub fn selection(mut self, selection: Option<&'a mut Option<(f32, f32)>>) -> Self {
+        self.selection = selection;
+        self
+    }
+
     pub fn default_marker_color(mut self, default_marker_color: DefaultCompassMarkerColor) -> Self {
         self.default_marker_color = default_marker_color;
         self
@@ -176,11 +404,41 @@ impl<'a> LinearCompass<'a> {
         self.default_marker_shape = default_marker_shape;
         self
     }
+
+    /// Seconds the pointer has to be held down to act as a right-click on touch screens,
+    /// `None` disables long-presses.
+    pub fn long_press_duration(mut self, long_press_duration: Option<f32>) -> Self {
+        self.long_press_duration = long_press_duration;
+        self
+    }
+
+    /// Appends a `(time, value)` sample to `recording` whenever the user changes the value.
+    /// Changes made within the same frame are coalesced into one sample,
+    /// thinning or compressing the recording is left to the caller.
+    pub fn record_into(mut self, recording: &'a mut Vec<(f64, f32)>) -> Self {
+        self.recording = Some(recording);
+        self
+    }
+
+    /// Forgets the transient state of the widget, see [`XtWidgetState`].
+    pub fn reset_state(mut self) -> Self {
+        self.reset_state = true;
+        self
+    }
 }
 
 impl<'a> Widget for LinearCompass<'a> {
-    fn ui(mut self, ui: &mut Ui) -> Response {
-        let desired_size = egui::vec2(self.width, self.height);
+    fn ui(self, ui: &mut Ui) -> Response {
+        self.show(ui).response
+    }
+}
+
+impl<'a> LinearCompass<'a> {
+    /// Adds the compass to `ui`, like `ui.add(widget)` but also returning the selection.
+    pub fn show(mut self, ui: &mut Ui) -> LinearCompassResponse {
+        let height = self.height.unwrap_or(ui.spacing().interact_size.y * 2.4);
+
+        let desired_size = egui::vec2(self.width, height);
 
         let (rect, mut response) = ui.allocate_exact_size(
             desired_size,
@@ -191,6 +449,8 @@ impl<'a> Widget for LinearCompass<'a> {
             },
         );
 
+        let winding = self.winding.mirrored(self.mirrored);
+
         let mut child_ui = ui.child_ui(rect, *ui.layout());
         child_ui.set_clip_rect(child_ui.clip_rect().intersect(rect));
 
@@ -216,56 +476,402 @@ impl<'a> Widget for LinearCompass<'a> {
             value
         };
 
-        if response.dragged() {
-            let new_value = get(&mut self.get_set_value)
-                - response.drag_delta().x / rect.width() * (self.spread * self.winding.to_float());
-            set(&mut self.get_set_value, constrain_value(new_value));
-            response.mark_changed();
+        if self.reset_state {
+            XtWidgetState::reset(ui.ctx(), response.id);
         }
 
-        if response.drag_released() {
-            if self.animated {
-                child_ui.ctx().clear_animations();
-                child_ui.ctx().animate_value_with_time(
-                    response.id,
-                    get(&mut self.get_set_value),
-                    0.1,
-                );
+        if let (true, Some(long_press_duration)) = (self.interactive, self.long_press_duration) {
+            long_press_as_secondary_click(ui, &mut response, long_press_duration);
+        }
+
+        let value_before_interaction = get(&mut self.get_set_value);
+
+        let mut state = XtWidgetState::load(ui.ctx(), response.id);
+
+        let mut selection = match &self.selection {
+            Some(selection) => **selection,
+            None => state.interval_selection,
+        };
+
+        let mut target = self.target;
+        let mut dragged_element = None;
+
+        let screen_x_to_angle = |x: f32| {
+            self.cursor_mode
+                .view_center(value_before_interaction, self.spread)
+                - (rect.center().x - x) * (self.spread * winding.to_float()) / rect.width()
+        };
+
+        // The bug is hit on any of its copies, as they repeat every full turn
+        let target_bug_at = |pointer_pos: Pos2| {
+            target.is_some_and(|target| {
+                let pointer_distance = normalized_angle(screen_x_to_angle(pointer_pos.x) - target)
+                    .abs()
+                    * (rect.width() / self.spread.abs());
+
+                rect.contains(pointer_pos) && pointer_distance <= height * 0.15
+            })
+        };
+
+        if response.drag_started() {
+            if ui.input(|input| input.modifiers.command) {
+                state.interval_selecting = true;
+            } else if self.target_interactive
+                && ui
+                    .input(|input| input.pointer.press_origin())
+                    .is_some_and(target_bug_at)
+            {
+                state.target_dragging = true;
             }
+        }
 
-            if let Some(snap_angle) = if child_ui.input(|input| input.modifiers.shift_only()) {
-                self.shift_snap
-            } else {
-                self.snap
-            } {
-                assert!(
-                    snap_angle > 0.0,
-                    "non-positive snap angles are not supported"
-                );
-                let new_value = (get(&mut self.get_set_value) / snap_angle).round() * snap_angle;
+        if state.interval_selecting {
+            // The selection is stored as angles, so it stays anchored while panning
+
+            if response.dragged() {
+                let start =
+                    screen_x_to_angle(ui.input(|input| input.pointer.press_origin().unwrap()).x);
+                let end = screen_x_to_angle(response.interact_pointer_pos().unwrap().x);
+                selection = Some((start.min(end), start.max(end)));
+            }
+
+            if response.drag_released() {
+                state.interval_selecting = false;
+            }
+        } else if state.target_dragging {
+            if response.dragged() {
+                let pointer_x = response.interact_pointer_pos().unwrap().x;
+                let mut new_target = screen_x_to_angle(pointer_x.clamp(rect.left(), rect.right()));
+
+                if let Some(snap_angle) = if ui.input(|input| input.modifiers.shift_only()) {
+                    self.shift_snap
+                } else {
+                    self.snap
+                } {
+                    new_target = (new_target / snap_angle).round() * snap_angle;
+                }
+
+                target = Some(self.wrap.wrap_angle(new_target));
+                dragged_element = Some(LinearCompassElement::Target);
+            }
+
+            if response.drag_released() {
+                state.target_dragging = false;
+            }
+        } else {
+            if response.dragged() {
+                dragged_element = Some(LinearCompassElement::Value);
+            }
+
+            if let (true, CompassCursorMode::FixedTape { .. }) =
+                (response.dragged(), self.cursor_mode)
+            {
+                let pointer_x = response.interact_pointer_pos().unwrap().x;
+                let new_value = screen_x_to_angle(pointer_x.clamp(rect.left(), rect.right()));
+                set(&mut self.get_set_value, constrain_value(new_value));
+                response.mark_changed();
+            } else if response.dragged() {
+                let acceleration = match self.drag_acceleration {
+                    Some(drag_acceleration) if !ui.input(|input| input.modifiers.shift) => {
+                        let pointer_x = response.interact_pointer_pos().unwrap().x;
+                        let center_distance =
+                            (pointer_x - rect.center().x).abs() / (rect.width() / 2.0);
+                        lerp(
+                            1.0..=drag_acceleration,
+                            remap_clamp(center_distance, (1.0 / 3.0)..=1.0, 0.0..=1.0),
+                        )
+                    }
+                    _ => 1.0,
+                };
+
+                let new_value = get(&mut self.get_set_value)
+                    - response.drag_delta().x / rect.width()
+                        * (self.spread * winding.to_float())
+                        * acceleration;
                 set(&mut self.get_set_value, constrain_value(new_value));
                 response.mark_changed();
             }
+
+            if response.drag_released() {
+                if self.animated {
+                    // The value was shown without animation while dragging
+                    settle_animation(child_ui.ctx(), response.id, get(&mut self.get_set_value));
+                }
+
+                if let Some(snap_angle) = if child_ui.input(|input| input.modifiers.shift_only()) {
+                    self.shift_snap
+                } else {
+                    self.snap
+                } {
+                    assert!(
+                        snap_angle > 0.0,
+                        "non-positive snap angles are not supported"
+                    );
+                    let new_value =
+                        (get(&mut self.get_set_value) / snap_angle).round() * snap_angle;
+                    set(&mut self.get_set_value, constrain_value(new_value));
+                    response.mark_changed();
+                }
+            }
+
+            if self.interactive {
+                if response.clicked() {
+                    response.request_focus();
+                }
+
+                if self.click_to_set && response.double_clicked() {
+                    let pointer_x = response.interact_pointer_pos().unwrap().x;
+                    let mut new_value = screen_x_to_angle(pointer_x);
+
+                    if let Some(snap_angle) = self.snap {
+                        new_value = (new_value / snap_angle).round() * snap_angle;
+                    }
+
+                    set(&mut self.get_set_value, constrain_value(new_value));
+                    response.mark_changed();
+                }
+
+                let nudge_step = if ui.input(|input| input.modifiers.shift_only()) {
+                    self.shift_snap
+                } else {
+                    self.snap
+                }
+                .unwrap_or(1.0f32.to_radians());
+
+                // Wheel notches are reported as 50 points, smooth scrolling is accumulated
+                // until it adds up to a whole notch.
+                // The scroll is consumed, so an enclosing scroll area stays put.
+                let scroll_steps = if response.hovered() {
+                    let scroll_delta =
+                        ui.input_mut(|input| std::mem::take(&mut input.scroll_delta));
+                    let scroll_distance = state.scroll_accumulator.unwrap_or(0.0) + scroll_delta.y
+                        - scroll_delta.x * winding.to_float();
+
+                    let scroll_steps = (scroll_distance / 50.0).trunc();
+                    state.scroll_accumulator = Some(scroll_distance - scroll_steps * 50.0);
+                    scroll_steps
+                } else {
+                    state.scroll_accumulator = None;
+                    0.0
+                };
+
+                let key_steps = if response.has_focus() {
+                    ui.input(|input| {
+                        (input.num_presses(Key::ArrowRight) as f32)
+                            - (input.num_presses(Key::ArrowLeft) as f32)
+                    })
+                } else {
+                    0.0
+                };
+
+                let nudge_steps = scroll_steps + key_steps;
+
+                if nudge_steps != 0.0 {
+                    // Nudges land on multiples of the step, like snapped drags
+                    let value = get(&mut self.get_set_value);
+                    let new_value = ((value / nudge_step).round() + nudge_steps) * nudge_step;
+                    set(&mut self.get_set_value, constrain_value(new_value));
+                    response.mark_changed();
+                }
+            }
+        }
+
+        if response.clicked()
+            || (response.hovered() && ui.input(|input| input.key_pressed(Key::Escape)))
+        {
+            selection = None;
         }
 
+        match &mut self.selection {
+            Some(app_selection) => **app_selection = selection,
+            None => state.interval_selection = selection,
+        }
+
+        state.store(ui.ctx(), response.id);
+
+        if let Some(recording) = &mut self.recording {
+            let value = get(&mut self.get_set_value);
+            if value != value_before_interaction {
+                record_sample(recording, ui.input(|input| input.time), value);
+            }
+        }
+
+        {
+            let value = get(&mut self.get_set_value);
+            slider_widget_info(
+                ui,
+                &response,
+                self.label.as_ref(),
+                value.to_degrees() as f64,
+                self.min.map(|min| min.to_degrees() as f64),
+                self.max.map(|max| max.to_degrees() as f64),
+                self.snap.map(|snap| snap.to_degrees() as f64),
+            );
+        }
+
+        let mut marker_positions = vec![None; self.markers.len()];
+        let mut hovered_marker = None;
+        let mut clicked_marker = None;
+
         if child_ui.is_rect_visible(rect) {
             let visuals = *child_ui.style().interact(&response);
 
             let value = if self.animated && !response.dragged() {
-                child_ui.ctx().animate_value_with_time(
+                animate_value(
+                    child_ui.ctx(),
                     response.id,
                     get(&mut self.get_set_value),
-                    child_ui.style().animation_time,
+                    self.animation_time
+                        .unwrap_or(child_ui.style().animation_time),
+                    self.easing.as_deref(),
                 )
             } else {
                 get(&mut self.get_set_value)
             };
 
+            let view_center = self.cursor_mode.view_center(value, self.spread);
+
             let map_angle_to_screen = |angle: f32| {
                 rect.center().x
-                    - (value - angle) * (rect.width() / (self.spread * self.winding.to_float()))
+                    - (view_center - angle) * (rect.width() / (self.spread * winding.to_float()))
             };
 
+            marker_positions = self
+                .markers
+                .iter()
+                .map(|marker| {
+                    let marker_x = map_angle_to_screen(
+                        view_center + normalized_angle(marker.angle - view_center),
+                    );
+                    (rect.left()..=rect.right())
+                        .contains(&marker_x)
+                        .then(|| pos2(marker_x, rect.top() + height * 0.375))
+                })
+                .collect();
+
+            // Clustered after mapping to the screen, so clusters split up again when panning
+            let marker_clusters = self.cluster_markers.map(|threshold| {
+                let start_tau = ((view_center - (self.spread.abs() / 2.0)) / TAU).floor() as isize;
+                let end_tau = ((view_center + (self.spread.abs() / 2.0)) / TAU).ceil() as isize;
+
+                let marker_copies = (start_tau..=end_tau)
+                    .cartesian_product(self.markers.iter().enumerate())
+                    .map(|(tau, (marker_index, marker))| {
+                        let angle = (tau as f32 * TAU) + marker.angle;
+                        (map_angle_to_screen(angle), angle, marker_index)
+                    })
+                    .sorted_by(|(a, _, _), (b, _, _)| a.total_cmp(b));
+
+                // (screen position of the first marker, angles and indices of the markers)
+                let mut clusters: Vec<(f32, Vec<(f32, usize)>)> = Vec::new();
+
+                for (marker_x, angle, marker_index) in marker_copies {
+                    match clusters.last_mut() {
+                        Some((first_x, members)) if marker_x - *first_x <= threshold => {
+                            members.push((angle, marker_index));
+                        }
+                        _ => clusters.push((marker_x, vec![(angle, marker_index)])),
+                    }
+                }
+
+                clusters
+                    .into_iter()
+                    .map(|(_, members)| {
+                        let angle = members.iter().map(|(angle, _)| angle).sum::<f32>()
+                            / members.len() as f32;
+                        (
+                            angle,
+                            members.into_iter().map(|(_, index)| index).collect_vec(),
+                        )
+                    })
+                    .collect_vec()
+            });
+
+            let cluster_at = |pointer_pos: Pos2| {
+                marker_clusters.as_ref()?.iter().find(|(angle, members)| {
+                    let cluster_center =
+                        pos2(map_angle_to_screen(*angle), rect.top() + height * 0.375);
+
+                    members.len() > 1
+                        && Rect::from_center_size(cluster_center, Vec2::splat(height * 0.25))
+                            .contains(pointer_pos)
+                })
+            };
+
+            // Hit test every copy of the markers, they repeat every full turn
+            let marker_at = |pointer_pos: Pos2| {
+                let start_tau = ((view_center - (self.spread.abs() / 2.0)) / TAU).floor() as isize;
+                let end_tau = ((view_center + (self.spread.abs() / 2.0)) / TAU).ceil() as isize;
+
+                (start_tau..=end_tau)
+                    .cartesian_product(self.markers.iter().enumerate())
+                    .filter_map(|(tau, (marker_index, marker))| {
+                        let marker_center = pos2(
+                            map_angle_to_screen((tau as f32 * TAU) + marker.angle),
+                            rect.top() + height * 0.375,
+                        );
+                        let marker_rect =
+                            Rect::from_center_size(marker_center, Vec2::splat(height * 0.25));
+
+                        marker_rect
+                            .contains(pointer_pos)
+                            .then(|| (marker_index, marker_center.distance(pointer_pos)))
+                    })
+                    .min_by(|(_, a), (_, b)| a.total_cmp(b))
+                    .map(|(marker_index, _)| marker_index)
+            };
+
+            // Clusters report their first marker
+            let hovered_cluster = response.hover_pos().and_then(cluster_at);
+
+            let marker_or_cluster_at = |pointer_pos: Pos2| match cluster_at(pointer_pos) {
+                Some((_, members)) => Some(members[0]),
+                None => marker_at(pointer_pos),
+            };
+
+            hovered_marker = response.hover_pos().and_then(marker_or_cluster_at);
+
+            if response.clicked() {
+                clicked_marker = response
+                    .interact_pointer_pos()
+                    .and_then(marker_or_cluster_at);
+            }
+
+            if let (Some((_, members)), false) = (hovered_cluster, response.dragged()) {
+                show_tooltip_at_pointer(ui.ctx(), response.id.with("marker_tooltip"), |ui| {
+                    for &marker_index in members {
+                        let marker = &self.markers[marker_index];
+                        let marker_angle = self.wrap.wrap_angle(marker.angle);
+
+                        let angle_text = match &self.value_formatter {
+                            Some(value_formatter) => value_formatter(marker_angle),
+                            None => self.ticks.format(ui.ctx(), marker_angle),
+                        };
+
+                        match marker.label.as_deref() {
+                            Some(label) => ui.label(format!("{label} ({angle_text})")),
+                            None => ui.label(angle_text),
+                        };
+                    }
+                });
+            } else if let (Some(marker_index), false) = (hovered_marker, response.dragged()) {
+                let marker = &self.markers[marker_index];
+
+                let marker_angle = self.wrap.wrap_angle(marker.angle);
+
+                let angle_text = match &self.value_formatter {
+                    Some(value_formatter) => value_formatter(marker_angle),
+                    None => self.ticks.format(ui.ctx(), marker_angle),
+                };
+
+                show_tooltip_at_pointer(ui.ctx(), response.id.with("marker_tooltip"), |ui| {
+                    if let Some(label) = marker.label.as_deref() {
+                        ui.label(label);
+                    }
+                    ui.label(angle_text);
+                });
+            }
+
             // Draw the widget background without clipping to avoid truncated outline strokes
             ui.painter().rect(
                 rect,
@@ -274,6 +880,38 @@ impl<'a> Widget for LinearCompass<'a> {
                 ui.style().visuals.noninteractive().fg_stroke,
             );
 
+            if let Some((selection_start, selection_end)) = selection {
+                let start_tau = ((view_center - (self.spread.abs() / 2.0) - selection_end) / TAU)
+                    .floor() as isize;
+                let end_tau = ((view_center + (self.spread.abs() / 2.0) - selection_start) / TAU)
+                    .ceil() as isize;
+
+                for tau in start_tau..=end_tau {
+                    let selection_rect = Rect::from_two_pos(
+                        pos2(
+                            map_angle_to_screen((tau as f32 * TAU) + selection_start),
+                            rect.top(),
+                        ),
+                        pos2(
+                            map_angle_to_screen((tau as f32 * TAU) + selection_end),
+                            rect.bottom(),
+                        ),
+                    );
+
+                    child_ui.painter().rect(
+                        selection_rect,
+                        0.0,
+                        child_ui
+                            .style()
+                            .visuals
+                            .selection
+                            .bg_fill
+                            .linear_multiply(0.25), // TODO: Semantically correct color
+                        child_ui.style().visuals.selection.stroke, // TODO: Semantically correct color
+                    );
+                }
+            }
+
             {
                 let paint_marker = |child_ui: &mut Ui,
                                     angle: f32,
@@ -282,12 +920,27 @@ impl<'a> Widget for LinearCompass<'a> {
                                     shape: CompassMarkerShape,
                                     fill: Color32,
                                     stroke: Stroke| {
-                    // Early exit when the marker is outside of the bounds of the widget,
-                    // plus some safety margin to avoid markers abruptly popping in from the sides.
+                    let marker_x = map_angle_to_screen(angle);
+
+                    let label_galley = label.map(|label| {
+                        child_ui.painter().layout_no_wrap(
+                            label.to_owned(),
+                            FontId::new(height / 4.0, FontFamily::Proportional),
+                            text_color,
+                        )
+                    });
+
+                    // Skip markers entirely outside of the widget, rather than leaving them
+                    // to the clip rect, as there may be hundreds of them
                     {
-                        let safety_margin = rect.width() / 4.0;
-                        if !((rect.left() - safety_margin)..=(rect.right() + safety_margin))
-                            .contains(&map_angle_to_screen(angle))
+                        let half_width = label_galley
+                            .as_ref()
+                            .map_or(0.0, |galley| galley.size().x)
+                            .max(height * 0.25)
+                            / 2.0;
+
+                        if marker_x + half_width < rect.left()
+                            || marker_x - half_width > rect.right()
                         {
                             return;
                         }
@@ -296,39 +949,28 @@ impl<'a> Widget for LinearCompass<'a> {
                     // Draw marker shape
                     {
                         let marker_rect = {
-                            let center =
-                                pos2(map_angle_to_screen(angle), rect.top() + self.height * 0.375);
-                            Rect::from_center_size(center, Vec2::splat(self.height * 0.25))
+                            let center = pos2(marker_x, rect.top() + height * 0.375);
+                            Rect::from_center_size(center, Vec2::splat(height * 0.25))
                         };
 
                         shape.paint(child_ui, marker_rect, fill, stroke);
                     }
 
                     // Draw marker text label
-                    {
-                        let label_center =
-                            pos2(map_angle_to_screen(angle), rect.top() + self.height * 0.125);
+                    if let Some(label_galley) = label_galley {
+                        let label_center = pos2(marker_x, rect.top() + height * 0.125);
+                        let label_rect = Align2::CENTER_CENTER
+                            .anchor_rect(Rect::from_min_size(label_center, label_galley.size()));
 
-                        if let Some(label) = label {
-                            child_ui.painter().text(
-                                label_center,
-                                Align2::CENTER_CENTER,
-                                label,
-                                FontId::new(self.height / 4.0, FontFamily::Proportional),
-                                text_color,
-                            );
-                        }
+                        child_ui.painter().galley(label_rect.min, label_galley);
                     }
                 };
 
-                let start_tau = ((value - (self.spread.abs() / 2.0)) / TAU).floor() as isize;
-                let end_tau = ((value + (self.spread.abs() / 2.0)) / TAU).ceil() as isize;
-
-                for tau in start_tau..=end_tau {
-                    for marker in self.markers.iter() {
+                let paint_compass_marker =
+                    |child_ui: &mut Ui, angle: f32, marker: &CompassMarker| {
                         let marker_color = marker
                             .color
-                            .unwrap_or_else(|| self.default_marker_color.color(&child_ui, marker));
+                            .unwrap_or_else(|| self.default_marker_color.color(child_ui, marker));
 
                         let marker_stroke = {
                             let stroke_color = tint_color_towards(
@@ -341,22 +983,207 @@ impl<'a> Widget for LinearCompass<'a> {
                         let marker_shape = marker.shape.unwrap_or(self.default_marker_shape);
 
                         paint_marker(
-                            &mut child_ui,
-                            (tau as f32 * TAU) + marker.angle,
-                            marker.label,
+                            child_ui,
+                            angle,
+                            marker.label.as_deref(),
                             marker_color,
                             marker_shape,
                             marker_color,
                             marker_stroke,
                         );
+                    };
+
+                match &marker_clusters {
+                    Some(marker_clusters) => {
+                        for (angle, members) in marker_clusters {
+                            if let [marker_index] = members[..] {
+                                paint_compass_marker(
+                                    &mut child_ui,
+                                    *angle,
+                                    &self.markers[marker_index],
+                                );
+                                continue;
+                            }
+
+                            let first_marker = &self.markers[members[0]];
+                            let cluster_color = first_marker.color.unwrap_or_else(|| {
+                                self.default_marker_color.color(&child_ui, first_marker)
+                            });
+                            let cluster_stroke = Stroke::new(
+                                1.0,
+                                tint_color_towards(
+                                    cluster_color,
+                                    child_ui.style().visuals.text_color(),
+                                ),
+                            );
+
+                            paint_marker(
+                                &mut child_ui,
+                                *angle,
+                                None,
+                                cluster_color,
+                                CompassMarkerShape::Circle,
+                                cluster_color,
+                                cluster_stroke,
+                            );
+
+                            child_ui.painter().text(
+                                pos2(map_angle_to_screen(*angle), rect.top() + height * 0.375),
+                                Align2::CENTER_CENTER,
+                                members.len().to_string(),
+                                FontId::new(height / 6.0, FontFamily::Proportional),
+                                child_ui.style().visuals.strong_text_color(), // TODO: Semantically correct color
+                            );
+                        }
+                    }
+                    None => {
+                        let start_tau =
+                            ((view_center - (self.spread.abs() / 2.0)) / TAU).floor() as isize;
+                        let end_tau =
+                            ((view_center + (self.spread.abs() / 2.0)) / TAU).ceil() as isize;
+
+                        for tau in start_tau..=end_tau {
+                            for marker in self.markers.iter() {
+                                paint_compass_marker(
+                                    &mut child_ui,
+                                    (tau as f32 * TAU) + marker.angle,
+                                    marker,
+                                );
+                            }
+                        }
+                    }
+                }
+
+                if self.show_overflow_markers {
+                    let half_spread = self.spread.abs() / 2.0;
+                    let chevron_size = height * 0.25;
+
+                    // Chevrons fade in while their markers slide out past the edge
+                    let fade_angle = chevron_size * (self.spread.abs() / rect.width());
+
+                    // (distance, color, opacity) of the overflowing markers on both edges
+                    let (mut left_markers, mut right_markers) = (Vec::new(), Vec::new());
+
+                    for marker in self.markers.iter() {
+                        let offset = normalized_angle(marker.angle - view_center);
+                        let overflow = offset.abs() - half_spread;
+
+                        if overflow <= 0.0 {
+                            continue;
+                        }
+
+                        let marker_color = marker
+                            .color
+                            .unwrap_or_else(|| self.default_marker_color.color(&child_ui, marker));
+                        let opacity = remap_clamp(overflow, 0.0..=fade_angle, 0.0..=1.0);
+
+                        if map_angle_to_screen(view_center + offset) < rect.center().x {
+                            left_markers.push((overflow, marker_color, opacity));
+                        } else {
+                            right_markers.push((overflow, marker_color, opacity));
+                        }
+                    }
+
+                    for (edge_markers, direction) in
+                        [(left_markers, -1.0f32), (right_markers, 1.0f32)]
+                    {
+                        if edge_markers.is_empty() {
+                            continue;
+                        }
+
+                        let tip = pos2(
+                            if direction < 0.0 {
+                                rect.left() + chevron_size * 0.25
+                            } else {
+                                rect.right() - chevron_size * 0.25
+                            },
+                            rect.top() + height * 0.375,
+                        );
+
+                        let chevron_points = vec![
+                            tip,
+                            tip - vec2(direction * chevron_size * 0.75, chevron_size / 2.0),
+                            tip - vec2(direction * chevron_size * 0.75, -chevron_size / 2.0),
+                        ];
+
+                        // The nearest marker ends up on top
+                        for &(_, marker_color, opacity) in edge_markers
+                            .iter()
+                            .sorted_by(|(a, _, _), (b, _, _)| b.total_cmp(a))
+                        {
+                            let stroke_color = tint_color_towards(
+                                marker_color,
+                                child_ui.style().visuals.text_color(),
+                            );
+
+                            child_ui.painter().add(Shape::convex_polygon(
+                                chevron_points.clone(),
+                                marker_color.linear_multiply(opacity),
+                                Stroke::new(1.0, stroke_color.linear_multiply(opacity)),
+                            ));
+                        }
+
+                        if edge_markers.len() > 1 {
+                            child_ui.painter().text(
+                                tip - vec2(direction * chevron_size * 0.375, height * 0.25),
+                                Align2::CENTER_CENTER,
+                                edge_markers.len().to_string(),
+                                FontId::new(height / 5.0, FontFamily::Proportional),
+                                child_ui.style().visuals.text_color(),
+                            );
+                        }
+                    }
+                }
+
+                if let Some(target) = target {
+                    let bug_stroke = Stroke::new(
+                        visuals.fg_stroke.width.max(1.5),
+                        self.target_color
+                            .unwrap_or_else(|| xt_colors(&child_ui).info),
+                    );
+
+                    let start_tau =
+                        ((view_center - (self.spread.abs() / 2.0)) / TAU).floor() as isize;
+                    let end_tau = ((view_center + (self.spread.abs() / 2.0)) / TAU).ceil() as isize;
+
+                    for tau in start_tau..=end_tau {
+                        let bug_rect = Rect::from_center_size(
+                            pos2(
+                                map_angle_to_screen((tau as f32 * TAU) + target),
+                                rect.top() + height * 0.375,
+                            ),
+                            vec2(height * 0.3, height * 0.25),
+                        );
+
+                        // Notched towards the cursor, which fits into it when on target
+                        child_ui.painter().add(Shape::closed_line(
+                            vec![
+                                bug_rect.left_top(),
+                                bug_rect.center_top() - vec2(bug_rect.width() * 0.2, 0.0),
+                                bug_rect.center(),
+                                bug_rect.center_top() + vec2(bug_rect.width() * 0.2, 0.0),
+                                bug_rect.right_top(),
+                                bug_rect.right_bottom(),
+                                bug_rect.left_bottom(),
+                            ],
+                            bug_stroke,
+                        ));
                     }
                 }
 
                 if self.show_cursor {
+                    // Animated values can stray outside of the range of the wrap mode
+                    let cursor_value = self.wrap.wrap_angle(value);
+
+                    let cursor_text = match &self.value_formatter {
+                        Some(value_formatter) => value_formatter(cursor_value),
+                        None => self.ticks.format(child_ui.ctx(), cursor_value),
+                    };
+
                     paint_marker(
                         &mut child_ui,
-                        value,
-                        Some(&format!("{:.0}°", value.to_degrees())),
+                        view_center + normalized_angle(value - view_center),
+                        Some(&cursor_text),
                         visuals.text_color(),
                         CompassMarkerShape::DownArrow,
                         visuals.bg_fill,
@@ -366,65 +1193,173 @@ impl<'a> Widget for LinearCompass<'a> {
             }
 
             {
-                let round_bounds_to = 10.0;
+                let start = view_center - (self.spread.abs() / 2.0);
+                let end = view_center + (self.spread.abs() / 2.0);
 
-                let start_degrees =
-                    (((value - (self.spread.abs() / 2.0)).to_degrees() / round_bounds_to).floor()
-                        * round_bounds_to) as isize;
+                let axis_spacing = self.axis_labels.spacing_degrees().to_radians();
+                let major_spacing = (self.axis_labels.major_spacing_degrees() as f32).to_radians();
 
-                let end_degrees =
-                    (((value + (self.spread.abs() / 2.0)).to_degrees() / round_bounds_to).ceil()
-                        * round_bounds_to) as isize;
+                let is_multiple_of = |angle: f32, step: f32| {
+                    let steps = angle / step;
+                    (steps - steps.round()).abs() < 1e-4
+                };
 
-                for degree in (start_degrees..=end_degrees).step_by(5) {
-                    let tick_x = map_angle_to_screen((degree as f32).to_radians());
+                let tick_stroke = child_ui.style().visuals.noninteractive().fg_stroke;
+                let tick_size = vec2(0.0, height * 0.25);
+
+                let highlight_stroke = child_ui.style().visuals.selection.stroke; // TODO: Semantically correct color
+
+                let label_count = self.axis_labels.inner.len() as isize;
+                let first_label = ((start - axis_spacing) / axis_spacing).floor() as isize;
+                let last_label = ((end + axis_spacing) / axis_spacing).ceil() as isize;
+
+                // Minor ticks are only drawn between the labelled directions
+                let tick_angles = self
+                    .ticks
+                    .tick_angles(start, end)
+                    .filter(|&tick_angle| !is_multiple_of(tick_angle, axis_spacing))
+                    .filter_map(|tick_angle| {
+                        let tick_scale = self.ticks.tick_scale(tick_angle)?;
+                        Some((tick_angle, tick_scale))
+                    })
+                    .collect_vec();
+
+                let highlighted_angle = response
+                    .hover_pos()
+                    .filter(|_| self.hover_highlight && !response.dragged())
+                    .and_then(|pointer_pos| {
+                        let pointer_angle = view_center
+                            - (rect.center().x - pointer_pos.x)
+                                * (self.spread * winding.to_float())
+                                / rect.width();
+
+                        let label_angles = (first_label..=last_label)
+                            .map(|label| label as f32 * axis_spacing)
+                            .filter(|_| self.show_ticks || self.show_axes);
+
+                        tick_angles
+                            .iter()
+                            .map(|&(tick_angle, _)| tick_angle)
+                            .filter(|_| self.show_ticks)
+                            .chain(label_angles)
+                            .min_by(|a, b| {
+                                (a - pointer_angle)
+                                    .abs()
+                                    .total_cmp(&(b - pointer_angle).abs())
+                            })
+                    });
+
+                let is_highlighted =
+                    |angle: f32| highlighted_angle.is_some_and(|highlighted| highlighted == angle);
+
+                for &(tick_angle, tick_scale) in tick_angles.iter().filter(|_| self.show_ticks) {
+                    let tick_x = map_angle_to_screen(tick_angle);
+                    let tick_position = pos2(tick_x, rect.top() + (height * 0.5));
 
-                    let tick_position = pos2(tick_x, rect.top() + (self.height * 0.5));
-                    let tick_size = vec2(0.0, self.height * 0.25);
+                    child_ui.painter().line_segment(
+                        [tick_position, tick_position + tick_size * tick_scale],
+                        if is_highlighted(tick_angle) {
+                            highlight_stroke
+                        } else {
+                            tick_stroke
+                        },
+                    );
+                }
+
+                for label in first_label..=last_label {
+                    let label_index = label.rem_euclid(label_count) as usize;
+                    let label_angle = label as f32 * axis_spacing;
 
-                    let tick_label_center = pos2(tick_x, rect.top() + (self.height * 0.875));
+                    let tick_x = map_angle_to_screen(label_angle);
 
-                    let (tick_scale, tick_label, is_axis_tick) = if degree % 90 == 0 {
-                        let axis_label_index = (degree / 90).rem_euclid(4) as usize;
-                        (1.0, Some(&self.axis_labels.inner[axis_label_index]), true)
-                    } else if degree % 30 == 0 {
-                        (0.75, None, false)
-                    } else if degree % 10 == 0 {
-                        (0.5, None, false)
-                    } else if degree % 5 == 0 {
-                        (0.3, None, false)
+                    let tick_position = pos2(tick_x, rect.top() + (height * 0.5));
+                    let tick_label_center = pos2(tick_x, rect.top() + (height * 0.875));
+
+                    let tick_scale = if is_multiple_of(label_angle, major_spacing) {
+                        1.0
                     } else {
-                        unreachable!()
+                        0.75
                     };
 
-                    if self.show_ticks || (self.show_axes && is_axis_tick) {
+                    let highlighted = is_highlighted(label_angle);
+
+                    if self.show_ticks || self.show_axes {
                         child_ui.painter().line_segment(
                             [tick_position, tick_position + tick_size * tick_scale],
-                            child_ui.style().visuals.noninteractive().fg_stroke,
+                            if highlighted {
+                                highlight_stroke
+                            } else {
+                                tick_stroke
+                            },
                         );
                     }
 
                     if self.show_axes {
-                        if let Some(tick_label) = tick_label {
-                            child_ui.painter().text(
-                                tick_label_center,
-                                Align2::CENTER_CENTER,
-                                tick_label,
-                                FontId::new(self.height / 4.0, FontFamily::Proportional),
-                                child_ui.style().visuals.text_color(),
-                            );
-                        }
+                        let label_scale = self.axis_labels.label_scale(label_index);
+
+                        child_ui.painter().text(
+                            tick_label_center,
+                            Align2::CENTER_CENTER,
+                            &self.axis_labels.inner[label_index],
+                            FontId::new(height / 4.0 * label_scale, FontFamily::Proportional),
+                            if highlighted {
+                                highlight_stroke.color
+                            } else {
+                                child_ui.style().visuals.text_color()
+                            },
+                        );
                     }
                 }
             }
 
             {
+                let stop_stroke = Stroke::new(
+                    child_ui.style().visuals.noninteractive().fg_stroke.width,
+                    xt_colors(&child_ui).warning,
+                );
+
+                // Angles beyond the stops are shaded, clamped to the visible interval
+                {
+                    let (start, end) = (
+                        view_center - (self.spread.abs() / 2.0),
+                        view_center + (self.spread.abs() / 2.0),
+                    );
+
+                    let shade_fill = xt_colors(&child_ui).warning.linear_multiply(0.1); // TODO: Semantically correct color
+
+                    let paint_shade = |from: f32, to: f32| {
+                        if from < to {
+                            child_ui.painter().rect_filled(
+                                Rect::from_two_pos(
+                                    pos2(map_angle_to_screen(from), rect.top()),
+                                    pos2(map_angle_to_screen(to), rect.bottom()),
+                                ),
+                                0.0,
+                                shade_fill,
+                            );
+                        }
+                    };
+
+                    let stops_ordered = self.min.zip(self.max).is_none_or(|(min, max)| min <= max);
+                    debug_assert!(stops_ordered, "min must not be greater than max");
+
+                    if stops_ordered {
+                        if let Some(min) = self.min {
+                            paint_shade(start, min.min(end));
+                        }
+
+                        if let Some(max) = self.max {
+                            paint_shade(max.max(start), end);
+                        }
+                    }
+                }
+
                 let paint_stop = |angle: f32| {
                     let stop_x = map_angle_to_screen(angle);
 
                     child_ui.painter().line_segment(
                         [pos2(stop_x, rect.top()), pos2(stop_x, rect.bottom())],
-                        child_ui.style().visuals.noninteractive().fg_stroke,
+                        stop_stroke,
                     );
                 };
 
@@ -438,6 +1373,27 @@ impl<'a> Widget for LinearCompass<'a> {
             }
         }
 
-        response
+        let selected_markers = selection
+            .map(|(selection_start, selection_end)| {
+                self.markers
+                    .iter()
+                    .positions(|marker| {
+                        normalized_angle_unsigned_excl(marker.angle - selection_start)
+                            <= selection_end - selection_start
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        LinearCompassResponse {
+            response,
+            selection,
+            selected_markers,
+            marker_positions,
+            hovered_marker,
+            clicked_marker,
+            target,
+            dragged_element,
+        }
     }
 }
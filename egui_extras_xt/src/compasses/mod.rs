@@ -2,8 +2,15 @@ mod compass_axis_labels;
 mod compass_marker;
 mod linear_compass;
 mod polar_compass;
+mod tick_spec;
 
 pub use compass_axis_labels::CompassAxisLabels;
 pub use compass_marker::{CompassMarker, CompassMarkerShape, DefaultCompassMarkerColor};
-pub use linear_compass::LinearCompass;
-pub use polar_compass::{PolarCompass, PolarCompassOverflow};
+pub use linear_compass::{
+    CompassCursorMode, LinearCompass, LinearCompassElement, LinearCompassResponse,
+};
+pub use polar_compass::{
+    DistanceScale, PolarCompass, PolarCompassLabelVisibility, PolarCompassOverflow,
+    PolarCompassResponse,
+};
+pub use tick_spec::TickSpec;
@@ -2,18 +2,25 @@ use std::f32::consts::TAU;
 
 use ecolor::tint_color_towards;
 use egui::{
-    lerp, Align2, FontFamily, FontId, Pos2, Rect, Response, Sense, Shape, Stroke, Ui, Vec2, Widget,
+    lerp, show_tooltip_at_pointer, Align2, FontFamily, FontId, Key, Pos2, Rect, Response, Sense,
+    Shape, Stroke, Ui, Vec2, Widget, WidgetText,
 };
 use emath::normalized_angle;
 
+use itertools::Itertools;
+
 use strum::{Display, EnumIter};
 
 use crate::common::{
-    snap_wrap_constrain_angle, Orientation, RotatedText, SymLog, Winding, WrapMode,
+    animate_value, normalized_angle_unsigned_excl, record_sample, slider_widget_info,
+    snap_wrap_constrain_angle, Orientation, RotatedText, SymLog, WidgetShape, Winding, WrapMode,
+    DEFAULT_ANGLE_SHIFT_SNAP,
 };
 use crate::compasses::{
     CompassAxisLabels, CompassMarker, CompassMarkerShape, DefaultCompassMarkerColor,
 };
+use crate::localization::localize;
+use crate::{XtText, XtWidgetState};
 
 // ----------------------------------------------------------------------------
 
@@ -29,6 +36,12 @@ fn set(get_set_value: &mut GetSetValue<'_>, value: f32) {
     (get_set_value)(Some(value));
 }
 
+type Easing<'a> = Box<dyn 'a + Fn(f32) -> f32>;
+
+type RingLabelFormatter<'a> = Box<dyn 'a + Fn(usize, f32) -> String>;
+
+type DistanceFormatter<'a> = Box<dyn 'a + Fn(f32) -> String>;
+
 // ----------------------------------------------------------------------------
 
 #[derive(Clone, Copy, Debug, Display, EnumIter, Eq, PartialEq)]
@@ -40,15 +53,75 @@ pub enum PolarCompassOverflow {
     Saturate,
 }
 
+/// When the labels of the markers are shown.
+#[derive(Clone, Copy, Debug, Display, EnumIter, Eq, PartialEq)]
+pub enum PolarCompassLabelVisibility {
+    #[strum(to_string = "Never")]
+    Never,
+
+    #[strum(to_string = "Always")]
+    Always,
+
+    /// Only the label of the hovered marker is shown, for keeping dense compasses legible.
+    #[strum(to_string = "Hover only")]
+    HoverOnly,
+}
+
+/// Mapping of marker distances to the radius of the compass.
+#[derive(Clone, Copy, Debug, Display, EnumIter, Eq, PartialEq)]
+pub enum DistanceScale {
+    #[strum(to_string = "Linear")]
+    Linear,
+
+    #[strum(to_string = "Logarithmic")]
+    Logarithmic,
+}
+
+// ----------------------------------------------------------------------------
+
+/// Response of [`PolarCompass::show`].
+pub struct PolarCompassResponse {
+    pub response: Response,
+
+    /// Indices of the markers inside the sector selected by a drag released in this frame,
+    /// see [`PolarCompass::sector_selection`].
+    pub selected_markers: Option<Vec<usize>>,
+
+    /// Index of the marker under the pointer.
+    pub hovered_marker: Option<usize>,
+
+    /// Index of the marker clicked in this frame.
+    pub clicked_marker: Option<usize>,
+
+    /// Screen position of the center of each marker as drawn in this frame,
+    /// `None` for clipped markers.
+    pub marker_positions: Vec<Option<Pos2>>,
+}
+
+/// Whether `angle` lies within the sector starting at `start` and spanning `sweep` radians,
+/// which is negative when the sector was swept counter to the angles.
+fn sector_contains(start: f32, sweep: f32, angle: f32) -> bool {
+    if sweep >= 0.0 {
+        normalized_angle_unsigned_excl(angle - start) <= sweep
+    } else {
+        normalized_angle_unsigned_excl(start - angle) <= -sweep
+    }
+}
+
 // ----------------------------------------------------------------------------
 
 #[must_use = "You should put this widget in an ui with `ui.add(widget);`"]
 pub struct PolarCompass<'a> {
     get_set_value: GetSetValue<'a>,
     interactive: bool,
+    label: Option<WidgetText>,
     orientation: Orientation,
     winding: Winding,
+    mirrored: bool,
     overflow: PolarCompassOverflow,
+    heading: Option<f32>,
+    rotate_with_heading: bool,
+    rotate_markers: bool,
     diameter: f32,
     wrap: WrapMode,
     min: Option<f32>,
@@ -56,21 +129,31 @@ pub struct PolarCompass<'a> {
     snap: Option<f32>,
     shift_snap: Option<f32>,
     animated: bool,
+    animation_time: Option<f32>,
+    easing: Option<Easing<'a>>,
     axis_labels: CompassAxisLabels,
     axis_label_height: f32,
     max_distance: f32,
+    distance_scale: DistanceScale,
     scale_log_base: f32,
     scale_log_mult: f32,
     marker_near_size: f32,
     marker_far_size: f32,
     show_axes: bool,
     show_rings: bool,
+    ring_labels: Option<RingLabelFormatter<'a>>,
+    ring_label_bearing: f32,
     show_cursor: bool,
-    show_marker_labels: bool,
+    show_marker_labels: PolarCompassLabelVisibility,
     show_marker_lines: bool,
+    show_marker_tooltips: bool,
+    distance_formatter: Option<DistanceFormatter<'a>>,
+    sector_selection: bool,
     markers: &'a [CompassMarker<'a>],
     default_marker_color: DefaultCompassMarkerColor,
     default_marker_shape: CompassMarkerShape,
+    recording: Option<&'a mut Vec<(f64, f32)>>,
+    reset_state: bool,
 }
 
 impl<'a> PolarCompass<'a> {
@@ -87,43 +170,62 @@ impl<'a> PolarCompass<'a> {
         Self {
             get_set_value: Box::new(get_set_value),
             interactive: true,
+            label: None,
             orientation: Orientation::Top,
             winding: Winding::Clockwise,
+            mirrored: false,
             overflow: PolarCompassOverflow::Saturate,
+            heading: None,
+            rotate_with_heading: true,
+            rotate_markers: true,
             diameter: 256.0,
             wrap: WrapMode::Unsigned,
             min: None,
             max: None,
             snap: None,
-            shift_snap: Some(15.0f32.to_radians()),
+            shift_snap: Some(DEFAULT_ANGLE_SHIFT_SNAP),
             animated: false,
+            animation_time: None,
+            easing: None,
             axis_labels: ["N", "E", "S", "W"].into(),
             axis_label_height: 24.0,
             max_distance: 10000.0,
+            distance_scale: DistanceScale::Logarithmic,
             scale_log_base: 10.0,
             scale_log_mult: 1.0,
             marker_near_size: 16.0,
             marker_far_size: 8.0,
             show_axes: true,
             show_rings: true,
+            ring_labels: None,
+            ring_label_bearing: 45.0f32.to_radians(),
             show_cursor: true,
-            show_marker_labels: true,
+            show_marker_labels: PolarCompassLabelVisibility::Always,
             show_marker_lines: true,
+            show_marker_tooltips: true,
+            distance_formatter: None,
+            sector_selection: false,
             markers: &[],
-            default_marker_color: DefaultCompassMarkerColor::HsvByAngle {
-                hue_phase: 0.0,
-                saturation: 1.0,
-                value: 1.0,
-            },
+            default_marker_color: DefaultCompassMarkerColor::Palette,
             default_marker_shape: CompassMarkerShape::Square,
+            recording: None,
+            reset_state: false,
         }
     }
 
+    /// Read-only when `false`: only hovering is sensed and the widget is painted with
+    /// the noninteractive visuals. Defaults to `true`.
     pub fn interactive(mut self, interactive: bool) -> Self {
         self.interactive = interactive;
         self
     }
 
+    /// Name of the widget announced by screen readers, e.g. "Heading".
+    pub fn label(mut self, label: impl Into<WidgetText>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
     pub fn orientation(mut self, orientation: Orientation) -> Self {
         self.orientation = orientation;
         self
@@ -134,11 +236,38 @@ impl<'a> PolarCompass<'a> {
         self
     }
 
+    /// Mirrors the widget horizontally, e.g. for right-to-left layouts.
+    pub fn mirrored(mut self, mirrored: bool) -> Self {
+        self.mirrored = mirrored;
+        self
+    }
+
     pub fn overflow(mut self, overflow: PolarCompassOverflow) -> Self {
         self.overflow = overflow;
         self
     }
 
+    /// Heading the compass rotates with, defaults to the value.
+    pub fn heading(mut self, heading: f32) -> Self {
+        self.heading = Some(heading);
+        self
+    }
+
+    /// Keeps the heading at the top of the compass (head-up), otherwise north stays up
+    /// and the cursor points at the value.
+    pub fn rotate_with_heading(mut self, rotate_with_heading: bool) -> Self {
+        self.rotate_with_heading = rotate_with_heading;
+        self
+    }
+
+    /// Whether marker angles are bearings rotating along with the compass (the default),
+    /// or are relative to the top of the widget, e.g. for contacts reported relative to
+    /// the vehicle.
+    pub fn rotate_markers(mut self, rotate_markers: bool) -> Self {
+        self.rotate_markers = rotate_markers;
+        self
+    }
+
     pub fn diameter(mut self, diameter: f32) -> Self {
         assert!(diameter > 0.0);
         self.diameter = diameter;
@@ -160,13 +289,16 @@ impl<'a> PolarCompass<'a> {
         self
     }
 
-    pub fn snap(mut self, snap: Option<f32>) -> Self {
-        self.snap = snap;
+    /// Snaps the angle when dragging, in radians. No snapping by default.
+    pub fn snap(mut self, snap: impl Into<Option<f32>>) -> Self {
+        self.snap = snap.into();
         self
     }
 
-    pub fn shift_snap(mut self, shift_snap: Option<f32>) -> Self {
-        self.shift_snap = shift_snap;
+    /// Snaps the angle while Shift is held, in radians. Defaults to 15°, pass `None`
+    /// to disable it.
+    pub fn shift_snap(mut self, shift_snap: impl Into<Option<f32>>) -> Self {
+        self.shift_snap = shift_snap.into();
         self
     }
 
@@ -175,6 +307,19 @@ impl<'a> PolarCompass<'a> {
         self
     }
 
+    /// Duration of the animations in seconds, defaults to the animation time of the style.
+    pub fn animation_time(mut self, animation_time: impl Into<f32>) -> Self {
+        self.animation_time = Some(animation_time.into());
+        self
+    }
+
+    /// Shapes the animations, mapping their linear progress to the eased progress,
+    /// both within `0.0..=1.0`.
+    pub fn easing(mut self, easing: impl 'a + Fn(f32) -> f32) -> Self {
+        self.easing = Some(Box::new(easing));
+        self
+    }
+
     pub fn scale_log_base(mut self, scale_log_base: f32) -> Self {
         assert!(scale_log_base > 1.0);
         self.scale_log_base = scale_log_base;
@@ -204,6 +349,13 @@ impl<'a> PolarCompass<'a> {
         self
     }
 
+    /// Scale of the marker distances and rings, the rings are drawn at the same radii
+    /// with either scale.
+    pub fn distance_scale(mut self, distance_scale: DistanceScale) -> Self {
+        self.distance_scale = distance_scale;
+        self
+    }
+
     pub fn ring_count(mut self, ring_count: usize) -> Self {
         assert!(ring_count > 0);
         self.scale_log_base = (self.max_distance.ln() / ring_count as f32).exp();
@@ -233,12 +385,27 @@ impl<'a> PolarCompass<'a> {
         self
     }
 
+    /// Labels each ring with the text returned for its index (starting from 1)
+    /// and the distance it represents, at half the axis label height.
+    pub fn ring_labels(mut self, ring_labels: impl 'a + Fn(usize, f32) -> String) -> Self {
+        self.ring_labels = Some(Box::new(ring_labels));
+        self
+    }
+
+    /// Bearing where the ring labels are placed.
+    pub fn ring_label_bearing(mut self, ring_label_bearing: f32) -> Self {
+        self.ring_label_bearing = ring_label_bearing;
+        self
+    }
+
     pub fn show_cursor(mut self, show_cursor: bool) -> Self {
         self.show_cursor = show_cursor;
         self
     }
 
-    pub fn show_marker_labels(mut self, show_marker_labels: bool) -> Self {
+    /// Labels are placed next to their markers, away from the center, at half the axis
+    /// label height.
+    pub fn show_marker_labels(mut self, show_marker_labels: PolarCompassLabelVisibility) -> Self {
         self.show_marker_labels = show_marker_labels;
         self
     }
@@ -248,6 +415,26 @@ impl<'a> PolarCompass<'a> {
         self
     }
 
+    /// Shows the label, bearing and distance of the marker under the pointer in a tooltip.
+    pub fn show_marker_tooltips(mut self, show_marker_tooltips: bool) -> Self {
+        self.show_marker_tooltips = show_marker_tooltips;
+        self
+    }
+
+    /// Formats the marker distances shown in tooltips, e.g. with units.
+    /// Defaults to one decimal.
+    pub fn distance_formatter(mut self, distance_formatter: impl 'a + Fn(f32) -> String) -> Self {
+        self.distance_formatter = Some(Box::new(distance_formatter));
+        self
+    }
+
+    /// Dragging selects the markers within the swept sector instead of rotating the compass.
+    /// Escape or a click without dragging clears the selection.
+    pub fn sector_selection(mut self, sector_selection: bool) -> Self {
+        self.sector_selection = sector_selection;
+        self
+    }
+
     pub fn markers(mut self, markers: &'a [CompassMarker<'a>]) -> Self {
         self.markers = markers;
         self
@@ -262,10 +449,55 @@ impl<'a> PolarCompass<'a> {
         self.default_marker_shape = default_marker_shape;
         self
     }
+
+    /// Appends a `(time, value)` sample to `recording` whenever the user changes the value.
+    /// Changes made within the same frame are coalesced into one sample,
+    /// thinning or compressing the recording is left to the caller.
+    pub fn record_into(mut self, recording: &'a mut Vec<(f64, f32)>) -> Self {
+        self.recording = Some(recording);
+        self
+    }
+
+    /// Forgets the transient state of the widget, see [`XtWidgetState`].
+    pub fn reset_state(mut self) -> Self {
+        self.reset_state = true;
+        self
+    }
 }
 
 impl<'a> Widget for PolarCompass<'a> {
-    fn ui(mut self, ui: &mut Ui) -> Response {
+    fn ui(self, ui: &mut Ui) -> Response {
+        self.show(ui).response
+    }
+}
+
+impl<'a> PolarCompass<'a> {
+    fn max_log(&self) -> f32 {
+        (self.max_distance / self.scale_log_mult).symlog(self.scale_log_base)
+    }
+
+    /// Fraction of the radius at which `distance` is drawn.
+    fn distance_to_t(&self, distance: f32) -> f32 {
+        match self.distance_scale {
+            DistanceScale::Linear => distance / self.max_distance,
+            DistanceScale::Logarithmic => {
+                (distance / self.scale_log_mult).symlog(self.scale_log_base) / self.max_log()
+            }
+        }
+    }
+
+    /// Distance represented by the ring with index `ring` (starting from 1).
+    fn ring_distance(&self, ring: usize) -> f32 {
+        match self.distance_scale {
+            DistanceScale::Linear => self.max_distance * (ring as f32 / self.max_log()),
+            DistanceScale::Logarithmic => {
+                (ring as f32).symexp(self.scale_log_base) * self.scale_log_mult
+            }
+        }
+    }
+
+    /// Adds the compass to `ui`, like `ui.add(widget)` but also returning the marker selection.
+    pub fn show(mut self, ui: &mut Ui) -> PolarCompassResponse {
         let desired_size = Vec2::splat(self.diameter + self.axis_label_height * 2.0);
 
         let (rect, mut response) = ui.allocate_exact_size(
@@ -277,23 +509,99 @@ impl<'a> Widget for PolarCompass<'a> {
             },
         );
 
-        let rotation_matrix = self.orientation.rot2();
+        let orientation = self.orientation.mirrored(self.mirrored);
+        let winding = self.winding.mirrored(self.mirrored);
+        let rotation_matrix = orientation.rot2();
+
+        if self.reset_state {
+            XtWidgetState::reset(ui.ctx(), response.id);
+        }
 
         if response.drag_started() {
             let value_before_drag = get(&mut self.get_set_value);
-            ui.memory_mut(|memory| memory.data.insert_temp(response.id, value_before_drag));
+            XtWidgetState::update(ui.ctx(), response.id, |state| {
+                state.drag_origin_value = Some(value_before_drag);
+            });
         }
 
         if response.drag_released() {
-            ui.memory_mut(|memory| memory.data.remove::<f32>(response.id));
+            XtWidgetState::update(ui.ctx(), response.id, |state| {
+                state.drag_origin_value = None;
+            });
         }
 
-        if response.dragged() {
-            let screen_pos_to_angle = |pos: Pos2| {
-                -(rotation_matrix * (rect.center() - pos)).angle() * self.winding.to_float()
+        let mut selected_markers = None;
+        let mut hovered_marker = None;
+        let mut clicked_marker = None;
+        let mut marker_positions = vec![None; self.markers.len()];
+
+        let heading_rotation = |value: f32| match (self.rotate_with_heading, self.heading) {
+            (true, Some(heading)) => heading,
+            (true, None) => value,
+            (false, _) => 0.0,
+        };
+
+        // Bearing of the marker, screen-referenced markers turn with the compass
+        let marker_bearing = |marker: &CompassMarker, rotation: f32| {
+            if self.rotate_markers {
+                marker.angle
+            } else {
+                marker.angle + rotation
+            }
+        };
+
+        if self.sector_selection {
+            let rotation = heading_rotation(get(&mut self.get_set_value));
+
+            let screen_pos_to_bearing = |pos: Pos2| {
+                rotation
+                    + (rotation_matrix.inverse() * (pos - rect.center())).angle()
+                        * winding.to_float()
             };
 
-            let value_before_drag = ui.memory_mut(|memory| memory.data.get_temp::<f32>(response.id).unwrap());
+            let mut state = XtWidgetState::load(ui.ctx(), response.id);
+
+            if response.drag_started() {
+                let press_origin = ui.input(|input| input.pointer.press_origin().unwrap());
+                state.sector_selection = Some((screen_pos_to_bearing(press_origin), 0.0));
+            }
+
+            if let (true, Some((start, sweep))) = (response.dragged(), &mut state.sector_selection)
+            {
+                // Accumulate the sweep frame by frame, so the drag direction decides
+                // between the shorter and the longer arc, even across 0°.
+                let bearing = screen_pos_to_bearing(response.interact_pointer_pos().unwrap());
+                *sweep = (*sweep + normalized_angle(bearing - (*start + *sweep))).clamp(-TAU, TAU);
+            }
+
+            if response.drag_released() {
+                if let Some((start, sweep)) = state.sector_selection {
+                    selected_markers = Some(
+                        self.markers
+                            .iter()
+                            .positions(|marker| {
+                                sector_contains(start, sweep, marker_bearing(marker, rotation))
+                            })
+                            .collect(),
+                    );
+                }
+            }
+
+            let escape_pressed = (response.hovered() || response.dragged())
+                && ui.input(|input| input.key_pressed(Key::Escape));
+
+            if response.clicked() || escape_pressed {
+                state.sector_selection = None;
+            }
+
+            state.store(ui.ctx(), response.id);
+        } else if response.dragged() {
+            let screen_pos_to_angle =
+                |pos: Pos2| -(rotation_matrix * (rect.center() - pos)).angle() * winding.to_float();
+
+            let value_before_drag = XtWidgetState::load(ui.ctx(), response.id)
+                .drag_origin_value
+                .unwrap();
             let prev_value = get(&mut self.get_set_value);
 
             let mut new_value = normalized_angle(
@@ -317,6 +625,23 @@ impl<'a> Widget for PolarCompass<'a> {
 
             set(&mut self.get_set_value, new_value);
             response.mark_changed();
+
+            if let Some(recording) = &mut self.recording {
+                record_sample(recording, ui.input(|input| input.time), new_value);
+            }
+        }
+
+        {
+            let value = get(&mut self.get_set_value);
+            slider_widget_info(
+                ui,
+                &response,
+                self.label.as_ref(),
+                value.to_degrees() as f64,
+                self.min.map(|min| min.to_degrees() as f64),
+                self.max.map(|max| max.to_degrees() as f64),
+                self.snap.map(|snap| snap.to_degrees() as f64),
+            );
         }
 
         if ui.is_rect_visible(rect) {
@@ -324,10 +649,12 @@ impl<'a> Widget for PolarCompass<'a> {
             let radius = self.diameter / 2.0;
 
             let value = if self.animated {
-                ui.ctx().animate_value_with_time(
+                animate_value(
+                    ui.ctx(),
                     response.id,
                     get(&mut self.get_set_value),
-                    ui.style().animation_time,
+                    self.animation_time.unwrap_or(ui.style().animation_time),
+                    self.easing.as_deref(),
                 )
             } else {
                 get(&mut self.get_set_value)
@@ -343,7 +670,7 @@ impl<'a> Widget for PolarCompass<'a> {
             }
 
             if self.show_rings {
-                let max_log = (self.max_distance / self.scale_log_mult).symlog(self.scale_log_base);
+                let max_log = self.max_log();
                 assert!(max_log < 256.0); // Prevent accidental OoM deaths during development
 
                 // No off-by-one bugs here, non-inclusive range end is used to
@@ -351,21 +678,39 @@ impl<'a> Widget for PolarCompass<'a> {
                 for i in 1..max_log.ceil() as usize {
                     ui.painter().circle_stroke(
                         rect.center(),
-                        radius * (i as f32 / max_log),
+                        radius * self.distance_to_t(self.ring_distance(i)),
                         ui.style().visuals.noninteractive().fg_stroke, // TODO: Semantically correct color
                     );
                 }
             }
 
+            let rotation = heading_rotation(value);
+
             let angle_to_direction = |angle: f32| {
-                rotation_matrix * Vec2::angled((angle - value) * self.winding.to_float())
+                rotation_matrix * Vec2::angled((angle - rotation) * winding.to_float())
             };
 
+            if let (true, Some(ring_labels)) = (self.show_rings, &self.ring_labels) {
+                for i in 1..self.max_log().ceil() as usize {
+                    let ring_distance = self.ring_distance(i);
+
+                    ui.painter().text(
+                        rect.center()
+                            + angle_to_direction(self.ring_label_bearing)
+                                * (radius * self.distance_to_t(ring_distance)),
+                        Align2::CENTER_CENTER,
+                        ring_labels(i, ring_distance),
+                        FontId::new(self.axis_label_height / 2.0, FontFamily::Proportional),
+                        ui.style().visuals.text_color(),
+                    );
+                }
+            }
+
             if self.show_cursor {
                 ui.painter().add(Shape::dashed_line(
                     &[
                         rect.center(),
-                        rect.center() + rotation_matrix * Vec2::RIGHT * radius,
+                        rect.center() + angle_to_direction(value) * radius,
                     ],
                     ui.style().visuals.noninteractive().fg_stroke, // TODO: Semantically correct color
                     2.0,
@@ -373,41 +718,146 @@ impl<'a> Widget for PolarCompass<'a> {
                 ));
             }
 
+            if self.sector_selection {
+                if let Some((start, sweep)) =
+                    XtWidgetState::load(ui.ctx(), response.id).sector_selection
+                {
+                    let orientation_angle = (rotation_matrix * Vec2::RIGHT).angle();
+
+                    WidgetShape::Circle.paint_arc(
+                        ui,
+                        rect.center(),
+                        0.0,
+                        radius,
+                        orientation_angle + (start - rotation) * winding.to_float(),
+                        orientation_angle + (start + sweep - rotation) * winding.to_float(),
+                        ui.style().visuals.selection.bg_fill.linear_multiply(0.25), // TODO: Semantically correct color
+                        ui.style().visuals.selection.stroke, // TODO: Semantically correct color
+                        rotation_matrix,
+                    );
+                }
+            }
+
             if self.show_axes {
+                let major_spacing = self.axis_labels.major_spacing_degrees() as f32;
+
                 for (axis_index, axis_label) in self.axis_labels.inner.iter().enumerate() {
-                    let axis_angle =
-                        axis_index as f32 * (TAU / (self.axis_labels.inner.len() as f32));
-
-                    ui.painter().add(Shape::line_segment(
-                        [
-                            rect.center(),
-                            rect.center() + angle_to_direction(axis_angle) * radius,
-                        ],
-                        visuals.fg_stroke, // TODO: Semantically correct color
-                    ));
+                    let axis_degrees = axis_index as f32 * self.axis_labels.spacing_degrees();
+                    let axis_angle = axis_degrees.to_radians();
+
+                    // Only major directions get an axis line, intermediate winds are just labelled
+                    if axis_degrees % major_spacing == 0.0 {
+                        ui.painter().add(Shape::line_segment(
+                            [
+                                rect.center(),
+                                rect.center() + angle_to_direction(axis_angle) * radius,
+                            ],
+                            visuals.fg_stroke, // TODO: Semantically correct color
+                        ));
+                    }
+
+                    let label_height =
+                        self.axis_label_height * self.axis_labels.label_scale(axis_index);
 
                     ui.painter().rotated_text(
                         rect.center()
-                            + angle_to_direction(axis_angle)
-                                * (radius + self.axis_label_height / 2.0),
+                            + angle_to_direction(axis_angle) * (radius + label_height / 2.0),
                         Align2::CENTER_CENTER,
                         axis_label,
-                        FontId::new(self.axis_label_height, FontFamily::Proportional),
+                        FontId::new(label_height, FontFamily::Proportional),
                         visuals.text_color(), // TODO: Semantically correct color
                         angle_to_direction(axis_angle).angle() + (TAU / 4.0),
                     );
                 }
             }
 
-            for marker in self.markers {
+            // Center and size of the marker as drawn, `None` when it is clipped
+            let marker_geometry = |marker: &CompassMarker| {
                 let marker_distance = marker.distance.expect("marker has no distance");
 
                 if (marker_distance > self.max_distance)
                     && (self.overflow == PolarCompassOverflow::Clip)
                 {
-                    continue;
+                    return None;
                 }
 
+                let marker_t = self.distance_to_t(marker_distance).clamp(0.0, 1.0);
+
+                let marker_center = rect.center()
+                    + angle_to_direction(marker_bearing(marker, rotation)) * (radius * marker_t);
+                let marker_size = lerp(self.marker_near_size..=self.marker_far_size, marker_t);
+
+                Some((marker_center, marker_size))
+            };
+
+            marker_positions = self
+                .markers
+                .iter()
+                .map(|marker| marker_geometry(marker).map(|(marker_center, _)| marker_center))
+                .collect();
+
+            // Overlapping markers resolve to the nearest one, then to the one nearest
+            // to the pointer
+            let marker_at = |pointer_pos: Pos2| {
+                self.markers
+                    .iter()
+                    .enumerate()
+                    .filter_map(|(marker_index, marker)| {
+                        let (marker_center, marker_size) = marker_geometry(marker)?;
+                        let pointer_distance = marker_center.distance(pointer_pos);
+                        (pointer_distance <= marker_size / 2.0).then_some((
+                            marker_index,
+                            marker.distance.unwrap_or(0.0),
+                            pointer_distance,
+                        ))
+                    })
+                    .min_by(|(_, a_distance, a_pointer), (_, b_distance, b_pointer)| {
+                        a_distance
+                            .total_cmp(b_distance)
+                            .then(a_pointer.total_cmp(b_pointer))
+                    })
+                    .map(|(marker_index, _, _)| marker_index)
+            };
+
+            hovered_marker = response.hover_pos().and_then(marker_at);
+
+            if response.clicked() {
+                clicked_marker = response.interact_pointer_pos().and_then(marker_at);
+            }
+
+            if let (true, Some(marker_index), false) = (
+                self.show_marker_tooltips,
+                hovered_marker,
+                response.dragged(),
+            ) {
+                let marker = &self.markers[marker_index];
+                let marker_distance = marker.distance.unwrap_or(0.0);
+
+                let distance_text = match &self.distance_formatter {
+                    Some(distance_formatter) => distance_formatter(marker_distance),
+                    None => format!("{marker_distance:.1}"),
+                };
+
+                show_tooltip_at_pointer(ui.ctx(), response.id.with("marker_tooltip"), |ui| {
+                    if let Some(label) = marker.label.as_deref() {
+                        ui.label(label);
+                    }
+
+                    ui.label(format!(
+                        "{} / {distance_text}",
+                        localize(
+                            ui.ctx(),
+                            XtText::Degrees(self.wrap.wrap_angle(marker.angle).to_degrees())
+                        )
+                    ));
+                });
+            }
+
+            for (marker_index, marker) in self.markers.iter().enumerate() {
+                let Some((marker_center, marker_size)) = marker_geometry(marker) else {
+                    continue;
+                };
+
                 let marker_color = marker
                     .color
                     .unwrap_or_else(|| self.default_marker_color.color(ui, marker));
@@ -418,15 +868,6 @@ impl<'a> Widget for PolarCompass<'a> {
                     Stroke::new(1.0, stroke_color)
                 };
 
-                let max_log = (self.max_distance / self.scale_log_mult).symlog(self.scale_log_base);
-                let marker_log =
-                    (marker_distance / self.scale_log_mult).symlog(self.scale_log_base);
-                let marker_t = (marker_log / max_log).clamp(0.0, 1.0);
-
-                let marker_center =
-                    rect.center() + angle_to_direction(marker.angle) * (radius * marker_t);
-                let marker_size = lerp(self.marker_near_size..=self.marker_far_size, marker_t);
-
                 if self.show_marker_lines {
                     ui.painter().add(Shape::dashed_line(
                         &[rect.center(), marker_center],
@@ -445,22 +886,46 @@ impl<'a> Widget for PolarCompass<'a> {
                     marker_stroke,
                 );
 
-                if self.show_marker_labels {
-                    let label_center = marker_center + Vec2::DOWN * marker_size;
-
-                    if let Some(marker_label) = marker.label {
-                        ui.painter().text(
-                            label_center,
-                            Align2::CENTER_CENTER,
-                            marker_label,
-                            FontId::new(marker_size, FontFamily::Proportional),
-                            marker_color,
-                        );
-                    }
+                let show_label = match self.show_marker_labels {
+                    PolarCompassLabelVisibility::Never => false,
+                    PolarCompassLabelVisibility::Always => true,
+                    PolarCompassLabelVisibility::HoverOnly => hovered_marker == Some(marker_index),
+                };
+
+                if let (true, Some(marker_label)) = (show_label, marker.label.as_deref()) {
+                    let label_galley = ui.painter().layout_no_wrap(
+                        marker_label.to_owned(),
+                        FontId::new(self.axis_label_height / 2.0, FontFamily::Proportional),
+                        marker_stroke.color,
+                    );
+
+                    // Pushed outwards until the label clears the marker,
+                    // markers in the center are labelled below
+                    let outward = if marker_center == rect.center() {
+                        Vec2::DOWN
+                    } else {
+                        (marker_center - rect.center()).normalized()
+                    };
+
+                    let label_extent = (outward * label_galley.size() / 2.0).abs();
+                    let label_offset = marker_size / 2.0 + label_extent.x + label_extent.y + 2.0;
+
+                    let label_rect = Align2::CENTER_CENTER.anchor_rect(Rect::from_min_size(
+                        marker_center + outward * label_offset,
+                        label_galley.size(),
+                    ));
+
+                    ui.painter().galley(label_rect.min, label_galley);
                 }
             }
         }
 
-        response
+        PolarCompassResponse {
+            response,
+            selected_markers,
+            hovered_marker,
+            clicked_marker,
+            marker_positions,
+        }
     }
 }
@@ -0,0 +1,112 @@
+use std::f32::consts::TAU;
+
+use egui::Context;
+
+use crate::localization::localize;
+use crate::XtText;
+
+// ----------------------------------------------------------------------------
+
+type TickFormatter<'a> = Box<dyn 'a + Fn(f32) -> String>;
+
+// ----------------------------------------------------------------------------
+
+/// Tick spacing of a compass scale, with the steps in radians.
+///
+/// Each step should be a multiple of the previous one. Directions labelled by the
+/// axis labels are always drawn as the largest ticks.
+pub struct TickSpec<'a> {
+    minor: f32,
+    medium: f32,
+    major: f32,
+    formatter: Option<TickFormatter<'a>>,
+}
+
+impl<'a> Default for TickSpec<'a> {
+    fn default() -> Self {
+        Self::degrees()
+    }
+}
+
+impl<'a> TickSpec<'a> {
+    pub fn new(minor: f32, medium: f32, major: f32) -> Self {
+        assert!(minor > 0.0);
+        assert!(medium >= minor);
+        assert!(major >= medium);
+
+        Self {
+            minor,
+            medium,
+            major,
+            formatter: None,
+        }
+    }
+
+    /// Ticks every 5°, 10° and 30°.
+    pub fn degrees() -> Self {
+        Self::new(
+            5.0f32.to_radians(),
+            10.0f32.to_radians(),
+            30.0f32.to_radians(),
+        )
+    }
+
+    /// NATO mils (6400 per turn), ticks every 100, 200 and 800 mils.
+    pub fn mils() -> Self {
+        let mil = TAU / 6400.0;
+
+        Self::new(100.0 * mil, 200.0 * mil, 800.0 * mil)
+            .formatter(move |angle| format!("{:.0} mil", angle / mil))
+    }
+
+    /// Gradians (400 per turn), ticks every 5, 10 and 50 gradians.
+    pub fn grads() -> Self {
+        let grad = TAU / 400.0;
+
+        Self::new(5.0 * grad, 10.0 * grad, 50.0 * grad)
+            .formatter(move |angle| format!("{:.1} gon", angle / grad))
+    }
+
+    /// Formats angles read out by the widget, like the cursor value.
+    /// Defaults to degrees.
+    pub fn formatter(mut self, formatter: impl 'a + Fn(f32) -> String) -> Self {
+        self.formatter = Some(Box::new(formatter));
+        self
+    }
+
+    pub(crate) fn format(&self, ctx: &Context, angle: f32) -> String {
+        match &self.formatter {
+            Some(formatter) => formatter(angle),
+            None => localize(ctx, XtText::Degrees(angle.to_degrees())),
+        }
+    }
+
+    /// Relative length of the tick at `angle`, `None` between minor ticks.
+    pub(crate) fn tick_scale(&self, angle: f32) -> Option<f32> {
+        let is_multiple_of = |step: f32| {
+            let steps = angle / step;
+            (steps - steps.round()).abs() * step < self.minor * 1e-3
+        };
+
+        if is_multiple_of(self.major) {
+            Some(0.75)
+        } else if is_multiple_of(self.medium) {
+            Some(0.5)
+        } else if is_multiple_of(self.minor) {
+            Some(0.3)
+        } else {
+            None
+        }
+    }
+
+    /// Angles of the minor ticks between `start` and `end`, extended by one major
+    /// step on both sides so labels near the edges are not cut off.
+    pub(crate) fn tick_angles(&self, start: f32, end: f32) -> impl Iterator<Item = f32> {
+        let minor = self.minor;
+
+        let first = ((start - self.major) / minor).floor() as isize;
+        let last = ((end + self.major) / minor).ceil() as isize;
+
+        (first..=last).map(move |tick| tick as f32 * minor)
+    }
+}
@@ -1,15 +1,33 @@
 use egui::{lerp, Color32, Rgba, Stroke, Ui};
 use strum::{Display, EnumIter};
 
+use crate::xt_style;
+
 // ----------------------------------------------------------------------------
 
 #[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub struct DisplayStyle {
     pub background_color: Color32,
     pub active_foreground_color: Color32,
     pub active_foreground_stroke: Stroke,
     pub inactive_foreground_color: Color32,
     pub inactive_foreground_stroke: Stroke,
+
+    /// Multiplier of the active foreground alpha, for simulating dimmed displays.
+    #[cfg_attr(feature = "serde", serde(default = "default_brightness"))]
+    pub brightness: f32,
+
+    /// Amplitude of the random dimming of lit segments towards the inactive color,
+    /// for simulating the unstable drive of CRTs and VFDs.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub flicker: Option<f32>,
+}
+
+/// Styles serialized before `brightness` was added are shown at full brightness.
+#[cfg(feature = "serde")]
+fn default_brightness() -> f32 {
+    1.0
 }
 
 impl DisplayStyle {
@@ -17,6 +35,7 @@ impl DisplayStyle {
     pub fn foreground_color(&self, active: bool) -> Color32 {
         if active {
             self.active_foreground_color
+                .linear_multiply(self.brightness)
         } else {
             self.inactive_foreground_color
         }
@@ -25,7 +44,12 @@ impl DisplayStyle {
     #[must_use]
     pub fn foreground_stroke(&self, active: bool) -> Stroke {
         if active {
-            self.active_foreground_stroke
+            Stroke::new(
+                self.active_foreground_stroke.width,
+                self.active_foreground_stroke
+                    .color
+                    .linear_multiply(self.brightness),
+            )
         } else {
             self.inactive_foreground_stroke
         }
@@ -34,35 +58,82 @@ impl DisplayStyle {
     #[must_use]
     pub fn foreground_color_blend(&self, value: f32) -> Color32 {
         Color32::from(lerp(
-            Rgba::from(self.inactive_foreground_color)..=Rgba::from(self.active_foreground_color),
+            Rgba::from(self.foreground_color(false))..=Rgba::from(self.foreground_color(true)),
             value,
         ))
     }
 
     #[must_use]
     pub fn foreground_stroke_blend(&self, value: f32) -> Stroke {
+        let (inactive_stroke, active_stroke) =
+            (self.foreground_stroke(false), self.foreground_stroke(true));
+
         Stroke::new(
-            lerp(
-                self.inactive_foreground_stroke.width..=self.active_foreground_stroke.width,
-                value,
-            ),
+            lerp(inactive_stroke.width..=active_stroke.width, value),
             Color32::from(lerp(
-                Rgba::from(self.inactive_foreground_stroke.color)
-                    ..=Rgba::from(self.active_foreground_stroke.color),
+                Rgba::from(inactive_stroke.color)..=Rgba::from(active_stroke.color),
                 value,
             )),
         )
     }
 
+    /// Blends the colors, stroke widths and brightness towards `other`.
     #[must_use]
-    pub fn system_style(ui: &Ui) -> Self {
+    pub fn lerp(&self, other: &DisplayStyle, t: f32) -> DisplayStyle {
+        let lerp_color =
+            |a: Color32, b: Color32| Color32::from(lerp(Rgba::from(a)..=Rgba::from(b), t));
+
+        let lerp_stroke = |a: Stroke, b: Stroke| {
+            Stroke::new(lerp(a.width..=b.width, t), lerp_color(a.color, b.color))
+        };
+
         DisplayStyle {
+            background_color: lerp_color(self.background_color, other.background_color),
+            active_foreground_color: lerp_color(
+                self.active_foreground_color,
+                other.active_foreground_color,
+            ),
+            active_foreground_stroke: lerp_stroke(
+                self.active_foreground_stroke,
+                other.active_foreground_stroke,
+            ),
+            inactive_foreground_color: lerp_color(
+                self.inactive_foreground_color,
+                other.inactive_foreground_color,
+            ),
+            inactive_foreground_stroke: lerp_stroke(
+                self.inactive_foreground_stroke,
+                other.inactive_foreground_stroke,
+            ),
+            brightness: lerp(self.brightness..=other.brightness, t),
+            flicker: match (self.flicker, other.flicker) {
+                (Some(a), Some(b)) => Some(lerp(a..=b, t)),
+                _ if t < 0.5 => self.flicker,
+                _ => other.flicker,
+            },
+        }
+    }
+
+    /// Style following the egui theme, dimmed by the crate-level backlight,
+    /// see [`crate::set_backlight`].
+    #[must_use]
+    pub fn system_style(ui: &Ui) -> Self {
+        let day_style = DisplayStyle {
             background_color: Color32::TRANSPARENT,
             active_foreground_color: ui.style().visuals.text_color(),
             active_foreground_stroke: Stroke::NONE,
             inactive_foreground_color: ui.style().visuals.faint_bg_color,
             inactive_foreground_stroke: Stroke::NONE,
-        }
+            brightness: 1.0,
+            flicker: None,
+        };
+
+        let night_style = DisplayStyle {
+            brightness: 0.25,
+            ..day_style
+        };
+
+        night_style.lerp(&day_style, xt_style(ui.ctx()).backlight)
     }
 }
 
@@ -76,6 +147,7 @@ impl Default for DisplayStyle {
 
 #[non_exhaustive]
 #[derive(Clone, Copy, Debug, Display, EnumIter, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub enum DisplayStylePreset {
     #[strum(to_string = "Default")]
     Default,
@@ -121,6 +193,8 @@ impl DisplayStylePreset {
                 active_foreground_stroke: Stroke::NONE,
                 inactive_foreground_color: Color32::from_rgb(0x00, 0x30, 0x00),
                 inactive_foreground_stroke: Stroke::NONE,
+                brightness: 1.0,
+                flicker: None,
             },
             DisplayStylePreset::Calculator => DisplayStyle {
                 background_color: Color32::from_rgb(0xC5, 0xCB, 0xB6),
@@ -128,6 +202,8 @@ impl DisplayStylePreset {
                 active_foreground_stroke: Stroke::NONE,
                 inactive_foreground_color: Color32::from_rgb(0xB9, 0xBE, 0xAB),
                 inactive_foreground_stroke: Stroke::NONE,
+                brightness: 1.0,
+                flicker: None,
             },
             DisplayStylePreset::NintendoGameBoy => DisplayStyle {
                 background_color: Color32::from_rgb(0x9B, 0xBC, 0x0F),
@@ -135,6 +211,8 @@ impl DisplayStylePreset {
                 active_foreground_stroke: Stroke::NONE,
                 inactive_foreground_color: Color32::from_rgb(0x8B, 0xAC, 0x0F),
                 inactive_foreground_stroke: Stroke::NONE,
+                brightness: 1.0,
+                flicker: None,
             },
             DisplayStylePreset::KnightRider => DisplayStyle {
                 background_color: Color32::from_rgb(0x10, 0x00, 0x00),
@@ -142,6 +220,8 @@ impl DisplayStylePreset {
                 active_foreground_stroke: Stroke::NONE,
                 inactive_foreground_color: Color32::from_rgb(0x20, 0x00, 0x00),
                 inactive_foreground_stroke: Stroke::NONE,
+                brightness: 1.0,
+                flicker: None,
             },
             DisplayStylePreset::BlueNegative => DisplayStyle {
                 background_color: Color32::from_rgb(0x00, 0x00, 0xFF),
@@ -149,6 +229,8 @@ impl DisplayStylePreset {
                 active_foreground_stroke: Stroke::NONE,
                 inactive_foreground_color: Color32::from_rgb(0x28, 0x28, 0xFF),
                 inactive_foreground_stroke: Stroke::NONE,
+                brightness: 1.0,
+                flicker: None,
             },
             DisplayStylePreset::Amber => DisplayStyle {
                 background_color: Color32::from_rgb(0x1D, 0x12, 0x07),
@@ -156,6 +238,8 @@ impl DisplayStylePreset {
                 active_foreground_stroke: Stroke::NONE,
                 inactive_foreground_color: Color32::from_rgb(0x33, 0x20, 0x00),
                 inactive_foreground_stroke: Stroke::NONE,
+                brightness: 1.0,
+                flicker: None,
             },
             DisplayStylePreset::LightBlue => DisplayStyle {
                 background_color: Color32::from_rgb(0x0F, 0xB0, 0xBC),
@@ -163,6 +247,8 @@ impl DisplayStylePreset {
                 active_foreground_stroke: Stroke::NONE,
                 inactive_foreground_color: Color32::from_black_alpha(60),
                 inactive_foreground_stroke: Stroke::NONE,
+                brightness: 1.0,
+                flicker: None,
             },
             DisplayStylePreset::DeLoreanRed => DisplayStyle {
                 background_color: Color32::from_rgb(0x12, 0x07, 0x0A),
@@ -170,6 +256,8 @@ impl DisplayStylePreset {
                 active_foreground_stroke: Stroke::NONE,
                 inactive_foreground_color: Color32::from_rgb(0x48, 0x0A, 0x0B),
                 inactive_foreground_stroke: Stroke::NONE,
+                brightness: 1.0,
+                flicker: None,
             },
             DisplayStylePreset::DeLoreanGreen => DisplayStyle {
                 background_color: Color32::from_rgb(0x05, 0x0A, 0x0A),
@@ -177,6 +265,8 @@ impl DisplayStylePreset {
                 active_foreground_stroke: Stroke::NONE,
                 inactive_foreground_color: Color32::from_rgb(0x07, 0x29, 0x0F),
                 inactive_foreground_stroke: Stroke::NONE,
+                brightness: 1.0,
+                flicker: None,
             },
             DisplayStylePreset::DeLoreanAmber => DisplayStyle {
                 background_color: Color32::from_rgb(0x08, 0x08, 0x0B),
@@ -184,6 +274,8 @@ impl DisplayStylePreset {
                 active_foreground_stroke: Stroke::NONE,
                 inactive_foreground_color: Color32::from_rgb(0x51, 0x2C, 0x0F),
                 inactive_foreground_stroke: Stroke::NONE,
+                brightness: 1.0,
+                flicker: None,
             },
             DisplayStylePreset::YamahaMU2000 => DisplayStyle {
                 background_color: Color32::from_rgb(0x8C, 0xD7, 0x01),
@@ -191,6 +283,8 @@ impl DisplayStylePreset {
                 active_foreground_stroke: Stroke::NONE,
                 inactive_foreground_color: Color32::from_rgb(0x7B, 0xCE, 0x02),
                 inactive_foreground_stroke: Stroke::NONE,
+                brightness: 1.0,
+                flicker: None,
             },
         }
     }
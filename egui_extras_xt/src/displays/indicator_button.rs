@@ -34,8 +34,8 @@ pub enum IndicatorButtonBehavior {
 #[must_use = "You should put this widget in an ui with `ui.add(widget);`"]
 pub struct IndicatorButton<'a> {
     get_set_value: GetSetValue<'a>,
-    width: f32,
-    height: f32,
+    width: Option<f32>,
+    height: Option<f32>,
     label: Option<String>,
     style: DisplayStyle,
     animated: bool,
@@ -65,8 +65,8 @@ impl<'a> IndicatorButton<'a> {
     pub fn from_get_set(get_set_value: impl 'a + FnMut(Option<bool>) -> bool) -> Self {
         Self {
             get_set_value: Box::new(get_set_value),
-            width: 64.0,
-            height: 40.0,
+            width: None,
+            height: None,
             label: None,
             style: DisplayStylePreset::Default.style(),
             animated: true,
@@ -76,13 +76,15 @@ impl<'a> IndicatorButton<'a> {
         }
     }
 
+    /// Defaults to 1.6 times the width of `ui.spacing().interact_size`.
     pub fn width(mut self, width: impl Into<f32>) -> Self {
-        self.width = width.into();
+        self.width = Some(width.into());
         self
     }
 
+    /// Defaults to twice the height of `ui.spacing().interact_size`.
     pub fn height(mut self, height: impl Into<f32>) -> Self {
-        self.height = height.into();
+        self.height = Some(height.into());
         self
     }
 
@@ -124,7 +126,10 @@ impl<'a> IndicatorButton<'a> {
 
 impl<'a> Widget for IndicatorButton<'a> {
     fn ui(mut self, ui: &mut Ui) -> Response {
-        let desired_size = vec2(self.width, self.height);
+        let width = self.width.unwrap_or(ui.spacing().interact_size.x * 1.6);
+        let height = self.height.unwrap_or(ui.spacing().interact_size.y * 2.0);
+
+        let desired_size = vec2(width, height);
 
         let (rect, mut response) = ui.allocate_exact_size(
             desired_size,
@@ -189,7 +194,7 @@ impl<'a> Widget for IndicatorButton<'a> {
             let top_rect = Rect::from_min_max(rect.left_top(), rect.right_center());
             let bottom_rect = Rect::from_min_max(rect.left_center(), rect.right_bottom());
 
-            let margin = (self.height / 2.0) * self.margin;
+            let margin = (height / 2.0) * self.margin;
 
             {
                 let indicator_rect = if self.label.is_some() { top_rect } else { rect };
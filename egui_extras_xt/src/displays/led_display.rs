@@ -9,7 +9,7 @@ use crate::displays::{DisplayStyle, DisplayStylePreset};
 #[must_use = "You should put this widget in an ui with `ui.add(widget);`"]
 pub struct LedDisplay {
     value: f32,
-    diameter: f32,
+    diameter: Option<f32>,
     padding: f32,
     range: RangeInclusive<f32>,
     style: DisplayStyle,
@@ -20,7 +20,7 @@ impl LedDisplay {
     pub fn new(value: f32) -> Self {
         Self {
             value,
-            diameter: 16.0,
+            diameter: None,
             padding: 0.25,
             range: 0.0..=1.0,
             style: DisplayStylePreset::Default.style(),
@@ -32,8 +32,9 @@ impl LedDisplay {
         Self::new(if value { 1.0 } else { 0.0 })
     }
 
+    /// Defaults to fit the padded LED into the height of `ui.spacing().interact_size`.
     pub fn diameter(mut self, diameter: impl Into<f32>) -> Self {
-        self.diameter = diameter.into();
+        self.diameter = Some(diameter.into());
         self
     }
 
@@ -65,7 +66,11 @@ impl LedDisplay {
 
 impl Widget for LedDisplay {
     fn ui(self, ui: &mut Ui) -> Response {
-        let desired_size = Vec2::splat(self.diameter + self.padding * self.diameter);
+        let diameter = self
+            .diameter
+            .unwrap_or(ui.spacing().interact_size.y / (1.0 + self.padding));
+
+        let desired_size = Vec2::splat(diameter + self.padding * diameter);
 
         let (rect, response) = ui.allocate_exact_size(desired_size, Sense::hover());
 
@@ -90,7 +95,7 @@ impl Widget for LedDisplay {
 
             ui.painter().circle(
                 rect.center(),
-                self.diameter / 2.0,
+                diameter / 2.0,
                 self.style.foreground_color_blend(value),
                 self.style.foreground_stroke_blend(value),
             );
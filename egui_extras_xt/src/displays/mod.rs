@@ -8,5 +8,7 @@ pub mod segmented_display;
 pub use display_style::{DisplayStyle, DisplayStylePreset};
 pub use indicator_button::{IndicatorButton, IndicatorButtonBehavior};
 pub use led_display::LedDisplay;
-pub use segmented_display::{DisplayKind, DisplayMetrics, SegmentedDisplayWidget};
+pub use segmented_display::{
+    DisplayAlignment, DisplayKind, DisplayMetrics, SegmentedDisplayWidget,
+};
 pub use waveform_display::{BufferLayout, SignalEdge, WaveformDisplayWidget};
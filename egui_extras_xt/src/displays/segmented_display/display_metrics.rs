@@ -1,6 +1,7 @@
 use strum::{Display, EnumIter};
 
 #[derive(Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub struct DisplayMetrics {
     pub segment_spacing: f32,
     pub segment_thickness: f32,
@@ -26,6 +27,7 @@ impl Default for DisplayMetrics {
 
 #[non_exhaustive]
 #[derive(Clone, Copy, Display, EnumIter, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub enum DisplayMetricsPreset {
     #[strum(to_string = "Default")]
     Default,
@@ -0,0 +1,185 @@
+use std::f32::consts::TAU;
+
+use egui::{pos2, Pos2, Vec2};
+use itertools::Itertools;
+
+use crate::displays::segmented_display::{DisplayGlyph, DisplayImpl};
+
+// ----------------------------------------------------------------------------
+
+/// Grid of 5×7 round dots, numbered row by row from the top left corner.
+#[derive(Clone, Copy, Default)]
+pub struct DotMatrix5x7;
+
+impl DotMatrix5x7 {
+    const COLUMNS: usize = 5;
+    const ROWS: usize = 7;
+
+    /// Corners of the polygons approximating the dots
+    const DOT_RESOLUTION: usize = 12;
+}
+
+impl DisplayImpl for DotMatrix5x7 {
+    fn segment_count(&self) -> usize {
+        Self::COLUMNS * Self::ROWS
+    }
+
+    fn glyph(&self, c: char) -> Option<DisplayGlyph> {
+        // Rows from top to bottom, the most significant bit is the leftmost column
+        #[rustfmt::skip]
+        const GLYPH_DATA: &[(char, [u8; DotMatrix5x7::ROWS])] = &[
+            // Basic Latin
+            (' ',  [0b00000, 0b00000, 0b00000, 0b00000, 0b00000, 0b00000, 0b00000]),
+            ('!',  [0b00100, 0b00100, 0b00100, 0b00100, 0b00000, 0b00000, 0b00100]),
+            ('"',  [0b01010, 0b01010, 0b01010, 0b00000, 0b00000, 0b00000, 0b00000]),
+            ('#',  [0b01010, 0b01010, 0b11111, 0b01010, 0b11111, 0b01010, 0b01010]),
+            ('$',  [0b00100, 0b01111, 0b10100, 0b01110, 0b00101, 0b11110, 0b00100]),
+            ('%',  [0b11000, 0b11001, 0b00010, 0b00100, 0b01000, 0b10011, 0b00011]),
+            ('&',  [0b01100, 0b10010, 0b10100, 0b01000, 0b10101, 0b10010, 0b01101]),
+            ('\'', [0b01100, 0b00100, 0b01000, 0b00000, 0b00000, 0b00000, 0b00000]),
+            ('(',  [0b00010, 0b00100, 0b01000, 0b01000, 0b01000, 0b00100, 0b00010]),
+            (')',  [0b01000, 0b00100, 0b00010, 0b00010, 0b00010, 0b00100, 0b01000]),
+            ('*',  [0b00000, 0b00100, 0b10101, 0b01110, 0b10101, 0b00100, 0b00000]),
+            ('+',  [0b00000, 0b00100, 0b00100, 0b11111, 0b00100, 0b00100, 0b00000]),
+            (',',  [0b00000, 0b00000, 0b00000, 0b00000, 0b01100, 0b00100, 0b01000]),
+            ('-',  [0b00000, 0b00000, 0b00000, 0b11111, 0b00000, 0b00000, 0b00000]),
+            ('.',  [0b00000, 0b00000, 0b00000, 0b00000, 0b00000, 0b01100, 0b01100]),
+            ('/',  [0b00000, 0b00001, 0b00010, 0b00100, 0b01000, 0b10000, 0b00000]),
+            ('0',  [0b01110, 0b10001, 0b10011, 0b10101, 0b11001, 0b10001, 0b01110]),
+            ('1',  [0b00100, 0b01100, 0b00100, 0b00100, 0b00100, 0b00100, 0b01110]),
+            ('2',  [0b01110, 0b10001, 0b00001, 0b00010, 0b00100, 0b01000, 0b11111]),
+            ('3',  [0b11111, 0b00010, 0b00100, 0b00010, 0b00001, 0b10001, 0b01110]),
+            ('4',  [0b00010, 0b00110, 0b01010, 0b10010, 0b11111, 0b00010, 0b00010]),
+            ('5',  [0b11111, 0b10000, 0b11110, 0b00001, 0b00001, 0b10001, 0b01110]),
+            ('6',  [0b00110, 0b01000, 0b10000, 0b11110, 0b10001, 0b10001, 0b01110]),
+            ('7',  [0b11111, 0b00001, 0b00010, 0b00100, 0b01000, 0b01000, 0b01000]),
+            ('8',  [0b01110, 0b10001, 0b10001, 0b01110, 0b10001, 0b10001, 0b01110]),
+            ('9',  [0b01110, 0b10001, 0b10001, 0b01111, 0b00001, 0b00010, 0b01100]),
+            (':',  [0b00000, 0b01100, 0b01100, 0b00000, 0b01100, 0b01100, 0b00000]),
+            (';',  [0b00000, 0b01100, 0b01100, 0b00000, 0b01100, 0b00100, 0b01000]),
+            ('<',  [0b00010, 0b00100, 0b01000, 0b10000, 0b01000, 0b00100, 0b00010]),
+            ('=',  [0b00000, 0b00000, 0b11111, 0b00000, 0b11111, 0b00000, 0b00000]),
+            ('>',  [0b01000, 0b00100, 0b00010, 0b00001, 0b00010, 0b00100, 0b01000]),
+            ('?',  [0b01110, 0b10001, 0b00001, 0b00010, 0b00100, 0b00000, 0b00100]),
+            ('@',  [0b01110, 0b10001, 0b00001, 0b01101, 0b10101, 0b10101, 0b01110]),
+            ('A',  [0b01110, 0b10001, 0b10001, 0b10001, 0b11111, 0b10001, 0b10001]),
+            ('B',  [0b11110, 0b10001, 0b10001, 0b11110, 0b10001, 0b10001, 0b11110]),
+            ('C',  [0b01110, 0b10001, 0b10000, 0b10000, 0b10000, 0b10001, 0b01110]),
+            ('D',  [0b11100, 0b10010, 0b10001, 0b10001, 0b10001, 0b10010, 0b11100]),
+            ('E',  [0b11111, 0b10000, 0b10000, 0b11110, 0b10000, 0b10000, 0b11111]),
+            ('F',  [0b11111, 0b10000, 0b10000, 0b11110, 0b10000, 0b10000, 0b10000]),
+            ('G',  [0b01110, 0b10001, 0b10000, 0b10111, 0b10001, 0b10001, 0b01111]),
+            ('H',  [0b10001, 0b10001, 0b10001, 0b11111, 0b10001, 0b10001, 0b10001]),
+            ('I',  [0b01110, 0b00100, 0b00100, 0b00100, 0b00100, 0b00100, 0b01110]),
+            ('J',  [0b00111, 0b00010, 0b00010, 0b00010, 0b00010, 0b10010, 0b01100]),
+            ('K',  [0b10001, 0b10010, 0b10100, 0b11000, 0b10100, 0b10010, 0b10001]),
+            ('L',  [0b10000, 0b10000, 0b10000, 0b10000, 0b10000, 0b10000, 0b11111]),
+            ('M',  [0b10001, 0b11011, 0b10101, 0b10101, 0b10001, 0b10001, 0b10001]),
+            ('N',  [0b10001, 0b10001, 0b11001, 0b10101, 0b10011, 0b10001, 0b10001]),
+            ('O',  [0b01110, 0b10001, 0b10001, 0b10001, 0b10001, 0b10001, 0b01110]),
+            ('P',  [0b11110, 0b10001, 0b10001, 0b11110, 0b10000, 0b10000, 0b10000]),
+            ('Q',  [0b01110, 0b10001, 0b10001, 0b10001, 0b10101, 0b10010, 0b01101]),
+            ('R',  [0b11110, 0b10001, 0b10001, 0b11110, 0b10100, 0b10010, 0b10001]),
+            ('S',  [0b01111, 0b10000, 0b10000, 0b01110, 0b00001, 0b00001, 0b11110]),
+            ('T',  [0b11111, 0b00100, 0b00100, 0b00100, 0b00100, 0b00100, 0b00100]),
+            ('U',  [0b10001, 0b10001, 0b10001, 0b10001, 0b10001, 0b10001, 0b01110]),
+            ('V',  [0b10001, 0b10001, 0b10001, 0b10001, 0b10001, 0b01010, 0b00100]),
+            ('W',  [0b10001, 0b10001, 0b10001, 0b10101, 0b10101, 0b10101, 0b01010]),
+            ('X',  [0b10001, 0b10001, 0b01010, 0b00100, 0b01010, 0b10001, 0b10001]),
+            ('Y',  [0b10001, 0b10001, 0b10001, 0b01010, 0b00100, 0b00100, 0b00100]),
+            ('Z',  [0b11111, 0b00001, 0b00010, 0b00100, 0b01000, 0b10000, 0b11111]),
+            ('[',  [0b01110, 0b01000, 0b01000, 0b01000, 0b01000, 0b01000, 0b01110]),
+            ('\\', [0b00000, 0b10000, 0b01000, 0b00100, 0b00010, 0b00001, 0b00000]),
+            (']',  [0b01110, 0b00010, 0b00010, 0b00010, 0b00010, 0b00010, 0b01110]),
+            ('^',  [0b00100, 0b01010, 0b10001, 0b00000, 0b00000, 0b00000, 0b00000]),
+            ('_',  [0b00000, 0b00000, 0b00000, 0b00000, 0b00000, 0b00000, 0b11111]),
+            ('`',  [0b01000, 0b00100, 0b00010, 0b00000, 0b00000, 0b00000, 0b00000]),
+            ('a',  [0b00000, 0b00000, 0b01110, 0b00001, 0b01111, 0b10001, 0b01111]),
+            ('b',  [0b10000, 0b10000, 0b10110, 0b11001, 0b10001, 0b10001, 0b11110]),
+            ('c',  [0b00000, 0b00000, 0b01110, 0b10000, 0b10000, 0b10001, 0b01110]),
+            ('d',  [0b00001, 0b00001, 0b01101, 0b10011, 0b10001, 0b10001, 0b01111]),
+            ('e',  [0b00000, 0b00000, 0b01110, 0b10001, 0b11111, 0b10000, 0b01110]),
+            ('f',  [0b00110, 0b01001, 0b01000, 0b11100, 0b01000, 0b01000, 0b01000]),
+            ('g',  [0b00000, 0b01111, 0b10001, 0b10001, 0b01111, 0b00001, 0b01110]),
+            ('h',  [0b10000, 0b10000, 0b10110, 0b11001, 0b10001, 0b10001, 0b10001]),
+            ('i',  [0b00100, 0b00000, 0b01100, 0b00100, 0b00100, 0b00100, 0b01110]),
+            ('j',  [0b00010, 0b00000, 0b00110, 0b00010, 0b00010, 0b10010, 0b01100]),
+            ('k',  [0b10000, 0b10000, 0b10010, 0b10100, 0b11000, 0b10100, 0b10010]),
+            ('l',  [0b01100, 0b00100, 0b00100, 0b00100, 0b00100, 0b00100, 0b01110]),
+            ('m',  [0b00000, 0b00000, 0b11010, 0b10101, 0b10101, 0b10001, 0b10001]),
+            ('n',  [0b00000, 0b00000, 0b10110, 0b11001, 0b10001, 0b10001, 0b10001]),
+            ('o',  [0b00000, 0b00000, 0b01110, 0b10001, 0b10001, 0b10001, 0b01110]),
+            ('p',  [0b00000, 0b00000, 0b11110, 0b10001, 0b11110, 0b10000, 0b10000]),
+            ('q',  [0b00000, 0b00000, 0b01101, 0b10011, 0b01111, 0b00001, 0b00001]),
+            ('r',  [0b00000, 0b00000, 0b10110, 0b11001, 0b10000, 0b10000, 0b10000]),
+            ('s',  [0b00000, 0b00000, 0b01110, 0b10000, 0b01110, 0b00001, 0b11110]),
+            ('t',  [0b01000, 0b01000, 0b11100, 0b01000, 0b01000, 0b01001, 0b00110]),
+            ('u',  [0b00000, 0b00000, 0b10001, 0b10001, 0b10001, 0b10011, 0b01101]),
+            ('v',  [0b00000, 0b00000, 0b10001, 0b10001, 0b10001, 0b01010, 0b00100]),
+            ('w',  [0b00000, 0b00000, 0b10001, 0b10001, 0b10101, 0b10101, 0b01010]),
+            ('x',  [0b00000, 0b00000, 0b10001, 0b01010, 0b00100, 0b01010, 0b10001]),
+            ('y',  [0b00000, 0b00000, 0b10001, 0b10001, 0b01111, 0b00001, 0b01110]),
+            ('z',  [0b00000, 0b00000, 0b11111, 0b00010, 0b00100, 0b01000, 0b11111]),
+            ('{',  [0b00010, 0b00100, 0b00100, 0b01000, 0b00100, 0b00100, 0b00010]),
+            ('|',  [0b00100, 0b00100, 0b00100, 0b00100, 0b00100, 0b00100, 0b00100]),
+            ('}',  [0b01000, 0b00100, 0b00100, 0b00010, 0b00100, 0b00100, 0b01000]),
+            ('~',  [0b00000, 0b00000, 0b00000, 0b01101, 0b10010, 0b00000, 0b00000]),
+        ];
+
+        debug_assert!(GLYPH_DATA
+            .iter()
+            .map(|(k, _)| k)
+            .tuple_windows()
+            .all(|(k1, k2)| k1 < k2)); // is_sorted()
+
+        GLYPH_DATA
+            .binary_search_by_key(&c, |(k, _)| *k)
+            .ok()
+            .map(|index| {
+                let rows = GLYPH_DATA[index].1;
+
+                (0..Self::ROWS)
+                    .cartesian_product(0..Self::COLUMNS)
+                    .enumerate()
+                    .filter(|(_, (row, column))| {
+                        (rows[*row] >> (Self::COLUMNS - 1 - column)) & 0x01 != 0x00
+                    })
+                    .fold(0, |glyph, (dot_index, _)| glyph | (1 << dot_index))
+            })
+    }
+
+    fn geometry(
+        &self,
+        digit_width: f32,
+        digit_height: f32,
+        segment_thickness: f32,
+        segment_spacing: f32,
+        _digit_median: f32,
+    ) -> Vec<Vec<Pos2>> {
+        let pitch_x = digit_width / Self::COLUMNS as f32;
+        let pitch_y = digit_height / Self::ROWS as f32;
+
+        // The dots are as thick as the segments of the other kinds, unless that
+        // would leave less than the segment spacing between neighbouring dots.
+        let dot_radius = (segment_thickness / 2.0)
+            .min((pitch_x.min(pitch_y) - segment_spacing) / 2.0)
+            .max(0.0);
+
+        (0..Self::ROWS)
+            .cartesian_product(0..Self::COLUMNS)
+            .map(|(row, column)| {
+                let dot_center = pos2(
+                    -(digit_width / 2.0) + pitch_x * (column as f32 + 0.5),
+                    -(digit_height / 2.0) + pitch_y * (row as f32 + 0.5),
+                );
+
+                (0..Self::DOT_RESOLUTION)
+                    .map(|i| {
+                        let t = (i as f32 / Self::DOT_RESOLUTION as f32) * TAU;
+                        dot_center + Vec2::angled(t) * dot_radius
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+}
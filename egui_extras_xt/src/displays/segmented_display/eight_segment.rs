@@ -0,0 +1,217 @@
+use egui::{pos2, Pos2};
+use itertools::Itertools;
+
+use crate::displays::segmented_display::{glyph_table, DisplayGlyph, DisplayImpl};
+
+// ----------------------------------------------------------------------------
+
+#[derive(Clone, Copy, Default)]
+pub struct EightSegment;
+
+impl EightSegment {
+    const A: DisplayGlyph = 1 << 0; // Top
+    const B: DisplayGlyph = 1 << 1; // Top right
+    const C: DisplayGlyph = 1 << 2; // Bottom right
+    const D: DisplayGlyph = 1 << 3; // Bottom
+    const E: DisplayGlyph = 1 << 4; // Bottom left
+    const F: DisplayGlyph = 1 << 5; // Top left
+    const G1: DisplayGlyph = 1 << 6; // Middle left
+    const G2: DisplayGlyph = 1 << 7; // Middle right
+}
+
+impl DisplayImpl for EightSegment {
+    fn segment_count(&self) -> usize {
+        8
+    }
+
+    fn glyph(&self, c: char) -> Option<DisplayGlyph> {
+        #[rustfmt::skip]
+        const GLYPH_DATA: &[(char, DisplayGlyph)] = &glyph_table!(EightSegment, [
+            // Basic Latin
+            (' ',  []),
+            ('!',  [E, F]),
+            ('"',  [B, F]),
+            ('#',  []),
+            ('$',  []),
+            ('%',  []),
+            ('&',  []),
+            ('\'', [B]),
+            ('(',  [A, D, E, F]),
+            (')',  [A, B, C, D]),
+            ('*',  []),
+            ('+',  []),
+            (',',  [C, D]),
+            ('-',  [G1, G2]),
+            ('.',  [C]),
+            ('/',  [B, E, G1, G2]),
+            ('0',  [A, B, C, D, E, F]),
+            ('1',  [B, C]),
+            ('2',  [A, B, D, E, G1, G2]),
+            ('3',  [A, B, C, D, G1, G2]),
+            ('4',  [B, C, F, G1, G2]),
+            ('5',  [A, C, D, F, G1, G2]),
+            ('6',  [A, C, D, E, F, G1, G2]),
+            ('7',  [A, B, C, F]),
+            ('8',  [A, B, C, D, E, F, G1, G2]),
+            ('9',  [A, B, C, D, F, G1, G2]),
+            (':',  [D, G1, G2]),
+            (';',  [D, G1, G2]),
+            ('<',  [A, D, E, F]),
+            ('=',  [D, G1, G2]),
+            ('>',  [A, B, C, D]),
+            ('?',  [A, B, E, G1, G2]),
+            ('@',  [A, B, D, E, F, G1, G2]),
+            ('A',  [A, B, C, E, F, G1, G2]),
+            ('B',  [C, D, E, F, G1, G2]),
+            ('C',  [A, D, E, F]),
+            ('D',  [B, C, D, E, G1, G2]),
+            ('E',  [A, D, E, F, G1, G2]),
+            ('F',  [A, E, F, G1, G2]),
+            ('G',  [A, C, D, E, F]),
+            ('H',  [B, C, E, F, G1, G2]),
+            ('I',  [E, F]),
+            ('J',  [B, C, D, E]),
+            ('K',  [B, C, E, F, G1, G2]),
+            ('L',  [D, E, F]),
+            ('M',  [A, B, D, F]),
+            ('N',  [A, B, C, E, F]),
+            ('O',  [A, B, C, D, E, F]),
+            ('P',  [A, B, E, F, G1, G2]),
+            ('Q',  [A, B, C, F, G1, G2]),
+            ('R',  [A, B, C, E, F, G1, G2]),
+            ('S',  [A, C, D, F, G1, G2]),
+            ('T',  [A, B, C]),
+            ('U',  [B, C, D, E, F]),
+            ('V',  [B, C, D, E, F]),
+            ('W',  [B, C, D, E, F, G1, G2]),
+            ('X',  [B, C, E, F, G1, G2]),
+            ('Y',  [B, C, D, F, G1, G2]),
+            ('Z',  [A, B, D, E, G1, G2]),
+            ('[',  [A, D, E, F]),
+            ('\\', [C, F, G1, G2]),
+            (']',  [A, B, C, D]),
+            ('^',  [A, B, F]),
+            ('_',  [D]),
+            ('`',  [F]),
+            ('a',  [A, B, C, D, E, G1, G2]),
+            ('b',  [C, D, E, F, G1, G2]),
+            ('c',  [D, E, G1, G2]),
+            ('d',  [B, C, D, E, G1, G2]),
+            ('e',  [A, B, D, E, F, G1, G2]),
+            ('f',  [A, E, F, G1, G2]),
+            ('g',  [A, B, C, D, F, G1, G2]),
+            ('h',  [C, E, F, G1, G2]),
+            ('i',  [E]),
+            ('j',  [B, C, D]),
+            ('k',  [B, C, E, F, G1, G2]),
+            ('l',  [B, C]),
+            ('m',  [A, C, E, G1, G2]),
+            ('n',  [C, E, G1, G2]),
+            ('o',  [C, D, E, G1, G2]),
+            ('p',  [A, B, E, F, G1, G2]),
+            ('q',  [A, B, C, F, G1, G2]),
+            ('r',  [E, G1]),
+            ('s',  [A, C, D, F, G1, G2]),
+            ('t',  [D, E, F, G1, G2]),
+            ('u',  [C, D, E]),
+            ('v',  [C, D, E]),
+            ('w',  [B, C, D, E, F, G1, G2]),
+            ('x',  [B, C, E, F, G1, G2]),
+            ('y',  [B, C, D, F, G1, G2]),
+            ('z',  [A, B, D, E, G1, G2]),
+            ('{',  [B, C, G1, G2]),
+            ('|',  [E, F]),
+            ('}',  [E, F, G1, G2]),
+            ('~',  [G1, G2]),
+        ]);
+
+        debug_assert!(GLYPH_DATA
+            .iter()
+            .map(|(k, _)| k)
+            .tuple_windows()
+            .all(|(k1, k2)| k1 < k2)); // is_sorted()
+
+        GLYPH_DATA
+            .binary_search_by_key(&c, |(k, _)| *k)
+            .ok()
+            .map(|index| GLYPH_DATA[index].1)
+    }
+
+    #[rustfmt::skip]
+    #[allow(unused_parens)]
+    fn geometry(
+        &self,
+        digit_width: f32,
+        digit_height: f32,
+        segment_thickness: f32,
+        segment_spacing: f32,
+        digit_median: f32,
+    ) -> Vec<Vec<Pos2>> {
+        vec![
+            vec![
+                pos2(-(digit_width / 2.0) + (segment_thickness / 4.0) + segment_spacing, -(digit_height / 2.0) + (segment_thickness / 4.0)                                 ),
+                pos2(-(digit_width / 2.0) + (segment_thickness / 2.0) + segment_spacing, -(digit_height / 2.0)                                                             ),
+                pos2( (digit_width / 2.0) - (segment_thickness / 2.0) - segment_spacing, -(digit_height / 2.0)                                                             ),
+                pos2( (digit_width / 2.0) - (segment_thickness / 4.0) - segment_spacing, -(digit_height / 2.0) + (segment_thickness / 4.0)                                 ),
+                pos2( (digit_width / 2.0) - (segment_thickness / 1.0) - segment_spacing, -(digit_height / 2.0) + (segment_thickness / 1.0)                                 ),
+                pos2(-(digit_width / 2.0) + (segment_thickness / 1.0) + segment_spacing, -(digit_height / 2.0) + (segment_thickness / 1.0)                                 ),
+            ],
+            vec![
+                pos2( (digit_width / 2.0) - (segment_thickness / 1.0)                  , -(digit_height / 2.0) + (segment_thickness / 1.0) + segment_spacing               ),
+                pos2( (digit_width / 2.0) - (segment_thickness / 4.0)                  , -(digit_height / 2.0) + (segment_thickness / 4.0) + segment_spacing               ),
+                pos2( (digit_width / 2.0)                                              , -(digit_height / 2.0) + (segment_thickness / 2.0) + segment_spacing               ),
+                pos2( (digit_width / 2.0)                                              ,                       - (segment_thickness / 2.0) - segment_spacing + digit_median),
+                pos2( (digit_width / 2.0) - (segment_thickness / 2.0)                  ,                                                   - segment_spacing + digit_median),
+                pos2( (digit_width / 2.0) - (segment_thickness / 1.0)                  ,                       - (segment_thickness / 2.0) - segment_spacing + digit_median),
+            ],
+            vec![
+                pos2( (digit_width / 2.0) - (segment_thickness / 1.0)                  ,  (digit_height / 2.0) - (segment_thickness / 1.0) - segment_spacing               ),
+                pos2( (digit_width / 2.0) - (segment_thickness / 4.0)                  ,  (digit_height / 2.0) - (segment_thickness / 4.0) - segment_spacing               ),
+                pos2( (digit_width / 2.0)                                              ,  (digit_height / 2.0) - (segment_thickness / 2.0) - segment_spacing               ),
+                pos2( (digit_width / 2.0)                                              ,                         (segment_thickness / 2.0) + segment_spacing + digit_median),
+                pos2( (digit_width / 2.0) - (segment_thickness / 2.0)                  ,                                                     segment_spacing + digit_median),
+                pos2( (digit_width / 2.0) - (segment_thickness / 1.0)                  ,                         (segment_thickness / 2.0) + segment_spacing + digit_median),
+            ],
+            vec![
+                pos2(-(digit_width / 2.0) + (segment_thickness / 4.0) + segment_spacing,  (digit_height / 2.0) - (segment_thickness / 4.0)                                 ),
+                pos2(-(digit_width / 2.0) + (segment_thickness / 2.0) + segment_spacing,  (digit_height / 2.0)                                                             ),
+                pos2( (digit_width / 2.0) - (segment_thickness / 2.0) - segment_spacing,  (digit_height / 2.0)                                                             ),
+                pos2( (digit_width / 2.0) - (segment_thickness / 4.0) - segment_spacing,  (digit_height / 2.0) - (segment_thickness / 4.0)                                 ),
+                pos2( (digit_width / 2.0) - (segment_thickness / 1.0) - segment_spacing,  (digit_height / 2.0) - (segment_thickness / 1.0)                                 ),
+                pos2(-(digit_width / 2.0) + (segment_thickness / 1.0) + segment_spacing,  (digit_height / 2.0) - (segment_thickness / 1.0)                                 ),
+            ],
+            vec![
+                pos2(-(digit_width / 2.0) + (segment_thickness / 1.0)                  ,  (digit_height / 2.0) - (segment_thickness / 1.0) - segment_spacing               ),
+                pos2(-(digit_width / 2.0) + (segment_thickness / 4.0)                  ,  (digit_height / 2.0) - (segment_thickness / 4.0) - segment_spacing               ),
+                pos2(-(digit_width / 2.0)                                              ,  (digit_height / 2.0) - (segment_thickness / 2.0) - segment_spacing               ),
+                pos2(-(digit_width / 2.0)                                              ,                         (segment_thickness / 2.0) + segment_spacing + digit_median),
+                pos2(-(digit_width / 2.0) + (segment_thickness / 2.0)                  ,                                                     segment_spacing + digit_median),
+                pos2(-(digit_width / 2.0) + (segment_thickness / 1.0)                  ,                         (segment_thickness / 2.0) + segment_spacing + digit_median),
+            ],
+            vec![
+                pos2(-(digit_width / 2.0) + (segment_thickness / 1.0)                  , -(digit_height / 2.0) + (segment_thickness / 1.0) + segment_spacing               ),
+                pos2(-(digit_width / 2.0) + (segment_thickness / 4.0)                  , -(digit_height / 2.0) + (segment_thickness / 4.0) + segment_spacing               ),
+                pos2(-(digit_width / 2.0)                                              , -(digit_height / 2.0) + (segment_thickness / 2.0) + segment_spacing               ),
+                pos2(-(digit_width / 2.0)                                              ,                       - (segment_thickness / 2.0) - segment_spacing + digit_median),
+                pos2(-(digit_width / 2.0) + (segment_thickness / 2.0)                  ,                                                   - segment_spacing + digit_median),
+                pos2(-(digit_width / 2.0) + (segment_thickness / 1.0)                  ,                       - (segment_thickness / 2.0) - segment_spacing + digit_median),
+            ],
+            vec![
+                pos2(-(digit_width / 2.0) + (segment_thickness / 2.0) + segment_spacing,                                                                       digit_median),
+                pos2(-(digit_width / 2.0) + (segment_thickness / 1.0) + segment_spacing,                       - (segment_thickness / 2.0)                   + digit_median),
+                pos2(                     - (segment_thickness / 2.0) - (segment_spacing / 2.0),                       - (segment_thickness / 2.0)                   + digit_median),
+                pos2(                                                 - (segment_spacing / 2.0),                                                                       digit_median),
+                pos2(                     - (segment_thickness / 2.0) - (segment_spacing / 2.0),                         (segment_thickness / 2.0)                   + digit_median),
+                pos2(-(digit_width / 2.0) + (segment_thickness / 1.0) + segment_spacing,                         (segment_thickness / 2.0)                   + digit_median),
+            ],
+            vec![
+                pos2(                                                   (segment_spacing / 2.0),                                                                       digit_median),
+                pos2(                       (segment_thickness / 2.0) + (segment_spacing / 2.0),                       - (segment_thickness / 2.0)                   + digit_median),
+                pos2( (digit_width / 2.0) - (segment_thickness / 1.0) - segment_spacing,                       - (segment_thickness / 2.0)                   + digit_median),
+                pos2( (digit_width / 2.0) - (segment_thickness / 2.0) - segment_spacing,                                                                       digit_median),
+                pos2( (digit_width / 2.0) - (segment_thickness / 1.0) - segment_spacing,                         (segment_thickness / 2.0)                   + digit_median),
+                pos2(                       (segment_thickness / 2.0) + (segment_spacing / 2.0),                         (segment_thickness / 2.0)                   + digit_median),
+            ],
+        ]
+    }
+}
@@ -1,6 +1,8 @@
 mod display_metrics;
 mod widget;
 
+mod dot_matrix;
+mod eight_segment;
 mod nine_segment;
 mod seven_segment;
 mod sixteen_segment;
@@ -14,7 +16,20 @@ use egui::Pos2;
 
 // ----------------------------------------------------------------------------
 
-pub type DisplayGlyph = u16;
+pub type DisplayGlyph = u64;
+
+/// Builds a `(char, DisplayGlyph)` lookup table where each glyph is written as
+/// a list of segment names, e.g. `('A', [A, B, C, E, F, G])`.
+///
+/// Segment names are resolved against the associated constants of the display
+/// implementation, so misspelled or foreign segments fail to compile.
+macro_rules! glyph_table {
+    ($display_impl:ident, [$(($c:literal, [$($segment:ident),*])),* $(,)?]) => {
+        [$(($c, 0 $(| $display_impl::$segment)*)),*]
+    };
+}
+
+pub(crate) use glyph_table;
 
 #[derive(Clone, Copy, Debug, Default)]
 pub struct DisplayDigit {
@@ -32,11 +47,19 @@ pub enum DisplayKind {
     #[strum(to_string = "7-segment")]
     SevenSegment,
 
+    /// Seven segments with the middle bar split into a left and a right half.
+    /// Unlike `NineSegment`, there are no diagonal segments.
+    #[strum(to_string = "8-segment")]
+    EightSegment,
+
     #[strum(to_string = "9-segment")]
     NineSegment,
 
     #[strum(to_string = "16-segment")]
     SixteenSegment,
+
+    #[strum(to_string = "5×7 dot matrix")]
+    DotMatrix5x7,
 }
 
 impl DisplayKind {
@@ -44,8 +67,10 @@ impl DisplayKind {
     pub(crate) fn display_impl(&self) -> Box<dyn DisplayImpl> {
         match *self {
             DisplayKind::SevenSegment => Box::new(seven_segment::SevenSegment),
+            DisplayKind::EightSegment => Box::new(eight_segment::EightSegment),
             DisplayKind::NineSegment => Box::new(nine_segment::NineSegment),
             DisplayKind::SixteenSegment => Box::new(sixteen_segment::SixteenSegment),
+            DisplayKind::DotMatrix5x7 => Box::new(dot_matrix::DotMatrix5x7),
         }
     }
 
@@ -57,6 +82,17 @@ impl DisplayKind {
 
 // ----------------------------------------------------------------------------
 
+#[derive(Clone, Copy, Debug, Display, EnumIter, Eq, PartialEq)]
+pub enum DisplayAlignment {
+    #[strum(to_string = "Left")]
+    Left,
+
+    #[strum(to_string = "Right")]
+    Right,
+}
+
+// ----------------------------------------------------------------------------
+
 pub(crate) trait DisplayImpl {
     fn segment_count(&self) -> usize;
 
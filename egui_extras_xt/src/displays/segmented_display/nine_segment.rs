@@ -1,13 +1,25 @@
 use egui::{pos2, Pos2};
 use itertools::Itertools;
 
-use crate::displays::segmented_display::{DisplayGlyph, DisplayImpl};
+use crate::displays::segmented_display::{glyph_table, DisplayGlyph, DisplayImpl};
 
 // ----------------------------------------------------------------------------
 
 #[derive(Clone, Copy, Default)]
 pub struct NineSegment;
 
+impl NineSegment {
+    const A: DisplayGlyph = 1 << 0; // Top
+    const B: DisplayGlyph = 1 << 1; // Top right
+    const C: DisplayGlyph = 1 << 2; // Bottom right
+    const D: DisplayGlyph = 1 << 3; // Bottom
+    const E: DisplayGlyph = 1 << 4; // Bottom left
+    const F: DisplayGlyph = 1 << 5; // Top left
+    const G: DisplayGlyph = 1 << 6; // Middle
+    const H: DisplayGlyph = 1 << 7; // Top right diagonal
+    const I: DisplayGlyph = 1 << 8; // Bottom left diagonal
+}
+
 impl DisplayImpl for NineSegment {
     fn segment_count(&self) -> usize {
         9
@@ -15,33 +27,104 @@ impl DisplayImpl for NineSegment {
 
     fn glyph(&self, c: char) -> Option<DisplayGlyph> {
         #[rustfmt::skip]
-        const GLYPH_DATA: &[(char, DisplayGlyph)] = &[
+        const GLYPH_DATA: &[(char, DisplayGlyph)] = &glyph_table!(NineSegment, [
             // Basic Latin
-            (' ',  0x0000), ('!',  0x0030), ('"',  0x0022), ('#',  0x0000),
-            ('$',  0x0000), ('%',  0x0000), ('&',  0x0000), ('\'', 0x0002),
-            ('(',  0x0039), (')',  0x000F), ('*',  0x0000), ('+',  0x0000),
-            (',',  0x000C), ('-',  0x0040), ('.',  0x0004), ('/',  0x0180),
-            ('0',  0x01BF), ('1',  0x0086), ('2',  0x005B), ('3',  0x004F),
-            ('4',  0x0066), ('5',  0x006D), ('6',  0x007D), ('7',  0x0027),
-            ('8',  0x007F), ('9',  0x006F), (':',  0x0048), (';',  0x0048),
-            ('<',  0x0039), ('=',  0x0048), ('>',  0x000F), ('?',  0x0053),
-            ('@',  0x007B), ('A',  0x0077), ('B',  0x00FD), ('C',  0x0039),
-            ('D',  0x005E), ('E',  0x0079), ('F',  0x0071), ('G',  0x003D),
-            ('H',  0x0076), ('I',  0x0030), ('J',  0x001E), ('K',  0x00F4),
-            ('L',  0x0038), ('M',  0x00B7), ('N',  0x0037), ('O',  0x003F),
-            ('P',  0x0073), ('Q',  0x013F), ('R',  0x00F5), ('S',  0x006D),
-            ('T',  0x0007), ('U',  0x003E), ('V',  0x01B0), ('W',  0x013E),
-            ('X',  0x0076), ('Y',  0x006E), ('Z',  0x0189), ('[',  0x0039),
-            ('\\', 0x0064), (']',  0x000F), ('^',  0x0082), ('_',  0x0008),
-            ('`',  0x0020), ('a',  0x005F), ('b',  0x007C), ('c',  0x0058),
-            ('d',  0x005E), ('e',  0x0158), ('f',  0x0071), ('g',  0x006F),
-            ('h',  0x0074), ('i',  0x0010), ('j',  0x000E), ('k',  0x00F4),
-            ('l',  0x0006), ('m',  0x0154), ('n',  0x0054), ('o',  0x005C),
-            ('p',  0x0073), ('q',  0x0067), ('r',  0x0050), ('s',  0x006D),
-            ('t',  0x0078), ('u',  0x001C), ('v',  0x0110), ('w',  0x011C),
-            ('x',  0x0076), ('y',  0x006E), ('z',  0x0148), ('{',  0x0046),
-            ('|',  0x0030), ('}',  0x0070), ('~',  0x0040),
-        ];
+            (' ',  []),
+            ('!',  [E, F]),
+            ('"',  [B, F]),
+            ('#',  []),
+            ('$',  []),
+            ('%',  []),
+            ('&',  []),
+            ('\'', [B]),
+            ('(',  [A, D, E, F]),
+            (')',  [A, B, C, D]),
+            ('*',  []),
+            ('+',  []),
+            (',',  [C, D]),
+            ('-',  [G]),
+            ('.',  [C]),
+            ('/',  [H, I]),
+            ('0',  [A, B, C, D, E, F, H, I]),
+            ('1',  [B, C, H]),
+            ('2',  [A, B, D, E, G]),
+            ('3',  [A, B, C, D, G]),
+            ('4',  [B, C, F, G]),
+            ('5',  [A, C, D, F, G]),
+            ('6',  [A, C, D, E, F, G]),
+            ('7',  [A, B, C, F]),
+            ('8',  [A, B, C, D, E, F, G]),
+            ('9',  [A, B, C, D, F, G]),
+            (':',  [D, G]),
+            (';',  [D, G]),
+            ('<',  [A, D, E, F]),
+            ('=',  [D, G]),
+            ('>',  [A, B, C, D]),
+            ('?',  [A, B, E, G]),
+            ('@',  [A, B, D, E, F, G]),
+            ('A',  [A, B, C, E, F, G]),
+            ('B',  [A, C, D, E, F, G, H]),
+            ('C',  [A, D, E, F]),
+            ('D',  [B, C, D, E, G]),
+            ('E',  [A, D, E, F, G]),
+            ('F',  [A, E, F, G]),
+            ('G',  [A, C, D, E, F]),
+            ('H',  [B, C, E, F, G]),
+            ('I',  [E, F]),
+            ('J',  [B, C, D, E]),
+            ('K',  [C, E, F, G, H]),
+            ('L',  [D, E, F]),
+            ('M',  [A, B, C, E, F, H]),
+            ('N',  [A, B, C, E, F]),
+            ('O',  [A, B, C, D, E, F]),
+            ('P',  [A, B, E, F, G]),
+            ('Q',  [A, B, C, D, E, F, I]),
+            ('R',  [A, C, E, F, G, H]),
+            ('S',  [A, C, D, F, G]),
+            ('T',  [A, B, C]),
+            ('U',  [B, C, D, E, F]),
+            ('V',  [E, F, H, I]),
+            ('W',  [B, C, D, E, F, I]),
+            ('X',  [B, C, E, F, G]),
+            ('Y',  [B, C, D, F, G]),
+            ('Z',  [A, D, H, I]),
+            ('[',  [A, D, E, F]),
+            ('\\', [C, F, G]),
+            (']',  [A, B, C, D]),
+            ('^',  [B, H]),
+            ('_',  [D]),
+            ('`',  [F]),
+            ('a',  [A, B, C, D, E, G]),
+            ('b',  [C, D, E, F, G]),
+            ('c',  [D, E, G]),
+            ('d',  [B, C, D, E, G]),
+            ('e',  [D, E, G, I]),
+            ('f',  [A, E, F, G]),
+            ('g',  [A, B, C, D, F, G]),
+            ('h',  [C, E, F, G]),
+            ('i',  [E]),
+            ('j',  [B, C, D]),
+            ('k',  [C, E, F, G, H]),
+            ('l',  [B, C]),
+            ('m',  [C, E, G, I]),
+            ('n',  [C, E, G]),
+            ('o',  [C, D, E, G]),
+            ('p',  [A, B, E, F, G]),
+            ('q',  [A, B, C, F, G]),
+            ('r',  [E, G]),
+            ('s',  [A, C, D, F, G]),
+            ('t',  [D, E, F, G]),
+            ('u',  [C, D, E]),
+            ('v',  [E, I]),
+            ('w',  [C, D, E, I]),
+            ('x',  [B, C, E, F, G]),
+            ('y',  [B, C, D, F, G]),
+            ('z',  [D, G, I]),
+            ('{',  [B, C, G]),
+            ('|',  [E, F]),
+            ('}',  [E, F, G]),
+            ('~',  [G]),
+        ]);
 
         debug_assert!(GLYPH_DATA
             .iter()
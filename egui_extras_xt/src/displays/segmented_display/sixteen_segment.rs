@@ -1,13 +1,32 @@
 use egui::{pos2, Pos2};
 use itertools::Itertools;
 
-use crate::displays::segmented_display::{DisplayGlyph, DisplayImpl};
+use crate::displays::segmented_display::{glyph_table, DisplayGlyph, DisplayImpl};
 
 // ----------------------------------------------------------------------------
 
 #[derive(Clone, Copy, Default)]
 pub struct SixteenSegment;
 
+impl SixteenSegment {
+    const A1: DisplayGlyph = 1 << 0; // Top left
+    const A2: DisplayGlyph = 1 << 1; // Top right
+    const B: DisplayGlyph = 1 << 2; // Right top
+    const C: DisplayGlyph = 1 << 3; // Right bottom
+    const D2: DisplayGlyph = 1 << 4; // Bottom right
+    const D1: DisplayGlyph = 1 << 5; // Bottom left
+    const E: DisplayGlyph = 1 << 6; // Left bottom
+    const F: DisplayGlyph = 1 << 7; // Left top
+    const H: DisplayGlyph = 1 << 8; // Top left diagonal
+    const I: DisplayGlyph = 1 << 9; // Top middle
+    const J: DisplayGlyph = 1 << 10; // Top right diagonal
+    const G2: DisplayGlyph = 1 << 11; // Middle right
+    const K: DisplayGlyph = 1 << 12; // Bottom right diagonal
+    const L: DisplayGlyph = 1 << 13; // Bottom middle
+    const M: DisplayGlyph = 1 << 14; // Bottom left diagonal
+    const G1: DisplayGlyph = 1 << 15; // Middle left
+}
+
 impl DisplayImpl for SixteenSegment {
     fn segment_count(&self) -> usize {
         16
@@ -15,148 +34,503 @@ impl DisplayImpl for SixteenSegment {
 
     fn glyph(&self, c: char) -> Option<DisplayGlyph> {
         #[rustfmt::skip]
-        const GLYPH_DATA: &[(char, DisplayGlyph)] = &[
+        const GLYPH_DATA: &[(char, DisplayGlyph)] = &glyph_table!(SixteenSegment, [
             // Basic Latin
-            (' ',  0x0000), ('!',  0x2200), ('"',  0x0280), ('#',  0xAA3C),
-            ('$',  0xAABB), ('%',  0xEE99), ('&',  0x9379), ('\'', 0x0080),
-            ('(',  0x1400), (')',  0x4100), ('*',  0xDD00), ('+',  0xAA00),
-            (',',  0xC000), ('-',  0x8800), ('.',  0x0020), ('/',  0x4400),
-            ('0',  0x44FF), ('1',  0x040C), ('2',  0x8877), ('3',  0x883F),
-            ('4',  0x888C), ('5',  0x88BB), ('6',  0x88FB), ('7',  0x2483),
-            ('8',  0x88FF), ('9',  0x88BF), (':',  0x8020), (';',  0xC001),
-            ('<',  0x9400), ('=',  0x8830), ('>',  0x4900), ('?',  0x2887),
-            ('@',  0x28DF), ('A',  0x88CF), ('B',  0x2A3F), ('C',  0x00F3),
-            ('D',  0x223F), ('E',  0x80F3), ('F',  0x80C3), ('G',  0x08FB),
-            ('H',  0x88CC), ('I',  0x2233), ('J',  0x007E), ('K',  0x94C0),
-            ('L',  0x00F0), ('M',  0x05CC), ('N',  0x11CC), ('O',  0x00FF),
-            ('P',  0x88C7), ('Q',  0x10FF), ('R',  0x98C7), ('S',  0x093B),
-            ('T',  0x2203), ('U',  0x00FC), ('V',  0x44C0), ('W',  0x50CC),
-            ('X',  0x5500), ('Y',  0x2500), ('Z',  0x4433), ('[',  0x2212),
-            ('\\', 0x1100), (']',  0x2221), ('^',  0x0404), ('_',  0x0030),
-            ('`',  0x0100), ('a',  0xA070), ('b',  0xA0E0), ('c',  0x8060),
-            ('d',  0xA260), ('e',  0xC060), ('f',  0xAA02), ('g',  0x1818),
-            ('h',  0xA0C0), ('i',  0x0040), ('j',  0x2220), ('k',  0x3A00),
-            ('l',  0x00E0), ('m',  0xA848), ('n',  0xA040), ('o',  0xA060),
-            ('p',  0x82C1), ('q',  0xA281), ('r',  0x8040), ('s',  0x1810),
-            ('t',  0xAA10), ('u',  0x2060), ('v',  0x4040), ('w',  0x5048),
-            ('x',  0xD800), ('y',  0x1018), ('z',  0xC020), ('{',  0xA212),
-            ('|',  0x2200), ('}',  0x2A21), ('~',  0x0A85),
+            (' ',  []),
+            ('!',  [I, L]),
+            ('"',  [F, I]),
+            ('#',  [B, C, D2, D1, I, G2, L, G1]),
+            ('$',  [A1, A2, C, D2, D1, F, I, G2, L, G1]),
+            ('%',  [A1, C, D2, F, I, J, G2, L, M, G1]),
+            ('&',  [A1, C, D2, D1, E, H, I, K, G1]),
+            ('\'', [F]),
+            ('(',  [J, K]),
+            (')',  [H, M]),
+            ('*',  [H, J, G2, K, M, G1]),
+            ('+',  [I, G2, L, G1]),
+            (',',  [M, G1]),
+            ('-',  [G2, G1]),
+            ('.',  [D1]),
+            ('/',  [J, M]),
+            ('0',  [A1, A2, B, C, D2, D1, E, F, J, M]),
+            ('1',  [B, C, J]),
+            ('2',  [A1, A2, B, D2, D1, E, G2, G1]),
+            ('3',  [A1, A2, B, C, D2, D1, G2, G1]),
+            ('4',  [B, C, F, G2, G1]),
+            ('5',  [A1, A2, C, D2, D1, F, G2, G1]),
+            ('6',  [A1, A2, C, D2, D1, E, F, G2, G1]),
+            ('7',  [A1, A2, F, J, L]),
+            ('8',  [A1, A2, B, C, D2, D1, E, F, G2, G1]),
+            ('9',  [A1, A2, B, C, D2, D1, F, G2, G1]),
+            (':',  [D1, G1]),
+            (';',  [A1, M, G1]),
+            ('<',  [J, K, G1]),
+            ('=',  [D2, D1, G2, G1]),
+            ('>',  [H, G2, M]),
+            ('?',  [A1, A2, B, F, G2, L]),
+            ('@',  [A1, A2, B, C, D2, E, F, G2, L]),
+            ('A',  [A1, A2, B, C, E, F, G2, G1]),
+            ('B',  [A1, A2, B, C, D2, D1, I, G2, L]),
+            ('C',  [A1, A2, D2, D1, E, F]),
+            ('D',  [A1, A2, B, C, D2, D1, I, L]),
+            ('E',  [A1, A2, D2, D1, E, F, G1]),
+            ('F',  [A1, A2, E, F, G1]),
+            ('G',  [A1, A2, C, D2, D1, E, F, G2]),
+            ('H',  [B, C, E, F, G2, G1]),
+            ('I',  [A1, A2, D2, D1, I, L]),
+            ('J',  [A2, B, C, D2, D1, E]),
+            ('K',  [E, F, J, K, G1]),
+            ('L',  [D2, D1, E, F]),
+            ('M',  [B, C, E, F, H, J]),
+            ('N',  [B, C, E, F, H, K]),
+            ('O',  [A1, A2, B, C, D2, D1, E, F]),
+            ('P',  [A1, A2, B, E, F, G2, G1]),
+            ('Q',  [A1, A2, B, C, D2, D1, E, F, K]),
+            ('R',  [A1, A2, B, E, F, G2, K, G1]),
+            ('S',  [A1, A2, C, D2, D1, H, G2]),
+            ('T',  [A1, A2, I, L]),
+            ('U',  [B, C, D2, D1, E, F]),
+            ('V',  [E, F, J, M]),
+            ('W',  [B, C, E, F, K, M]),
+            ('X',  [H, J, K, M]),
+            ('Y',  [H, J, L]),
+            ('Z',  [A1, A2, D2, D1, J, M]),
+            ('[',  [A2, D2, I, L]),
+            ('\\', [H, K]),
+            (']',  [A1, D1, I, L]),
+            ('^',  [B, J]),
+            ('_',  [D2, D1]),
+            ('`',  [H]),
+            ('a',  [D2, D1, E, L, G1]),
+            ('b',  [D1, E, F, L, G1]),
+            ('c',  [D1, E, G1]),
+            ('d',  [D1, E, I, L, G1]),
+            ('e',  [D1, E, M, G1]),
+            ('f',  [A2, I, G2, L, G1]),
+            ('g',  [C, D2, G2, K]),
+            ('h',  [E, F, L, G1]),
+            ('i',  [E]),
+            ('j',  [D1, I, L]),
+            ('k',  [I, G2, K, L]),
+            ('l',  [D1, E, F]),
+            ('m',  [C, E, G2, L, G1]),
+            ('n',  [E, L, G1]),
+            ('o',  [D1, E, L, G1]),
+            ('p',  [A1, E, F, I, G1]),
+            ('q',  [A1, F, I, L, G1]),
+            ('r',  [E, G1]),
+            ('s',  [D2, G2, K]),
+            ('t',  [D2, I, G2, L, G1]),
+            ('u',  [D1, E, L]),
+            ('v',  [E, M]),
+            ('w',  [C, E, K, M]),
+            ('x',  [G2, K, M, G1]),
+            ('y',  [C, D2, K]),
+            ('z',  [D1, M, G1]),
+            ('{',  [A2, D2, I, L, G1]),
+            ('|',  [I, L]),
+            ('}',  [A1, D1, I, G2, L]),
+            ('~',  [A1, B, F, I, G2]),
             // Latin-1 Supplement
-            ('¢',  0x8060), ('£',  0xA276), ('¥',  0xAD00), ('§',  0x11BB),
-            ('¬',  0x8808), ('°',  0x8281), ('±',  0xAA30), ('²',  0x0C02),
-            ('³',  0x0C06), ('µ',  0x82C0), ('¶',  0xA28F), ('×',  0x5500),
-            ('ß',  0x0CDB),
+            ('¢',  [D1, E, G1]),
+            ('£',  [A2, B, D2, D1, E, I, L, G1]),
+            ('¥',  [H, J, G2, L, G1]),
+            ('§',  [A1, A2, C, D2, D1, F, H, K]),
+            ('¬',  [C, G2, G1]),
+            ('°',  [A1, F, I, G1]),
+            ('±',  [D2, D1, I, G2, L, G1]),
+            ('²',  [A2, J, G2]),
+            ('³',  [A2, B, J, G2]),
+            ('µ',  [E, F, I, G1]),
+            ('¶',  [A1, A2, B, C, F, I, L, G1]),
+            ('×',  [H, J, K, M]),
+            ('ß',  [A1, A2, C, D2, E, F, J, G2]),
             // Greek and Coptic
-            ('Α',  0x88CF), ('Β',  0x8CFB), ('Γ',  0x00C3), ('Δ',  0x443C),
-            ('Ε',  0x88F3), ('Ζ',  0x4433), ('Η',  0x88CC), ('Θ',  0x80FF),
-            ('Ι',  0x2233), ('Κ',  0x94C0), ('Λ',  0x440C), ('Μ',  0x05CC),
-            ('Ν',  0x11CC), ('Ξ',  0x8833), ('Ο',  0x00FF), ('Π',  0x00CF),
-            ('Ρ',  0x88C7), ('Σ',  0x4133), ('Τ',  0x2203), ('Υ',  0x2500),
-            ('Φ',  0xAA87), ('Χ',  0x5500), ('Ψ',  0xAA84), ('Ω',  0xD887),
-            ('α',  0xB070), ('β',  0x0CDB), ('γ',  0xC280), ('δ',  0xA161),
-            ('ε',  0x80E1), ('ζ',  0x8161), ('η',  0xA040), ('θ',  0xA2E1),
-            ('ι',  0x0060), ('κ',  0x3600), ('λ',  0x5100), ('μ',  0x82C0),
-            ('ν',  0x4040), ('ξ',  0x80F1), ('ο',  0xA060), ('π',  0xD800),
-            ('ρ',  0x82C1), ('ς',  0x8060), ('σ',  0xA860), ('τ',  0xA800),
-            ('υ',  0x2060), ('φ',  0xAA86), ('χ',  0x5500), ('ψ',  0xAA84),
-            ('ω',  0x2078),
+            ('Α',  [A1, A2, B, C, E, F, G2, G1]),
+            ('Β',  [A1, A2, C, D2, D1, E, F, J, G2, G1]),
+            ('Γ',  [A1, A2, E, F]),
+            ('Δ',  [B, C, D2, D1, J, M]),
+            ('Ε',  [A1, A2, D2, D1, E, F, G2, G1]),
+            ('Ζ',  [A1, A2, D2, D1, J, M]),
+            ('Η',  [B, C, E, F, G2, G1]),
+            ('Θ',  [A1, A2, B, C, D2, D1, E, F, G1]),
+            ('Ι',  [A1, A2, D2, D1, I, L]),
+            ('Κ',  [E, F, J, K, G1]),
+            ('Λ',  [B, C, J, M]),
+            ('Μ',  [B, C, E, F, H, J]),
+            ('Ν',  [B, C, E, F, H, K]),
+            ('Ξ',  [A1, A2, D2, D1, G2, G1]),
+            ('Ο',  [A1, A2, B, C, D2, D1, E, F]),
+            ('Π',  [A1, A2, B, C, E, F]),
+            ('Ρ',  [A1, A2, B, E, F, G2, G1]),
+            ('Σ',  [A1, A2, D2, D1, H, M]),
+            ('Τ',  [A1, A2, I, L]),
+            ('Υ',  [H, J, L]),
+            ('Φ',  [A1, A2, B, F, I, G2, L, G1]),
+            ('Χ',  [H, J, K, M]),
+            ('Ψ',  [B, F, I, G2, L, G1]),
+            ('Ω',  [A1, A2, B, F, G2, K, M, G1]),
+            ('α',  [D2, D1, E, K, L, G1]),
+            ('β',  [A1, A2, C, D2, E, F, J, G2]),
+            ('γ',  [F, I, M, G1]),
+            ('δ',  [A1, D1, E, H, L, G1]),
+            ('ε',  [A1, D1, E, F, G1]),
+            ('ζ',  [A1, D1, E, H, G1]),
+            ('η',  [E, L, G1]),
+            ('θ',  [A1, D1, E, F, I, L, G1]),
+            ('ι',  [D1, E]),
+            ('κ',  [I, J, K, L]),
+            ('λ',  [H, K, M]),
+            ('μ',  [E, F, I, G1]),
+            ('ν',  [E, M]),
+            ('ξ',  [A1, D2, D1, E, F, G1]),
+            ('ο',  [D1, E, L, G1]),
+            ('π',  [G2, K, M, G1]),
+            ('ρ',  [A1, E, F, I, G1]),
+            ('ς',  [D1, E, G1]),
+            ('σ',  [D1, E, G2, L, G1]),
+            ('τ',  [G2, L, G1]),
+            ('υ',  [D1, E, L]),
+            ('φ',  [A2, B, F, I, G2, L, G1]),
+            ('χ',  [H, J, K, M]),
+            ('ψ',  [B, F, I, G2, L, G1]),
+            ('ω',  [C, D2, D1, E, L]),
             // Currency Symbols
-            ('€',  0xEA12),
+            ('€',  [A2, D2, I, G2, L, M, G1]),
             // Enclosed Alphanumerics
-            ('①',  0x000C), ('②',  0x2816), ('③',  0x081E), ('④',  0x0A0C),
-            ('⑤',  0x0A1A), ('⑥',  0x2A1A), ('⑦',  0x020E), ('⑧',  0x2A1E),
-            ('⑨',  0x0A1E), ('⑩',  0x22DE), ('⑪',  0x00CC), ('⑫',  0x28D6),
-            ('⑬',  0x08DE), ('⑭',  0x0ACC), ('⑮',  0x0ADA), ('⑯',  0x2ADA),
-            ('⑰',  0x02CE), ('⑱',  0x2ADE), ('⑲',  0x0ADE), ('⑴',  0x000C),
-            ('⑵',  0x2816), ('⑶',  0x081E), ('⑷',  0x0A0C), ('⑸',  0x0A1A),
-            ('⑹',  0x2A1A), ('⑺',  0x020E), ('⑻',  0x2A1E), ('⑼',  0x0A1E),
-            ('⑽',  0x22DE), ('⑾',  0x00CC), ('⑿',  0x28D6), ('⒀',  0x08DE),
-            ('⒁',  0x0ACC), ('⒂',  0x0ADA), ('⒃',  0x2ADA), ('⒄',  0x02CE),
-            ('⒅',  0x2ADE), ('⒆',  0x0ADE), ('⒈',  0x000C), ('⒉',  0x2816),
-            ('⒊',  0x081E), ('⒋',  0x0A0C), ('⒌',  0x0A1A), ('⒍',  0x2A1A),
-            ('⒎',  0x020E), ('⒏',  0x2A1E), ('⒐',  0x0A1E), ('⒑',  0x22DE),
-            ('⒒',  0x00CC), ('⒓',  0x28D6), ('⒔',  0x08DE), ('⒕',  0x0ACC),
-            ('⒖',  0x0ADA), ('⒗',  0x2ADA), ('⒘',  0x02CE), ('⒙',  0x2ADE),
-            ('⒚',  0x0ADE), ('⓫',  0x00CC), ('⓬',  0x28D6), ('⓭',  0x08DE),
-            ('⓮',  0x0ACC), ('⓯',  0x0ADA), ('⓰',  0x2ADA), ('⓱',  0x02CE),
-            ('⓲',  0x2ADE), ('⓳',  0x0ADE), ('⓵',  0x000C), ('⓶',  0x2816),
-            ('⓷',  0x081E), ('⓸',  0x0A0C), ('⓹',  0x0A1A), ('⓺',  0x2A1A),
-            ('⓻',  0x020E), ('⓼',  0x2A1E), ('⓽',  0x0A1E), ('⓾',  0x22DE),
-            ('⓿',  0x221E),
+            ('①',  [B, C]),
+            ('②',  [A2, B, D2, G2, L]),
+            ('③',  [A2, B, C, D2, G2]),
+            ('④',  [B, C, I, G2]),
+            ('⑤',  [A2, C, D2, I, G2]),
+            ('⑥',  [A2, C, D2, I, G2, L]),
+            ('⑦',  [A2, B, C, I]),
+            ('⑧',  [A2, B, C, D2, I, G2, L]),
+            ('⑨',  [A2, B, C, D2, I, G2]),
+            ('⑩',  [A2, B, C, D2, E, F, I, L]),
+            ('⑪',  [B, C, E, F]),
+            ('⑫',  [A2, B, D2, E, F, G2, L]),
+            ('⑬',  [A2, B, C, D2, E, F, G2]),
+            ('⑭',  [B, C, E, F, I, G2]),
+            ('⑮',  [A2, C, D2, E, F, I, G2]),
+            ('⑯',  [A2, C, D2, E, F, I, G2, L]),
+            ('⑰',  [A2, B, C, E, F, I]),
+            ('⑱',  [A2, B, C, D2, E, F, I, G2, L]),
+            ('⑲',  [A2, B, C, D2, E, F, I, G2]),
+            ('⑴',  [B, C]),
+            ('⑵',  [A2, B, D2, G2, L]),
+            ('⑶',  [A2, B, C, D2, G2]),
+            ('⑷',  [B, C, I, G2]),
+            ('⑸',  [A2, C, D2, I, G2]),
+            ('⑹',  [A2, C, D2, I, G2, L]),
+            ('⑺',  [A2, B, C, I]),
+            ('⑻',  [A2, B, C, D2, I, G2, L]),
+            ('⑼',  [A2, B, C, D2, I, G2]),
+            ('⑽',  [A2, B, C, D2, E, F, I, L]),
+            ('⑾',  [B, C, E, F]),
+            ('⑿',  [A2, B, D2, E, F, G2, L]),
+            ('⒀',  [A2, B, C, D2, E, F, G2]),
+            ('⒁',  [B, C, E, F, I, G2]),
+            ('⒂',  [A2, C, D2, E, F, I, G2]),
+            ('⒃',  [A2, C, D2, E, F, I, G2, L]),
+            ('⒄',  [A2, B, C, E, F, I]),
+            ('⒅',  [A2, B, C, D2, E, F, I, G2, L]),
+            ('⒆',  [A2, B, C, D2, E, F, I, G2]),
+            ('⒈',  [B, C]),
+            ('⒉',  [A2, B, D2, G2, L]),
+            ('⒊',  [A2, B, C, D2, G2]),
+            ('⒋',  [B, C, I, G2]),
+            ('⒌',  [A2, C, D2, I, G2]),
+            ('⒍',  [A2, C, D2, I, G2, L]),
+            ('⒎',  [A2, B, C, I]),
+            ('⒏',  [A2, B, C, D2, I, G2, L]),
+            ('⒐',  [A2, B, C, D2, I, G2]),
+            ('⒑',  [A2, B, C, D2, E, F, I, L]),
+            ('⒒',  [B, C, E, F]),
+            ('⒓',  [A2, B, D2, E, F, G2, L]),
+            ('⒔',  [A2, B, C, D2, E, F, G2]),
+            ('⒕',  [B, C, E, F, I, G2]),
+            ('⒖',  [A2, C, D2, E, F, I, G2]),
+            ('⒗',  [A2, C, D2, E, F, I, G2, L]),
+            ('⒘',  [A2, B, C, E, F, I]),
+            ('⒙',  [A2, B, C, D2, E, F, I, G2, L]),
+            ('⒚',  [A2, B, C, D2, E, F, I, G2]),
+            ('⓫',  [B, C, E, F]),
+            ('⓬',  [A2, B, D2, E, F, G2, L]),
+            ('⓭',  [A2, B, C, D2, E, F, G2]),
+            ('⓮',  [B, C, E, F, I, G2]),
+            ('⓯',  [A2, C, D2, E, F, I, G2]),
+            ('⓰',  [A2, C, D2, E, F, I, G2, L]),
+            ('⓱',  [A2, B, C, E, F, I]),
+            ('⓲',  [A2, B, C, D2, E, F, I, G2, L]),
+            ('⓳',  [A2, B, C, D2, E, F, I, G2]),
+            ('⓵',  [B, C]),
+            ('⓶',  [A2, B, D2, G2, L]),
+            ('⓷',  [A2, B, C, D2, G2]),
+            ('⓸',  [B, C, I, G2]),
+            ('⓹',  [A2, C, D2, I, G2]),
+            ('⓺',  [A2, C, D2, I, G2, L]),
+            ('⓻',  [A2, B, C, I]),
+            ('⓼',  [A2, B, C, D2, I, G2, L]),
+            ('⓽',  [A2, B, C, D2, I, G2]),
+            ('⓾',  [A2, B, C, D2, E, F, I, L]),
+            ('⓿',  [A2, B, C, D2, I, L]),
             // Dingbats
-            ('✔',  0x4440), ('❶',  0x000C), ('❷',  0x2816), ('❸',  0x081E),
-            ('❹',  0x0A0C), ('❺',  0x0A1A), ('❻',  0x2A1A), ('❼',  0x020E),
-            ('❽',  0x2A1E), ('❾',  0x0A1E), ('❿',  0x22DE), ('➀',  0x000C),
-            ('➁',  0x2816), ('➂',  0x081E), ('➃',  0x0A0C), ('➄',  0x0A1A),
-            ('➅',  0x2A1A), ('➆',  0x020E), ('➇',  0x2A1E), ('➈',  0x0A1E),
-            ('➉',  0x22DE), ('➊',  0x000C), ('➋',  0x2816), ('➌',  0x081E),
-            ('➍',  0x0A0C), ('➎',  0x0A1A), ('➏',  0x2A1A), ('➐',  0x020E),
-            ('➑',  0x2A1E), ('➒',  0x0A1E), ('➓',  0x22DE),
+            ('✔',  [E, J, M]),
+            ('❶',  [B, C]),
+            ('❷',  [A2, B, D2, G2, L]),
+            ('❸',  [A2, B, C, D2, G2]),
+            ('❹',  [B, C, I, G2]),
+            ('❺',  [A2, C, D2, I, G2]),
+            ('❻',  [A2, C, D2, I, G2, L]),
+            ('❼',  [A2, B, C, I]),
+            ('❽',  [A2, B, C, D2, I, G2, L]),
+            ('❾',  [A2, B, C, D2, I, G2]),
+            ('❿',  [A2, B, C, D2, E, F, I, L]),
+            ('➀',  [B, C]),
+            ('➁',  [A2, B, D2, G2, L]),
+            ('➂',  [A2, B, C, D2, G2]),
+            ('➃',  [B, C, I, G2]),
+            ('➄',  [A2, C, D2, I, G2]),
+            ('➅',  [A2, C, D2, I, G2, L]),
+            ('➆',  [A2, B, C, I]),
+            ('➇',  [A2, B, C, D2, I, G2, L]),
+            ('➈',  [A2, B, C, D2, I, G2]),
+            ('➉',  [A2, B, C, D2, E, F, I, L]),
+            ('➊',  [B, C]),
+            ('➋',  [A2, B, D2, G2, L]),
+            ('➌',  [A2, B, C, D2, G2]),
+            ('➍',  [B, C, I, G2]),
+            ('➎',  [A2, C, D2, I, G2]),
+            ('➏',  [A2, C, D2, I, G2, L]),
+            ('➐',  [A2, B, C, I]),
+            ('➑',  [A2, B, C, D2, I, G2, L]),
+            ('➒',  [A2, B, C, D2, I, G2]),
+            ('➓',  [A2, B, C, D2, E, F, I, L]),
             // CJK Symbols and Punctuation
-            ('。',  0xA060), ('〆',  0x5540), ('〇',  0x00FF), ('〈',  0x1400),
-            ('〉',  0x4100), ('「',  0x00C3), ('」',  0x003C), ('『',  0x28E7),
-            ('』',  0x827E), ('〒',  0xA803), ('〔',  0x2212), ('〕',  0x2221),
-            ('〖',  0x14F3), ('〗',  0x413F), ('〘',  0x14F3), ('〙',  0x413F),
-            ('〚',  0x22F3), ('〛',  0x223F), ('〜',  0xA058), ('〿',  0x55FF),
+            ('。',  [D1, E, L, G1]),
+            ('〆',  [E, H, J, K, M]),
+            ('〇',  [A1, A2, B, C, D2, D1, E, F]),
+            ('〈',  [J, K]),
+            ('〉',  [H, M]),
+            ('「',  [A1, A2, E, F]),
+            ('」',  [B, C, D2, D1]),
+            ('『',  [A1, A2, B, D1, E, F, G2, L]),
+            ('』',  [A2, B, C, D2, D1, E, I, G1]),
+            ('〒',  [A1, A2, G2, L, G1]),
+            ('〔',  [A2, D2, I, L]),
+            ('〕',  [A1, D1, I, L]),
+            ('〖',  [A1, A2, D2, D1, E, F, J, K]),
+            ('〗',  [A1, A2, B, C, D2, D1, H, M]),
+            ('〘',  [A1, A2, D2, D1, E, F, J, K]),
+            ('〙',  [A1, A2, B, C, D2, D1, H, M]),
+            ('〚',  [A1, A2, D2, D1, E, F, I, L]),
+            ('〛',  [A1, A2, B, C, D2, D1, I, L]),
+            ('〜',  [C, D2, E, L, G1]),
+            ('〿',  [A1, A2, B, C, D2, D1, E, F, H, J, K, M]),
             // CJK Unified Ideographs
-            ('一',  0x8800), ('七',  0xAA10), ('万',  0x4A0B), ('三',  0x8833),
-            ('上',  0x2A30), ('下',  0x3203), ('中',  0xAA87), ('主',  0xAA33),
-            ('九',  0xCA08), ('二',  0x0033), ('五',  0xAA3B), ('人',  0x5200),
-            ('元',  0xD803), ('入',  0x5200), ('八',  0x420C), ('六',  0xDA00),
-            ('円',  0x8ADF), ('力',  0xCA18), ('十',  0xAA00), ('千',  0xAA03),
-            ('口',  0x00FF), ('四',  0x8AFF), ('土',  0xAA30), ('大',  0xDA00),
-            ('天',  0xDA03), ('子',  0xAC03), ('山',  0x22FC), ('川',  0x22CC),
-            ('工',  0x2233), ('市',  0xAA5B), ('日',  0x88FF), ('木',  0xFA00),
-            ('正',  0x2A73), ('田',  0xAAFF), ('百',  0x88FF), ('示',  0xF803),
-            ('米',  0xFF00), ('零',  0x5287),
+            ('一',  [G2, G1]),
+            ('七',  [D2, I, G2, L, G1]),
+            ('万',  [A1, A2, C, I, G2, M]),
+            ('三',  [A1, A2, D2, D1, G2, G1]),
+            ('上',  [D2, D1, I, G2, L]),
+            ('下',  [A1, A2, I, K, L]),
+            ('中',  [A1, A2, B, F, I, G2, L, G1]),
+            ('主',  [A1, A2, D2, D1, I, G2, L, G1]),
+            ('九',  [C, I, G2, M, G1]),
+            ('二',  [A1, A2, D2, D1]),
+            ('五',  [A1, A2, C, D2, D1, I, G2, L, G1]),
+            ('人',  [I, K, M]),
+            ('元',  [A1, A2, G2, K, M, G1]),
+            ('入',  [I, K, M]),
+            ('八',  [B, C, I, M]),
+            ('六',  [I, G2, K, M, G1]),
+            ('円',  [A1, A2, B, C, D2, E, F, I, G2, G1]),
+            ('力',  [C, D2, I, G2, M, G1]),
+            ('十',  [I, G2, L, G1]),
+            ('千',  [A1, A2, I, G2, L, G1]),
+            ('口',  [A1, A2, B, C, D2, D1, E, F]),
+            ('四',  [A1, A2, B, C, D2, D1, E, F, I, G2, G1]),
+            ('土',  [D2, D1, I, G2, L, G1]),
+            ('大',  [I, G2, K, M, G1]),
+            ('天',  [A1, A2, I, G2, K, M, G1]),
+            ('子',  [A1, A2, J, G2, L, G1]),
+            ('山',  [B, C, D2, D1, E, F, I, L]),
+            ('川',  [B, C, E, F, I, L]),
+            ('工',  [A1, A2, D2, D1, I, L]),
+            ('市',  [A1, A2, C, D2, E, I, G2, L, G1]),
+            ('日',  [A1, A2, B, C, D2, D1, E, F, G2, G1]),
+            ('木',  [I, G2, K, L, M, G1]),
+            ('正',  [A1, A2, D2, D1, E, I, G2, L]),
+            ('田',  [A1, A2, B, C, D2, D1, E, F, I, G2, L, G1]),
+            ('百',  [A1, A2, B, C, D2, D1, E, F, G2, G1]),
+            ('示',  [A1, A2, G2, K, L, M, G1]),
+            ('米',  [H, I, J, G2, K, L, M, G1]),
+            ('零',  [A1, A2, B, F, I, K, M]),
             // Halfwidth and Fullwidth Forms
-            ('！',  0x2200), ('＂',  0x0280), ('＃',  0xAA3C), ('＄',  0xAABB),
-            ('％',  0xEE99), ('＆',  0x9379), ('＇',  0x0080), ('（',  0x1400),
-            ('）',  0x4100), ('＊',  0xDD00), ('＋',  0xAA00), ('，',  0xC000),
-            ('－',  0x8800), ('．',  0x0020), ('／',  0x4400), ('０',  0x44FF),
-            ('１',  0x040C), ('２',  0x8877), ('３',  0x883F), ('４',  0x888C),
-            ('５',  0x88BB), ('６',  0x88FB), ('７',  0x2483), ('８',  0x88FF),
-            ('９',  0x88BF), ('：',  0x8020), ('；',  0xC001), ('＜',  0x9400),
-            ('＝',  0x8830), ('＞',  0x4900), ('？',  0x2887), ('＠',  0x28DF),
-            ('Ａ',  0x88CF), ('Ｂ',  0x2A3F), ('Ｃ',  0x00F3), ('Ｄ',  0x223F),
-            ('Ｅ',  0x80F3), ('Ｆ',  0x80C3), ('Ｇ',  0x08FB), ('Ｈ',  0x88CC),
-            ('Ｉ',  0x2233), ('Ｊ',  0x007E), ('Ｋ',  0x94C0), ('Ｌ',  0x00F0),
-            ('Ｍ',  0x05CC), ('Ｎ',  0x11CC), ('Ｏ',  0x00FF), ('Ｐ',  0x88C7),
-            ('Ｑ',  0x10FF), ('Ｒ',  0x98C7), ('Ｓ',  0x093B), ('Ｔ',  0x2203),
-            ('Ｕ',  0x00FC), ('Ｖ',  0x44C0), ('Ｗ',  0x50CC), ('Ｘ',  0x5500),
-            ('Ｙ',  0x2500), ('Ｚ',  0x4433), ('［',  0x2212), ('＼',  0x1100),
-            ('］',  0x2221), ('＾',  0x0404), ('＿',  0x0030), ('｀',  0x0100),
-            ('ａ',  0xA070), ('ｂ',  0xA0E0), ('ｃ',  0x8060), ('ｄ',  0xA260),
-            ('ｅ',  0xC060), ('ｆ',  0xAA02), ('ｇ',  0x1818), ('ｈ',  0xA0C0),
-            ('ｉ',  0x0040), ('ｊ',  0x2220), ('ｋ',  0x3A00), ('ｌ',  0x00E0),
-            ('ｍ',  0xA848), ('ｎ',  0xA040), ('ｏ',  0xA060), ('ｐ',  0x82C1),
-            ('ｑ',  0xA281), ('ｒ',  0x8040), ('ｓ',  0x1810), ('ｔ',  0xAA10),
-            ('ｕ',  0x2060), ('ｖ',  0x4040), ('ｗ',  0x5048), ('ｘ',  0xD800),
-            ('ｙ',  0x1018), ('ｚ',  0xC020), ('｛',  0xA212), ('｜',  0x2200),
-            ('｝',  0x2A21), ('～',  0x0A85), ('｡',  0xA060), ('｢',  0x00C3),
-            ('｣',  0x003C), ('￠',  0x8060), ('￡',  0xA276), ('￤',  0x2200),
-            ('￥',  0xAD00),
+            ('！',  [I, L]),
+            ('＂',  [F, I]),
+            ('＃',  [B, C, D2, D1, I, G2, L, G1]),
+            ('＄',  [A1, A2, C, D2, D1, F, I, G2, L, G1]),
+            ('％',  [A1, C, D2, F, I, J, G2, L, M, G1]),
+            ('＆',  [A1, C, D2, D1, E, H, I, K, G1]),
+            ('＇',  [F]),
+            ('（',  [J, K]),
+            ('）',  [H, M]),
+            ('＊',  [H, J, G2, K, M, G1]),
+            ('＋',  [I, G2, L, G1]),
+            ('，',  [M, G1]),
+            ('－',  [G2, G1]),
+            ('．',  [D1]),
+            ('／',  [J, M]),
+            ('０',  [A1, A2, B, C, D2, D1, E, F, J, M]),
+            ('１',  [B, C, J]),
+            ('２',  [A1, A2, B, D2, D1, E, G2, G1]),
+            ('３',  [A1, A2, B, C, D2, D1, G2, G1]),
+            ('４',  [B, C, F, G2, G1]),
+            ('５',  [A1, A2, C, D2, D1, F, G2, G1]),
+            ('６',  [A1, A2, C, D2, D1, E, F, G2, G1]),
+            ('７',  [A1, A2, F, J, L]),
+            ('８',  [A1, A2, B, C, D2, D1, E, F, G2, G1]),
+            ('９',  [A1, A2, B, C, D2, D1, F, G2, G1]),
+            ('：',  [D1, G1]),
+            ('；',  [A1, M, G1]),
+            ('＜',  [J, K, G1]),
+            ('＝',  [D2, D1, G2, G1]),
+            ('＞',  [H, G2, M]),
+            ('？',  [A1, A2, B, F, G2, L]),
+            ('＠',  [A1, A2, B, C, D2, E, F, G2, L]),
+            ('Ａ',  [A1, A2, B, C, E, F, G2, G1]),
+            ('Ｂ',  [A1, A2, B, C, D2, D1, I, G2, L]),
+            ('Ｃ',  [A1, A2, D2, D1, E, F]),
+            ('Ｄ',  [A1, A2, B, C, D2, D1, I, L]),
+            ('Ｅ',  [A1, A2, D2, D1, E, F, G1]),
+            ('Ｆ',  [A1, A2, E, F, G1]),
+            ('Ｇ',  [A1, A2, C, D2, D1, E, F, G2]),
+            ('Ｈ',  [B, C, E, F, G2, G1]),
+            ('Ｉ',  [A1, A2, D2, D1, I, L]),
+            ('Ｊ',  [A2, B, C, D2, D1, E]),
+            ('Ｋ',  [E, F, J, K, G1]),
+            ('Ｌ',  [D2, D1, E, F]),
+            ('Ｍ',  [B, C, E, F, H, J]),
+            ('Ｎ',  [B, C, E, F, H, K]),
+            ('Ｏ',  [A1, A2, B, C, D2, D1, E, F]),
+            ('Ｐ',  [A1, A2, B, E, F, G2, G1]),
+            ('Ｑ',  [A1, A2, B, C, D2, D1, E, F, K]),
+            ('Ｒ',  [A1, A2, B, E, F, G2, K, G1]),
+            ('Ｓ',  [A1, A2, C, D2, D1, H, G2]),
+            ('Ｔ',  [A1, A2, I, L]),
+            ('Ｕ',  [B, C, D2, D1, E, F]),
+            ('Ｖ',  [E, F, J, M]),
+            ('Ｗ',  [B, C, E, F, K, M]),
+            ('Ｘ',  [H, J, K, M]),
+            ('Ｙ',  [H, J, L]),
+            ('Ｚ',  [A1, A2, D2, D1, J, M]),
+            ('［',  [A2, D2, I, L]),
+            ('＼',  [H, K]),
+            ('］',  [A1, D1, I, L]),
+            ('＾',  [B, J]),
+            ('＿',  [D2, D1]),
+            ('｀',  [H]),
+            ('ａ',  [D2, D1, E, L, G1]),
+            ('ｂ',  [D1, E, F, L, G1]),
+            ('ｃ',  [D1, E, G1]),
+            ('ｄ',  [D1, E, I, L, G1]),
+            ('ｅ',  [D1, E, M, G1]),
+            ('ｆ',  [A2, I, G2, L, G1]),
+            ('ｇ',  [C, D2, G2, K]),
+            ('ｈ',  [E, F, L, G1]),
+            ('ｉ',  [E]),
+            ('ｊ',  [D1, I, L]),
+            ('ｋ',  [I, G2, K, L]),
+            ('ｌ',  [D1, E, F]),
+            ('ｍ',  [C, E, G2, L, G1]),
+            ('ｎ',  [E, L, G1]),
+            ('ｏ',  [D1, E, L, G1]),
+            ('ｐ',  [A1, E, F, I, G1]),
+            ('ｑ',  [A1, F, I, L, G1]),
+            ('ｒ',  [E, G1]),
+            ('ｓ',  [D2, G2, K]),
+            ('ｔ',  [D2, I, G2, L, G1]),
+            ('ｕ',  [D1, E, L]),
+            ('ｖ',  [E, M]),
+            ('ｗ',  [C, E, K, M]),
+            ('ｘ',  [G2, K, M, G1]),
+            ('ｙ',  [C, D2, K]),
+            ('ｚ',  [D1, M, G1]),
+            ('｛',  [A2, D2, I, L, G1]),
+            ('｜',  [I, L]),
+            ('｝',  [A1, D1, I, G2, L]),
+            ('～',  [A1, B, F, I, G2]),
+            ('｡',  [D1, E, L, G1]),
+            ('｢',  [A1, A2, E, F]),
+            ('｣',  [B, C, D2, D1]),
+            ('￠',  [D1, E, G1]),
+            ('￡',  [A2, B, D2, D1, E, I, L, G1]),
+            ('￤',  [I, L]),
+            ('￥',  [H, J, G2, L, G1]),
             // Enclosed Alphanumeric Supplement
-            ('🄀',  0x221E), ('🄁',  0x221E), ('🄂',  0x000C), ('🄃',  0x2816),
-            ('🄄',  0x081E), ('🄅',  0x0A0C), ('🄆',  0x0A1A), ('🄇',  0x2A1A),
-            ('🄈',  0x020E), ('🄉',  0x2A1E), ('🄊',  0x0A1E), ('🄋',  0x221E),
-            ('🄌',  0x221E),
+            ('🄀',  [A2, B, C, D2, I, L]),
+            ('🄁',  [A2, B, C, D2, I, L]),
+            ('🄂',  [B, C]),
+            ('🄃',  [A2, B, D2, G2, L]),
+            ('🄄',  [A2, B, C, D2, G2]),
+            ('🄅',  [B, C, I, G2]),
+            ('🄆',  [A2, C, D2, I, G2]),
+            ('🄇',  [A2, C, D2, I, G2, L]),
+            ('🄈',  [A2, B, C, I]),
+            ('🄉',  [A2, B, C, D2, I, G2, L]),
+            ('🄊',  [A2, B, C, D2, I, G2]),
+            ('🄋',  [A2, B, C, D2, I, L]),
+            ('🄌',  [A2, B, C, D2, I, L]),
             // Miscellaneous Symbols and Pictographs
-            ('🌟',  0xDA00), ('🌠',  0xDA00), ('🍀',  0xFF55), ('🎂',  0x8AFC),
-            ('🎈',  0xC887), ('🐱',  0x05FC), ('👍',  0xAC38), ('👎',  0x9A07),
-            ('💣',  0xA160), ('💤',  0xCC22), ('💥',  0xFF00), ('📐',  0x51F0),
-            ('🔫',  0x988F), ('🗕',  0x0030), ('🗖',  0x00FF), ('🗗',  0xAA66),
-            ('🗙',  0x5500), ('🗚',  0x2A4E), ('🗛',  0xA2C9), ('🗤',  0x0700),
-            ('🗥',  0x7000), ('🗦',  0xC100), ('🗧',  0x1C00), ('🗼',  0x7200),
+            ('🌟',  [I, G2, K, M, G1]),
+            ('🌠',  [I, G2, K, M, G1]),
+            ('🍀',  [A1, B, D2, E, H, I, J, G2, K, L, M, G1]),
+            ('🎂',  [B, C, D2, D1, E, F, I, G2, G1]),
+            ('🎈',  [A1, A2, B, F, G2, M, G1]),
+            ('🐱',  [B, C, D2, D1, E, F, H, J]),
+            ('👍',  [C, D2, D1, J, G2, L, G1]),
+            ('👎',  [A1, A2, B, I, G2, K, G1]),
+            ('💣',  [D1, E, H, L, G1]),
+            ('💤',  [A2, D1, J, G2, M, G1]),
+            ('💥',  [H, I, J, G2, K, L, M, G1]),
+            ('📐',  [D2, D1, E, F, H, K, M]),
+            ('🔫',  [A1, A2, B, C, F, G2, K, G1]),
+            ('🗕',  [D2, D1]),
+            ('🗖',  [A1, A2, B, C, D2, D1, E, F]),
+            ('🗗',  [A2, B, D1, E, I, G2, L, G1]),
+            ('🗙',  [H, J, K, M]),
+            ('🗚',  [A2, B, C, E, I, G2, L]),
+            ('🗛',  [A1, C, E, F, I, L, G1]),
+            ('🗤',  [H, I, J]),
+            ('🗥',  [K, L, M]),
+            ('🗦',  [H, M, G1]),
+            ('🗧',  [J, G2, K]),
+            ('🗼',  [I, K, L, M]),
             // Emoticons
-            ('😂',  0x0578), ('😉',  0x04B8), ('😊',  0x0578), ('😏',  0x04B8),
-            ('😐',  0x00B4), ('😑',  0x00B4), ('😒',  0x00B4), ('😕',  0x5084),
-            ('😞',  0x5084), ('😢',  0x5084), ('😥',  0x5084), ('😩',  0x50B4),
-            ('😫',  0x50B4), ('😭',  0x50B4), ('😲',  0x50B4), ('😳',  0x8AB7),
-            ('🙁',  0x5084),
+            ('😂',  [C, D2, D1, E, H, J]),
+            ('😉',  [C, D2, D1, F, J]),
+            ('😊',  [C, D2, D1, E, H, J]),
+            ('😏',  [C, D2, D1, F, J]),
+            ('😐',  [B, D2, D1, F]),
+            ('😑',  [B, D2, D1, F]),
+            ('😒',  [B, D2, D1, F]),
+            ('😕',  [B, F, K, M]),
+            ('😞',  [B, F, K, M]),
+            ('😢',  [B, F, K, M]),
+            ('😥',  [B, F, K, M]),
+            ('😩',  [B, D2, D1, F, K, M]),
+            ('😫',  [B, D2, D1, F, K, M]),
+            ('😭',  [B, D2, D1, F, K, M]),
+            ('😲',  [B, D2, D1, F, K, M]),
+            ('😳',  [A1, A2, B, D2, D1, F, I, G2, G1]),
+            ('🙁',  [B, F, K, M]),
             // Supplemental Symbols and Pictographs
-            ('🤣',  0x0578), ('🥵',  0x50B4), ('🧘',  0x7230),
-        ];
+            ('🤣',  [C, D2, D1, E, H, J]),
+            ('🥵',  [B, D2, D1, F, K, M]),
+            ('🧘',  [D2, D1, I, K, L, M]),
+        ]);
 
         debug_assert!(GLYPH_DATA
             .iter()
@@ -1,10 +1,16 @@
-use egui::{pos2, vec2, Pos2, Response, Sense, Shape, Stroke, Ui, Widget};
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::time::Duration;
+
+use egui::{lerp, pos2, vec2, Color32, Pos2, Response, Rgba, Sense, Shape, Stroke, Ui, Widget};
 use itertools::Itertools;
 
 use crate::displays::segmented_display::{
-    DisplayDigit, DisplayKind, DisplayMetrics, DisplayMetricsPreset,
+    DisplayAlignment, DisplayDigit, DisplayKind, DisplayMetrics, DisplayMetricsPreset,
 };
 use crate::displays::{DisplayStyle, DisplayStylePreset};
+use crate::hash::PearsonHash;
+use crate::XtWidgetState;
 
 #[must_use = "You should put this widget in an ui with `ui.add(widget);`"]
 pub struct SegmentedDisplayWidget {
@@ -16,6 +22,19 @@ pub struct SegmentedDisplayWidget {
     show_dots: bool,
     show_colons: bool,
     show_apostrophes: bool,
+    glow: f32,
+    blink_mask: Vec<bool>,
+    blink_period: f32,
+    digit_colors: Vec<Option<Color32>>,
+    max_digits: Option<usize>,
+    alignment: DisplayAlignment,
+    overflow_character: Option<char>,
+    scroll_offset: f32,
+    marquee: bool,
+    marquee_speed: f32,
+    marquee_gap: usize,
+    mirrored: bool,
+    reset_state: bool,
 }
 
 impl SegmentedDisplayWidget {
@@ -29,6 +48,19 @@ impl SegmentedDisplayWidget {
             show_dots: true,
             show_colons: true,
             show_apostrophes: true,
+            glow: 0.0,
+            blink_mask: Vec::new(),
+            blink_period: 1.0,
+            digit_colors: Vec::new(),
+            max_digits: None,
+            alignment: DisplayAlignment::Left,
+            overflow_character: Some('E'),
+            scroll_offset: 0.0,
+            marquee: false,
+            marquee_speed: 4.0,
+            marquee_gap: 2,
+            mirrored: false,
+            reset_state: false,
         }
     }
 
@@ -36,6 +68,10 @@ impl SegmentedDisplayWidget {
         Self::new(DisplayKind::SevenSegment).push_string(value.as_ref())
     }
 
+    pub fn eight_segment<T: AsRef<str>>(value: T) -> Self {
+        Self::new(DisplayKind::EightSegment).push_string(value.as_ref())
+    }
+
     pub fn nine_segment<T: AsRef<str>>(value: T) -> Self {
         Self::new(DisplayKind::NineSegment).push_string(value.as_ref())
     }
@@ -44,6 +80,10 @@ impl SegmentedDisplayWidget {
         Self::new(DisplayKind::SixteenSegment).push_string(value.as_ref())
     }
 
+    pub fn dot_matrix_5x7<T: AsRef<str>>(value: T) -> Self {
+        Self::new(DisplayKind::DotMatrix5x7).push_string(value.as_ref())
+    }
+
     pub fn push_string<T: AsRef<str>>(mut self, value: T) -> Self {
         let display_impl = self.display_kind.display_impl();
 
@@ -69,6 +109,16 @@ impl SegmentedDisplayWidget {
         self
     }
 
+    /// Pushes `value` as uppercase hexadecimal, zero-padded to at least `digits` digits.
+    pub fn push_u32_hex(self, value: u32, digits: usize) -> Self {
+        self.push_string(format!("{value:0digits$X}"))
+    }
+
+    /// Pushes `value` in binary, zero-padded to at least `digits` digits.
+    pub fn push_u32_binary(self, value: u32, digits: usize) -> Self {
+        self.push_string(format!("{value:0digits$b}"))
+    }
+
     pub fn push_digit(mut self, digit: DisplayDigit) -> Self {
         self.digits.push(digit);
         self
@@ -113,6 +163,99 @@ impl SegmentedDisplayWidget {
         self.show_apostrophes = show_apostrophes;
         self
     }
+
+    /// Strength of the glow around lit segments, from 0.0 (no glow) to 1.0.
+    /// The glow is blended into the background color of the style.
+    pub fn glow(mut self, glow: impl Into<f32>) -> Self {
+        self.glow = glow.into();
+        self
+    }
+
+    /// Digits flagged in the mask alternate between lit and unlit states.
+    /// Digits past the end of the mask are never blinking.
+    pub fn blink(mut self, mask: &[bool]) -> Self {
+        self.blink_mask = mask.to_vec();
+        self
+    }
+
+    /// Duration of a full on-off blink cycle in seconds, non-positive periods disable blinking.
+    pub fn blink_period(mut self, blink_period: impl Into<f32>) -> Self {
+        self.blink_period = blink_period.into();
+        self
+    }
+
+    /// Overrides the active foreground color of individual digits.
+    /// Digits with `None` or past the end of the slice use the display style.
+    pub fn digit_colors(mut self, digit_colors: &[Option<Color32>]) -> Self {
+        self.digit_colors = digit_colors.to_vec();
+        self
+    }
+
+    /// Fixes the number of rendered digits instead of growing with the pushed string.
+    pub fn max_digits(mut self, max_digits: Option<usize>) -> Self {
+        self.max_digits = max_digits;
+        self
+    }
+
+    /// Fixes the display to `digit_count` digits, shorthand for `max_digits(Some(digit_count))`.
+    /// Shorter strings are padded with blank digits according to the alignment.
+    pub fn digit_count(self, digit_count: usize) -> Self {
+        self.max_digits(Some(digit_count))
+    }
+
+    /// Side of the display the digits are placed against when `max_digits` leaves
+    /// blank digits, or which end is cut off when the string doesn't fit.
+    /// Ignored while scrolling.
+    pub fn alignment(mut self, alignment: DisplayAlignment) -> Self {
+        self.alignment = alignment;
+        self
+    }
+
+    /// Character shown in the leftmost digit of strings that don't fit into `max_digits`,
+    /// regardless of the alignment, or `None` to silently truncate them. Characters without
+    /// a glyph light up every segment instead.
+    pub fn overflow_character(mut self, overflow_character: Option<char>) -> Self {
+        self.overflow_character = overflow_character;
+        self
+    }
+
+    /// Shifts the visible window across the pushed string by the given number of digits.
+    /// The string loops around with a gap of `marquee_gap` blank digits.
+    pub fn scroll_offset(mut self, scroll_offset: impl Into<f32>) -> Self {
+        self.scroll_offset = scroll_offset.into();
+        self
+    }
+
+    pub fn marquee(mut self, marquee: bool) -> Self {
+        self.marquee = marquee;
+        self
+    }
+
+    /// Marquee scrolling speed in digits per second, non-positive or non-finite speeds
+    /// show the text statically.
+    pub fn marquee_speed(mut self, marquee_speed: impl Into<f32>) -> Self {
+        self.marquee_speed = marquee_speed.into();
+        self
+    }
+
+    pub fn marquee_gap(mut self, marquee_gap: usize) -> Self {
+        self.marquee_gap = marquee_gap;
+        self
+    }
+
+    /// Places the digits from right to left, e.g. for right-to-left layouts.
+    /// The glyphs themselves are not mirrored.
+    pub fn mirrored(mut self, mirrored: bool) -> Self {
+        self.mirrored = mirrored;
+        self
+    }
+
+    /// Forgets the transient state of the widget, see [`XtWidgetState`].
+    /// Restarts the blink and marquee cycles.
+    pub fn reset_state(mut self) -> Self {
+        self.reset_state = true;
+        self
+    }
 }
 
 impl Widget for SegmentedDisplayWidget {
@@ -132,9 +275,11 @@ impl Widget for SegmentedDisplayWidget {
         let digit_median = self.metrics.digit_median * (digit_height / 2.0);
         let colon_separation = self.metrics.colon_separation * (digit_height / 2.0);
 
+        let display_digit_count = self.max_digits.unwrap_or(self.digits.len());
+
         let desired_size = vec2(
-            (digit_width * self.digits.len() as f32)
-                + (digit_spacing * (self.digits.len().saturating_sub(1)) as f32)
+            (digit_width * display_digit_count as f32)
+                + (digit_spacing * (display_digit_count.saturating_sub(1)) as f32)
                 + (2.0 * margin_horizontal)
                 + (2.0 * digit_shearing.abs()),
             digit_height + (2.0 * margin_vertical),
@@ -145,7 +290,37 @@ impl Widget for SegmentedDisplayWidget {
         let mut child_ui = ui.child_ui(rect, *ui.layout());
         child_ui.set_clip_rect(child_ui.clip_rect().intersect(rect));
 
+        if self.reset_state {
+            XtWidgetState::reset(ui.ctx(), response.id);
+        }
+
+        let blinking = self.blink_mask.contains(&true) && self.blink_period > 0.0;
+        let marquee = self.marquee && self.marquee_speed.is_finite() && self.marquee_speed > 0.0;
+
+        // Blinking and marquees are timed from when the widget first showed up
+        let time = if marquee || blinking {
+            let current_time = ui.input(|input| input.time);
+            let start_time = XtWidgetState::update(ui.ctx(), response.id, |state| {
+                *state.animation_start_time.get_or_insert(current_time)
+            });
+            current_time - start_time
+        } else {
+            0.0
+        };
+
         if child_ui.is_rect_visible(rect) {
+            let blink_visible = if blinking {
+                let half_period = self.blink_period as f64 / 2.0;
+
+                ui.ctx().request_repaint_after(Duration::from_secs_f64(
+                    half_period - time.rem_euclid(half_period),
+                ));
+
+                (time / half_period).floor() as i64 % 2 == 0
+            } else {
+                true
+            };
+
             // Draw the widget background without clipping
             ui.painter().rect(
                 rect,
@@ -177,69 +352,243 @@ impl Widget for SegmentedDisplayWidget {
                 pos2( (digit_width / 2.0) + (digit_spacing / 2.0), (digit_height / 2.0) - (segment_thickness / 2.0))
             );
 
-            let paint_digit = |digit: &DisplayDigit, digit_center: Pos2| {
+            // Flicker changes 30 times a second, the same for every repaint within a step
+            let flicker_seed = self.style.flicker.map(|_| {
+                const FLICKER_RATE: f64 = 30.0;
+
+                let current_time = ui.input(|input| input.time);
+                let time_step = (current_time * FLICKER_RATE).floor();
+
+                ui.ctx().request_repaint_after(Duration::from_secs_f64(
+                    (time_step + 1.0) / FLICKER_RATE - current_time,
+                ));
+
+                let mut hasher = DefaultHasher::new();
+                (response.id, time_step as u64).hash(&mut hasher);
+                hasher.finish().to_le_bytes()
+            });
+
+            let paint_digit = |slot_index: usize,
+                               digit: &DisplayDigit,
+                               digit_center: Pos2,
+                               digit_style: &DisplayStyle,
+                               digit_lit: bool| {
+                // Dot, colons and apostrophe flicker after the segments
+                let foreground = |element_index: usize, active: bool| match (
+                    active,
+                    flicker_seed,
+                    digit_style.flicker,
+                ) {
+                    (true, Some(seed), Some(amplitude)) => {
+                        let mut bytes = [0; 10];
+                        bytes[..8].copy_from_slice(&seed);
+                        bytes[8] = slot_index as u8;
+                        bytes[9] = element_index as u8;
+
+                        let noise = bytes.pearson_hash() as f32 / 255.0;
+                        let value = 1.0 - amplitude.clamp(0.0, 1.0) * noise;
+
+                        (
+                            digit_style.foreground_color_blend(value),
+                            digit_style.foreground_stroke_blend(value),
+                        )
+                    }
+                    _ => (
+                        digit_style.foreground_color(active),
+                        digit_style.foreground_stroke(active),
+                    ),
+                };
+
+                let segment_count = segment_geometry.len();
+
                 let transform = |&Pos2 { x, y }| {
                     digit_center + vec2(x, y)
                         - vec2((y / (digit_height / 2.0)) * digit_shearing, 0.0)
                 };
 
+                let segment_active = |segment_index: usize| {
+                    digit_lit && ((digit.glyph >> segment_index) & 0x01) != 0x00
+                };
+
+                if self.glow > 0.0 {
+                    let glow_color = Color32::from(lerp(
+                        Rgba::from(digit_style.background_color)
+                            ..=Rgba::from(digit_style.foreground_color(true)),
+                        self.glow.clamp(0.0, 1.0) * 0.5,
+                    ));
+
+                    // The stroke inflates the glow polygons past the segment outlines
+                    for (segment_index, segment_points) in segment_geometry.iter().enumerate() {
+                        if segment_active(segment_index) {
+                            child_ui.painter().add(Shape::convex_polygon(
+                                segment_points.iter().map(transform).collect_vec(),
+                                glow_color,
+                                Stroke::new(segment_thickness * 0.5, glow_color),
+                            ));
+                        }
+                    }
+                }
+
                 for (segment_index, segment_points) in segment_geometry.iter().enumerate() {
-                    let segment_active = ((digit.glyph >> segment_index) & 0x01) != 0x00;
+                    let (fill, stroke) = foreground(segment_index, segment_active(segment_index));
 
                     // TODO: concave_polygon
                     // https://github.com/emilk/egui/issues/513
                     child_ui.painter().add(Shape::convex_polygon(
                         segment_points.iter().map(transform).collect_vec(),
-                        self.style.foreground_color(segment_active),
-                        self.style.foreground_stroke(segment_active),
+                        fill,
+                        stroke,
                     ));
                 }
 
                 if self.show_dots {
+                    let (fill, stroke) = foreground(segment_count, digit_lit && digit.dot);
+
                     child_ui.painter().circle(
                         transform(&dot_pos),
                         segment_thickness / 2.0,
-                        self.style.foreground_color(digit.dot),
-                        self.style.foreground_stroke(digit.dot),
+                        fill,
+                        stroke,
                     );
                 }
 
                 if self.show_colons {
+                    let (fill, stroke) = foreground(segment_count + 1, digit_lit && digit.colon);
+
                     child_ui.painter().circle(
                         transform(&colon_top_pos),
                         segment_thickness / 2.0,
-                        self.style.foreground_color(digit.colon),
-                        self.style.foreground_stroke(digit.colon),
+                        fill,
+                        stroke,
                     );
 
+                    let (fill, stroke) = foreground(segment_count + 2, digit_lit && digit.colon);
+
                     child_ui.painter().circle(
                         transform(&colon_bottom_pos),
                         segment_thickness / 2.0,
-                        self.style.foreground_color(digit.colon),
-                        self.style.foreground_stroke(digit.colon),
+                        fill,
+                        stroke,
                     );
                 }
 
                 if self.show_apostrophes {
+                    let (fill, stroke) =
+                        foreground(segment_count + 3, digit_lit && digit.apostrophe);
+
                     child_ui.painter().add(Shape::convex_polygon(
                         apostrophe_points.iter().map(transform).collect_vec(),
-                        self.style.foreground_color(digit.apostrophe),
-                        self.style.foreground_stroke(digit.apostrophe),
+                        fill,
+                        stroke,
                     ));
                 }
             };
 
-            for (digit_index, digit) in self.digits.iter().enumerate() {
-                let digit_center = rect.left_center()
-                    + vec2(
-                        margin_horizontal
-                            + digit_shearing.abs()
-                            + ((digit_width + digit_spacing) * digit_index as f32)
-                            + (digit_width / 2.0),
-                        0.0,
-                    );
+            let scrolling = marquee || (self.scroll_offset != 0.0);
+
+            let scroll_offset = if marquee {
+                // Marquees step one whole digit at a time like their hardware counterparts
+                let step_duration = 1.0 / self.marquee_speed as f64;
+
+                ui.ctx().request_repaint_after(Duration::from_secs_f64(
+                    step_duration - time.rem_euclid(step_duration),
+                ));
+
+                self.scroll_offset + (time / step_duration).floor() as f32
+            } else {
+                self.scroll_offset
+            };
+
+            // One extra slot is needed on the right side when scrolling by fractional offsets,
+            // the partially visible digits on the edges are clipped to the widget rect.
+            let (slot_count, scroll_offset_whole, scroll_offset_fract) = if scrolling {
+                (
+                    display_digit_count + 1,
+                    scroll_offset.floor() as isize,
+                    scroll_offset - scroll_offset.floor(),
+                )
+            } else {
+                (display_digit_count, 0, 0.0)
+            };
+
+            let loop_length = ((self.digits.len() + self.marquee_gap) as isize).max(1);
+
+            // Right aligned digits are shifted by the blank digits padding the left side
+            let alignment_offset = match self.alignment {
+                DisplayAlignment::Left => 0,
+                DisplayAlignment::Right => {
+                    self.digits.len() as isize - display_digit_count as isize
+                }
+            };
+
+            // Truncated strings are marked in the leftmost digit, like numeric overflows
+            let overflow_glyph = self
+                .overflow_character
+                .filter(|_| !scrolling && (self.digits.len() > display_digit_count))
+                .map(|overflow_character| {
+                    display_impl
+                        .glyph(overflow_character)
+                        .unwrap_or((1 << display_impl.segment_count()) - 1)
+                });
+
+            for slot_index in 0..slot_count {
+                let digit_index = if scrolling {
+                    Some(
+                        (slot_index as isize + scroll_offset_whole).rem_euclid(loop_length)
+                            as usize,
+                    )
+                } else {
+                    usize::try_from(slot_index as isize + alignment_offset).ok()
+                };
+
+                // Slots outside of the string (padding and marquee gaps) are blank
+                let digit = match overflow_glyph {
+                    Some(glyph) if slot_index == 0 => DisplayDigit {
+                        glyph,
+                        ..Default::default()
+                    },
+                    _ => digit_index
+                        .and_then(|digit_index| self.digits.get(digit_index))
+                        .copied()
+                        .unwrap_or_default(),
+                };
+
+                let digit_offset = margin_horizontal
+                    + digit_shearing.abs()
+                    + ((digit_width + digit_spacing) * (slot_index as f32 - scroll_offset_fract))
+                    + (digit_width / 2.0);
+
+                let digit_center = if self.mirrored {
+                    rect.right_center() - vec2(digit_offset, 0.0)
+                } else {
+                    rect.left_center() + vec2(digit_offset, 0.0)
+                };
+
+                let digit_blinking = digit_index
+                    .and_then(|digit_index| self.blink_mask.get(digit_index))
+                    .copied()
+                    .unwrap_or(false);
+
+                let digit_color = digit_index
+                    .and_then(|digit_index| self.digit_colors.get(digit_index))
+                    .copied()
+                    .flatten();
+
+                let digit_style = match digit_color {
+                    Some(digit_color) => DisplayStyle {
+                        active_foreground_color: digit_color,
+                        ..self.style
+                    },
+                    None => self.style,
+                };
 
-                paint_digit(digit, digit_center);
+                paint_digit(
+                    slot_index,
+                    &digit,
+                    digit_center,
+                    &digit_style,
+                    blink_visible || !digit_blinking,
+                );
             }
         }
 
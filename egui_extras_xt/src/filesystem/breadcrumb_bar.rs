@@ -6,6 +6,8 @@ use itertools::Itertools;
 
 use crate::filesystem::path_symbol::PathSymbol;
 use crate::filesystem::{DirectoryContextMenu, DirectoryFilter, DirectoryHoverUi};
+use crate::localization::localize;
+use crate::XtText;
 
 // ----------------------------------------------------------------------------
 
@@ -163,7 +165,10 @@ impl<'a> Widget for BreadcrumbBar<'a> {
                                 }
 
                                 if ui
-                                    .button(format!("Contents of {:?}", path_prefix))
+                                    .button(localize(
+                                        ui.ctx(),
+                                        XtText::DirectoryContents(path_prefix.clone()),
+                                    ))
                                     .clicked()
                                 {
                                     ui.close_menu();
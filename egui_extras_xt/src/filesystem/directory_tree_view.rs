@@ -9,6 +9,8 @@ use itertools::Itertools;
 use crate::filesystem::directory_cache::DirectoryCache;
 use crate::filesystem::path_symbol::PathSymbol;
 use crate::filesystem::{DirectoryContextMenu, DirectoryFilter, DirectoryHoverUi};
+use crate::localization::localize;
+use crate::XtText;
 
 // ----------------------------------------------------------------------------
 
@@ -158,9 +160,13 @@ impl<'a> DirectoryTreeViewWidget<'a> {
     ) -> Option<Response> {
         let directory_label = {
             let directory_name = if directory_path.parent().is_none() {
-                "Root directory"
+                localize(ui.ctx(), XtText::RootDirectory)
             } else {
-                directory_path.file_name().and_then(OsStr::to_str).unwrap()
+                directory_path
+                    .file_name()
+                    .and_then(OsStr::to_str)
+                    .unwrap()
+                    .to_owned()
             };
             let directory_symbol = directory_path.symbol();
 
@@ -276,7 +282,7 @@ impl<'a> DirectoryTreeViewWidget<'a> {
                             })
                             .reduce(|result, response| result.union(response))
                     } else {
-                        ui.weak("Empty directory");
+                        ui.weak(localize(ui.ctx(), XtText::EmptyDirectory));
                         None
                     }
                 }
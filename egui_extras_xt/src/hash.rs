@@ -38,9 +38,15 @@ pub trait PearsonHash {
     fn pearson_hash(&self) -> u8;
 }
 
+impl PearsonHash for [u8] {
+    fn pearson_hash(&self) -> u8 {
+        self.iter()
+            .fold(0, |hash, &b| PEARSON_LOOKUP[(hash ^ b) as usize])
+    }
+}
+
 impl PearsonHash for str {
     fn pearson_hash(&self) -> u8 {
-        self.bytes()
-            .fold(0, |hash, b| PEARSON_LOOKUP[(hash ^ b) as usize])
+        self.as_bytes().pearson_hash()
     }
 }
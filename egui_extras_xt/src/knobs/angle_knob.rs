@@ -1,11 +1,22 @@
 use std::f32::consts::TAU;
 
-use egui::{self, Response, Sense, Ui, Widget};
-use emath::Vec2;
-use epaint::{Shape, Stroke};
+use egui::{
+    self, Align2, CursorIcon, FontId, FontSelection, Response, Sense, TextStyle, Ui, Widget,
+    WidgetText,
+};
+use emath::{normalized_angle, pos2, vec2, Rect, Vec2};
+use epaint::{Color32, Shape, Stroke};
 use strum::{Display, EnumIter};
 
-use crate::common::{snap_wrap_constrain_angle, Orientation, WidgetShape, Winding, WrapMode};
+use crate::common::{
+    animate_value, detent_crossings, indeterminate_get_set, long_press_as_secondary_click,
+    nearest_angle, paint_glow, peak_deflection, record_sample, slider_widget_info,
+    snap_wrap_constrain_angle, spring_back, Orientation, WidgetShape, Winding, WrapMode,
+    DEFAULT_ANGLE_SHIFT_SNAP,
+};
+use crate::knobs::value_entry::{parse_angle, value_entry_popup};
+use crate::localization::localize;
+use crate::{xt_colors, XtText, XtWidgetState};
 
 // ----------------------------------------------------------------------------
 
@@ -21,6 +32,27 @@ fn set(get_set_value: &mut GetSetValue<'_>, value: f32) {
     (get_set_value)(Some(value));
 }
 
+type Easing<'a> = Box<dyn 'a + Fn(f32) -> f32>;
+
+type ValueFormatter<'a> = Box<dyn 'a + Fn(f32) -> String>;
+
+type DetentCallback<'a> = Box<dyn 'a + FnMut(i32)>;
+
+type ReleaseCallback<'a> = Box<dyn 'a + FnMut(f32)>;
+
+// ----------------------------------------------------------------------------
+
+#[derive(Clone, Copy, Debug, Display, EnumIter, Eq, PartialEq)]
+pub enum KnobInteraction {
+    /// The value follows the angle of the pointer around the knob center.
+    #[strum(to_string = "Absolute")]
+    Absolute,
+
+    /// Dragging up or down changes the value relative to where the drag started.
+    #[strum(to_string = "Relative vertical")]
+    RelativeVertical,
+}
+
 // ----------------------------------------------------------------------------
 
 #[non_exhaustive]
@@ -91,22 +123,66 @@ impl AngleKnobPreset {
     }
 }
 
+/// Response of [`AngleKnob::show`], with the value in both units.
+pub struct AngleKnobResponse {
+    pub response: Response,
+
+    /// The value as stored, in radians.
+    pub value_radians: f32,
+
+    /// The value in degrees, normalized according to the active [`WrapMode`].
+    pub value_degrees: f32,
+}
+
+// ----------------------------------------------------------------------------
+
 #[must_use = "You should put this widget in an ui with `ui.add(widget);`"]
 pub struct AngleKnob<'a> {
     get_set_value: GetSetValue<'a>,
     interactive: bool,
-    diameter: f32,
+    label: Option<WidgetText>,
+    editable: bool,
+    indeterminate: bool,
+    interaction: KnobInteraction,
+    drag_sensitivity: f32,
+    lock_cursor: bool,
+    diameter: Option<f32>,
     orientation: Orientation,
     winding: Winding,
+    mirrored: bool,
     wrap: WrapMode,
     shape: WidgetShape,
+    glow: bool,
     min: Option<f32>,
     max: Option<f32>,
     snap: Option<f32>,
     shift_snap: Option<f32>,
+    snap_angles: Vec<f32>,
     animated: bool,
+    animation_time: Option<f32>,
+    easing: Option<Easing<'a>>,
     show_axes: bool,
     axis_count: usize,
+    axis_angles: Option<Vec<f32>>,
+    axis_offset: f32,
+    axis_colors: Vec<Color32>,
+    axis_labels: Vec<String>,
+    show_value: bool,
+    value_formatter: Option<ValueFormatter<'a>>,
+    tick_count: usize,
+    tick_length: f32,
+    major_tick_every: Option<usize>,
+    show_range: bool,
+    show_drag_origin: bool,
+    reference_values: Vec<f32>,
+    show_turns: bool,
+    on_detent: Option<DetentCallback<'a>>,
+    spring_to: Option<f32>,
+    spring_speed: f32,
+    on_release: Option<ReleaseCallback<'a>>,
+    long_press_duration: Option<f32>,
+    recording: Option<&'a mut Vec<(f64, f32)>>,
+    reset_state: bool,
 }
 
 impl<'a> AngleKnob<'a> {
@@ -123,33 +199,108 @@ impl<'a> AngleKnob<'a> {
         Self {
             get_set_value: Box::new(get_set_value),
             interactive: true,
-            diameter: 32.0,
+            label: None,
+            editable: false,
+            indeterminate: false,
+            interaction: KnobInteraction::Absolute,
+            drag_sensitivity: 0.01,
+            lock_cursor: false,
+            diameter: None,
             orientation: Orientation::Top,
             winding: Winding::Clockwise,
+            mirrored: false,
             wrap: WrapMode::Unsigned,
             shape: WidgetShape::Circle,
+            glow: false,
             min: None,
             max: None,
             snap: None,
-            shift_snap: Some(15.0f32.to_radians()),
+            shift_snap: Some(DEFAULT_ANGLE_SHIFT_SNAP),
+            snap_angles: Vec::new(),
             animated: false,
+            animation_time: None,
+            easing: None,
             show_axes: true,
             axis_count: 4,
+            axis_angles: None,
+            axis_offset: 0.0,
+            axis_colors: Vec::new(),
+            axis_labels: Vec::new(),
+            show_value: false,
+            value_formatter: None,
+            tick_count: 0,
+            tick_length: 0.15,
+            major_tick_every: None,
+            show_range: false,
+            show_drag_origin: true,
+            reference_values: Vec::new(),
+            show_turns: false,
+            on_detent: None,
+            spring_to: None,
+            spring_speed: TAU * 2.0,
+            on_release: None,
+            long_press_duration: Some(0.6),
+            recording: None,
+            reset_state: false,
         }
     }
 
+    /// Read-only when `false`: only hovering is sensed and the widget is painted with
+    /// the noninteractive visuals. Defaults to `true`.
     pub fn interactive(mut self, interactive: bool) -> Self {
         self.interactive = interactive;
         self
     }
 
+    /// Name of the widget announced by screen readers, e.g. "Heading".
+    pub fn label(mut self, label: impl Into<WidgetText>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// Lets the user type an exact value into a popup opened by right-click or double-click.
+    pub fn editable(mut self, editable: bool) -> Self {
+        self.editable = editable;
+        self
+    }
+
+    /// Shows the value as unknown, e.g. for a selection of parameters with differing
+    /// values. The knob is dimmed without an indicator and the value isn't read.
+    /// Dragging or entering a value resolves it and marks the response as changed,
+    /// the caller is expected to clear the indeterminate state then.
+    pub fn indeterminate(mut self, indeterminate: bool) -> Self {
+        self.indeterminate = indeterminate;
+        self
+    }
+
+    pub fn interaction(mut self, interaction: KnobInteraction) -> Self {
+        self.interaction = interaction;
+        self
+    }
+
+    /// Radians per point of vertical drag in [`KnobInteraction::RelativeVertical`] mode.
+    pub fn drag_sensitivity(mut self, drag_sensitivity: impl Into<f32>) -> Self {
+        self.drag_sensitivity = drag_sensitivity.into();
+        self
+    }
+
+    /// Hides the pointer during [`KnobInteraction::RelativeVertical`] drags, so they feel
+    /// unbounded on backends that keep reporting motion past the window edge.
+    pub fn lock_cursor(mut self, lock_cursor: bool) -> Self {
+        self.lock_cursor = lock_cursor;
+        self
+    }
+
+    /// Sets orientation, winding and wrap mode from `preset`.
+    /// Other properties are left untouched and the three can still be overridden afterwards.
     pub fn preset(mut self, preset: AngleKnobPreset) -> Self {
         (self.orientation, self.winding, self.wrap) = preset.properties();
         self
     }
 
+    /// Defaults to 1.6 times the height of `ui.spacing().interact_size`.
     pub fn diameter(mut self, diameter: impl Into<f32>) -> Self {
-        self.diameter = diameter.into();
+        self.diameter = Some(diameter.into());
         self
     }
 
@@ -158,6 +309,12 @@ impl<'a> AngleKnob<'a> {
         self
     }
 
+    /// Mirrors the widget horizontally, e.g. for right-to-left layouts.
+    pub fn mirrored(mut self, mirrored: bool) -> Self {
+        self.mirrored = mirrored;
+        self
+    }
+
     pub fn orientation(mut self, orientation: Orientation) -> Self {
         self.orientation = orientation;
         self
@@ -168,6 +325,12 @@ impl<'a> AngleKnob<'a> {
         self
     }
 
+    /// Surrounds the knob with a soft halo while it is being dragged.
+    pub fn glow(mut self, glow: bool) -> Self {
+        self.glow = glow;
+        self
+    }
+
     pub fn wrap(mut self, wrap: WrapMode) -> Self {
         self.wrap = wrap;
         self
@@ -183,13 +346,23 @@ impl<'a> AngleKnob<'a> {
         self
     }
 
-    pub fn snap(mut self, snap: Option<f32>) -> Self {
-        self.snap = snap;
+    /// Snaps the angle when dragging, in radians. No snapping by default.
+    pub fn snap(mut self, snap: impl Into<Option<f32>>) -> Self {
+        self.snap = snap.into();
         self
     }
 
-    pub fn shift_snap(mut self, shift_snap: Option<f32>) -> Self {
-        self.shift_snap = shift_snap;
+    /// Snaps the angle while Shift is held, in radians. Defaults to 15°, pass `None`
+    /// to disable it.
+    pub fn shift_snap(mut self, shift_snap: impl Into<Option<f32>>) -> Self {
+        self.shift_snap = shift_snap.into();
+        self
+    }
+
+    /// Snaps to the nearest of an explicit list of angles, taking precedence over
+    /// `snap` and `shift_snap`.
+    pub fn snap_angles(mut self, snap_angles: &[f32]) -> Self {
+        self.snap_angles = snap_angles.to_vec();
         self
     }
 
@@ -198,6 +371,19 @@ impl<'a> AngleKnob<'a> {
         self
     }
 
+    /// Duration of the animations in seconds, defaults to the animation time of the style.
+    pub fn animation_time(mut self, animation_time: impl Into<f32>) -> Self {
+        self.animation_time = Some(animation_time.into());
+        self
+    }
+
+    /// Shapes the animations, mapping their linear progress to the eased progress,
+    /// both within `0.0..=1.0`.
+    pub fn easing(mut self, easing: impl 'a + Fn(f32) -> f32) -> Self {
+        self.easing = Some(Box::new(easing));
+        self
+    }
+
     pub fn show_axes(mut self, show_axes: bool) -> Self {
         self.show_axes = show_axes;
         self
@@ -207,14 +393,216 @@ impl<'a> AngleKnob<'a> {
         self.axis_count = axis_count;
         self
     }
+
+    /// Places the axes at arbitrary angles instead of spacing `axis_count` of them evenly.
+    pub fn axis_angles(mut self, axis_angles: &[f32]) -> Self {
+        self.axis_angles = Some(axis_angles.to_vec());
+        self
+    }
+
+    /// Rotates all axes (and their labels) by `axis_offset` radians.
+    pub fn axis_offset(mut self, axis_offset: impl Into<f32>) -> Self {
+        self.axis_offset = axis_offset.into();
+        self
+    }
+
+    /// Stroke colors of the axes in order, axes without a color use the default one.
+    pub fn axis_colors(mut self, axis_colors: &[Color32]) -> Self {
+        self.axis_colors = axis_colors.to_vec();
+        self
+    }
+
+    /// Labels painted outside of the knob at each axis, one for every axis.
+    /// Empty labels are skipped, labels past the last axis are ignored and
+    /// axes past the last label are left unlabeled.
+    pub fn axis_labels(mut self, axis_labels: impl IntoIterator<Item = impl ToString>) -> Self {
+        self.axis_labels = axis_labels
+            .into_iter()
+            .map(|label| label.to_string())
+            .collect();
+        self
+    }
+
+    pub fn show_value(mut self, show_value: bool) -> Self {
+        self.show_value = show_value;
+        self
+    }
+
+    pub fn value_formatter(mut self, value_formatter: impl 'a + Fn(f32) -> String) -> Self {
+        self.value_formatter = Some(Box::new(value_formatter));
+        self
+    }
+
+    pub fn tick_count(mut self, tick_count: usize) -> Self {
+        self.tick_count = tick_count;
+        self
+    }
+
+    /// Length of the ticks relative to the knob radius.
+    /// Major ticks are drawn twice as long.
+    pub fn tick_length(mut self, tick_length: impl Into<f32>) -> Self {
+        self.tick_length = tick_length.into();
+        self
+    }
+
+    /// `Some(0)` is treated like `None`, no ticks are major.
+    pub fn major_tick_every(mut self, major_tick_every: Option<usize>) -> Self {
+        self.major_tick_every = major_tick_every.filter(|&major_tick_every| major_tick_every > 0);
+        self
+    }
+
+    /// Shades the region outside of the `min`/`max` limits when both are set.
+    pub fn show_range(mut self, show_range: bool) -> Self {
+        self.show_range = show_range;
+        self
+    }
+
+    /// Shows a faint pointer at the value the knob had when the current drag started.
+    pub fn show_drag_origin(mut self, show_drag_origin: bool) -> Self {
+        self.show_drag_origin = show_drag_origin;
+        self
+    }
+
+    /// Paints a faint ghost of the pointer at `reference_value`, wrapped and limited
+    /// like the value itself. Doesn't affect interaction.
+    pub fn reference_value(mut self, reference_value: Option<f32>) -> Self {
+        self.reference_values = reference_value.into_iter().collect();
+        self
+    }
+
+    /// Like [`Self::reference_value`], but with a ghost for every value.
+    pub fn reference_values(mut self, reference_values: &[f32]) -> Self {
+        self.reference_values = reference_values.to_vec();
+        self
+    }
+
+    /// Shows a "×N" counter inside the knob once the value is a full turn or more
+    /// away from zero, as it can be with [`WrapMode::None`].
+    pub fn show_turns(mut self, show_turns: bool) -> Self {
+        self.show_turns = show_turns;
+        self
+    }
+
+    /// Called with the number of snap detents crossed while dragging,
+    /// negative when the value decreases.
+    pub fn on_detent(mut self, on_detent: impl 'a + FnMut(i32)) -> Self {
+        self.on_detent = Some(Box::new(on_detent));
+        self
+    }
+
+    /// Makes the knob spring-loaded: once released, the value returns to `spring_to`.
+    pub fn spring_to(mut self, spring_to: Option<f32>) -> Self {
+        self.spring_to = spring_to;
+        self
+    }
+
+    /// Radians per second the value returns to the spring target with.
+    pub fn spring_speed(mut self, spring_speed: impl Into<f32>) -> Self {
+        self.spring_speed = spring_speed.into();
+        self
+    }
+
+    /// Called when a spring-loaded knob is released,
+    /// with the peak deflection from the spring target during the drag.
+    pub fn on_release(mut self, on_release: impl 'a + FnMut(f32)) -> Self {
+        self.on_release = Some(Box::new(on_release));
+        self
+    }
+
+    /// Seconds the pointer has to be held down to act as a right-click on touch screens,
+    /// `None` disables long-presses.
+    pub fn long_press_duration(mut self, long_press_duration: Option<f32>) -> Self {
+        self.long_press_duration = long_press_duration;
+        self
+    }
+
+    /// Appends a `(time, value)` sample to `recording` whenever the user changes the value.
+    /// Changes made within the same frame are coalesced into one sample,
+    /// thinning or compressing the recording is left to the caller.
+    pub fn record_into(mut self, recording: &'a mut Vec<(f64, f32)>) -> Self {
+        self.recording = Some(recording);
+        self
+    }
+
+    /// Forgets the transient state of the widget, see [`XtWidgetState`].
+    pub fn reset_state(mut self) -> Self {
+        self.reset_state = true;
+        self
+    }
 }
 
 impl<'a> Widget for AngleKnob<'a> {
-    fn ui(mut self, ui: &mut Ui) -> Response {
-        let desired_size = Vec2::splat(self.diameter);
+    fn ui(self, ui: &mut Ui) -> Response {
+        self.show(ui).response
+    }
+}
+
+impl<'a> AngleKnob<'a> {
+    /// Adds the knob to `ui`, like `ui.add(widget)` but also returning the value.
+    pub fn show(mut self, ui: &mut Ui) -> AngleKnobResponse {
+        let diameter = self.diameter.unwrap_or(ui.spacing().interact_size.y * 1.6);
+
+        let value_font_id = FontSelection::Default.resolve(ui.style());
+
+        let value_text_height = if self.show_value {
+            ui.fonts(|fonts| fonts.row_height(&value_font_id))
+        } else {
+            0.0
+        };
+
+        let axis_angles = self
+            .axis_angles
+            .clone()
+            .unwrap_or_else(|| {
+                (0..self.axis_count)
+                    .map(|axis| axis as f32 * (TAU / (self.axis_count as f32)))
+                    .collect()
+            })
+            .into_iter()
+            .map(|axis_angle| axis_angle + self.axis_offset)
+            .collect::<Vec<_>>();
+
+        let axis_label_font_id = TextStyle::Small.resolve(ui.style());
 
-        let (rect, mut response) = ui.allocate_exact_size(
-            desired_size,
+        let axis_label_height = if self.axis_labels.is_empty() {
+            0.0
+        } else {
+            ui.fonts(|fonts| fonts.row_height(&axis_label_font_id))
+        };
+
+        let desired_size =
+            Vec2::splat(diameter + axis_label_height * 2.0) + vec2(0.0, value_text_height);
+
+        let (id, space_rect) = ui.allocate_space(desired_size);
+        let widget_rect = ui.layout().align_size_within_rect(desired_size, space_rect);
+
+        // The axis labels surround the knob and the value readout is placed below it,
+        // keep the knob itself square.
+        let rect = Rect::from_min_size(
+            widget_rect.min + Vec2::splat(axis_label_height),
+            Vec2::splat(diameter),
+        );
+
+        let orientation = self.orientation.mirrored(self.mirrored);
+        let winding = self.winding.mirrored(self.mirrored);
+        let rotation_matrix = orientation.rot2();
+
+        // Only the knob shape is hoverable, so the corners of the rect don't start drags.
+        // Drags already in progress keep tracking the pointer outside of the shape.
+        let hovered = ui.rect_contains_pointer(rect)
+            && ui.ctx().pointer_hover_pos().is_some_and(|pointer_pos| {
+                let pointer_offset = pointer_pos - rect.center();
+                let shape_radius = self
+                    .shape
+                    .eval(pointer_offset.angle() - (rotation_matrix * Vec2::RIGHT).angle())
+                    * (diameter / 2.0);
+                pointer_offset.length() <= shape_radius
+            });
+
+        let mut response = ui.interact_with_hovered(
+            widget_rect,
+            hovered,
+            id,
             if self.interactive {
                 Sense::click_and_drag()
             } else {
@@ -222,41 +610,232 @@ impl<'a> Widget for AngleKnob<'a> {
             },
         );
 
-        let rotation_matrix = self.orientation.rot2();
+        if self.indeterminate {
+            let placeholder =
+                snap_wrap_constrain_angle(0.0, 0.0, None, self.wrap, self.min, self.max);
+            let get_set_value = std::mem::replace(&mut self.get_set_value, Box::new(|_| 0.0));
+            self.get_set_value = indeterminate_get_set(get_set_value, placeholder);
+        }
 
-        if response.clicked() || response.dragged() {
+        if self.reset_state {
+            XtWidgetState::reset(ui.ctx(), response.id);
+        }
+
+        if let (true, Some(long_press_duration)) = (self.interactive, self.long_press_duration) {
+            long_press_as_secondary_click(ui, &mut response, long_press_duration);
+        }
+
+        if response.drag_started() {
+            let value_before_drag = get(&mut self.get_set_value);
+            XtWidgetState::update(ui.ctx(), response.id, |state| {
+                state.drag_origin_value = Some(value_before_drag);
+                state.drag_accumulator = None;
+            });
+        }
+
+        if response.drag_released() {
+            XtWidgetState::update(ui.ctx(), response.id, |state| {
+                state.drag_origin_value = None;
+            });
+        }
+
+        let value_changed_by_pointer = match self.interaction {
+            // Pressing an indeterminate knob to open the value entry popup mustn't resolve it
+            KnobInteraction::Absolute if self.indeterminate => {
+                response.clicked() || (response.dragged() && response.drag_delta() != Vec2::ZERO)
+            }
+            KnobInteraction::Absolute => response.clicked() || response.dragged(),
+            // A press without movement already counts as a drag, leave the value alone then
+            KnobInteraction::RelativeVertical => {
+                response.dragged() && response.drag_delta() != Vec2::ZERO
+            }
+        };
+
+        if self.lock_cursor
+            && response.dragged()
+            && self.interaction == KnobInteraction::RelativeVertical
+        {
+            ui.ctx().set_cursor_icon(CursorIcon::None);
+        }
+
+        if value_changed_by_pointer {
             let prev_value = get(&mut self.get_set_value);
-            let mut new_value = (rotation_matrix.inverse()
-                * (response.interact_pointer_pos().unwrap() - rect.center()))
-            .angle()
-                * self.winding.to_float();
+            let mut new_value = match self.interaction {
+                KnobInteraction::Absolute => {
+                    (rotation_matrix.inverse()
+                        * (response.interact_pointer_pos().unwrap() - rect.center()))
+                    .angle()
+                        * winding.to_float()
+                }
+                KnobInteraction::RelativeVertical => {
+                    // Accumulate the unsnapped value in the widget state, otherwise small
+                    // drag deltas would be snapped away on every frame.
+                    let mut state = XtWidgetState::load(ui.ctx(), response.id);
+
+                    let accumulated_value = state.drag_accumulator.unwrap_or(prev_value);
+
+                    let sensitivity = if ui.input(|input| input.modifiers.ctrl) {
+                        self.drag_sensitivity * 0.1
+                    } else {
+                        self.drag_sensitivity
+                    };
+
+                    let mut accumulated_value =
+                        accumulated_value - response.drag_delta().y * sensitivity;
+
+                    if let Some(min) = self.min {
+                        accumulated_value = accumulated_value.max(min);
+                    }
+
+                    if let Some(max) = self.max {
+                        accumulated_value = accumulated_value.min(max);
+                    }
+
+                    state.drag_accumulator = Some(accumulated_value);
+                    state.store(ui.ctx(), response.id);
+
+                    // Wrapping is handled by `snap_wrap_constrain_angle` below,
+                    // which expects a raw angle like the one from the pointer position.
+                    normalized_angle(accumulated_value)
+                }
+            };
+
+            let snap = if !self.snap_angles.is_empty() {
+                None
+            } else if ui.input(|input| input.modifiers.shift_only()) {
+                self.shift_snap
+            } else {
+                self.snap
+            };
+
+            if let Some(snap_angle) = nearest_angle(&self.snap_angles, new_value) {
+                new_value = snap_angle;
+            }
 
             new_value = snap_wrap_constrain_angle(
-                prev_value,
-                new_value,
-                if ui.input(|input| input.modifiers.shift_only()) {
-                    self.shift_snap
-                } else {
-                    self.snap
-                },
-                self.wrap,
-                self.min,
-                self.max,
+                prev_value, new_value, snap, self.wrap, self.min, self.max,
             );
 
+            if let (Some(on_detent), Some(snap)) = (&mut self.on_detent, snap) {
+                // Unwrap the new value so crossing the 0°/360° boundary counts as a single step
+                let unwrapped_value = if self.wrap == WrapMode::None {
+                    new_value
+                } else {
+                    prev_value + normalized_angle(new_value - prev_value)
+                };
+
+                let crossings = detent_crossings(prev_value, unwrapped_value, snap);
+                if crossings != 0 {
+                    on_detent(crossings);
+                }
+            }
+
             set(&mut self.get_set_value, new_value);
             response.mark_changed();
+
+            if let Some(recording) = &mut self.recording {
+                record_sample(recording, ui.input(|input| input.time), new_value);
+            }
+        }
+
+        // Springing back would resolve the value without the user picking one
+        if let (Some(spring_target), false) = (self.spring_to, self.indeterminate) {
+            let value = get(&mut self.get_set_value);
+
+            let deflection = if self.wrap == WrapMode::None {
+                value - spring_target
+            } else {
+                normalized_angle(value - spring_target)
+            };
+
+            if response.dragged() {
+                XtWidgetState::update(ui.ctx(), response.id, |state| {
+                    state.spring_peak = Some(peak_deflection(state.spring_peak, deflection));
+                });
+            } else if deflection != 0.0 {
+                let new_value = self
+                    .wrap
+                    .wrap_angle(spring_target + spring_back(ui, deflection, self.spring_speed));
+
+                set(&mut self.get_set_value, new_value);
+                response.mark_changed();
+
+                if let Some(recording) = &mut self.recording {
+                    record_sample(recording, ui.input(|input| input.time), new_value);
+                }
+            }
+
+            if response.drag_released() {
+                let spring_peak =
+                    XtWidgetState::update(ui.ctx(), response.id, |state| state.spring_peak.take());
+
+                if let (Some(on_release), Some(spring_peak)) = (&mut self.on_release, spring_peak) {
+                    on_release(spring_peak);
+                }
+            }
+        }
+
+        if self.interactive && self.editable {
+            let entered_value = value_entry_popup(
+                ui,
+                &response,
+                || {
+                    if self.indeterminate {
+                        String::new()
+                    } else {
+                        format!("{:.2}", get(&mut self.get_set_value).to_degrees())
+                    }
+                },
+                parse_angle,
+            );
+
+            if let Some(entered_value) = entered_value {
+                let mut new_value = self.wrap.wrap_angle(entered_value);
+
+                if let Some(min) = self.min {
+                    new_value = new_value.max(min);
+                }
+
+                if let Some(max) = self.max {
+                    new_value = new_value.min(max);
+                }
+
+                set(&mut self.get_set_value, new_value);
+                response.mark_changed();
+
+                if let Some(recording) = &mut self.recording {
+                    record_sample(recording, ui.input(|input| input.time), new_value);
+                }
+            }
+        }
+
+        {
+            let value = get(&mut self.get_set_value);
+            slider_widget_info(
+                ui,
+                &response,
+                self.label.as_ref(),
+                value.to_degrees() as f64,
+                self.min.map(|min| min.to_degrees() as f64),
+                self.max.map(|max| max.to_degrees() as f64),
+                self.snap.map(|snap| snap.to_degrees() as f64),
+            );
         }
 
-        if ui.is_rect_visible(rect) {
+        // Shown resolved as soon as the user picks a value
+        let indeterminate = self.indeterminate && !response.changed();
+
+        if ui.is_rect_visible(widget_rect) {
             let visuals = *ui.style().interact(&response);
-            let radius = self.diameter / 2.0;
+            let radius = diameter / 2.0;
 
             let value = if self.animated {
-                ui.ctx().animate_value_with_time(
+                animate_value(
+                    ui.ctx(),
                     response.id,
                     get(&mut self.get_set_value),
-                    ui.style().animation_time,
+                    self.animation_time.unwrap_or(ui.style().animation_time),
+                    self.easing.as_deref(),
                 )
             } else {
                 get(&mut self.get_set_value)
@@ -264,37 +843,134 @@ impl<'a> Widget for AngleKnob<'a> {
 
             let angle_to_shape_outline = |angle: f32| {
                 rotation_matrix
-                    * Vec2::angled(angle * self.winding.to_float())
-                    * (self.shape.eval(angle * self.winding.to_float()) * radius)
+                    * Vec2::angled(angle * winding.to_float())
+                    * (self.shape.eval(angle * winding.to_float()) * radius)
             };
 
-            self.shape.paint_shape(
-                ui,
-                rect.center(),
-                radius,
-                visuals.bg_fill,
-                visuals.fg_stroke,
-                self.orientation.rot2(),
-            );
+            if self.glow && response.dragged() {
+                paint_glow(ui, rect.center(), radius, 4.0, xt_colors(ui).info);
+            }
+
+            if indeterminate {
+                self.shape.paint_shape(
+                    ui,
+                    rect.center(),
+                    radius,
+                    visuals.bg_fill.linear_multiply(0.5), // TODO: Semantically correct color
+                    Stroke::NONE,
+                    orientation.rot2(),
+                );
+
+                let outline_points = (0..=64)
+                    .map(|point| rect.center() + angle_to_shape_outline(point as f32 / 64.0 * TAU))
+                    .collect::<Vec<_>>();
+
+                ui.painter().add(Shape::dashed_line(
+                    &outline_points,
+                    visuals.fg_stroke,
+                    2.0,
+                    2.0,
+                ));
+            } else {
+                self.shape.paint_shape(
+                    ui,
+                    rect.center(),
+                    radius,
+                    visuals.bg_fill,
+                    visuals.fg_stroke,
+                    orientation.rot2(),
+                );
+            }
+
+            if let (true, Some(min), Some(max)) = (self.show_range, self.min, self.max) {
+                // Nothing is disallowed when the limits span a full turn or more
+                if (0.0..TAU).contains(&(max - min)) {
+                    let orientation_angle = (rotation_matrix * Vec2::RIGHT).angle();
+
+                    self.shape.paint_arc(
+                        ui,
+                        rect.center(),
+                        0.0,
+                        radius,
+                        orientation_angle + max * winding.to_float(),
+                        orientation_angle + (min + TAU) * winding.to_float(),
+                        visuals.fg_stroke.color.linear_multiply(0.15), // TODO: Semantically correct color
+                        Stroke::NONE,
+                        rotation_matrix,
+                    );
+                }
+            }
 
             {
-                let paint_axis = |axis_angle| {
+                let paint_tick = |tick_angle, tick_length: f32| {
+                    let tick_outline = angle_to_shape_outline(tick_angle);
+
+                    ui.painter().line_segment(
+                        [
+                            rect.center() + tick_outline * (1.0 - tick_length.clamp(0.0, 1.0)),
+                            rect.center() + tick_outline,
+                        ],
+                        ui.visuals().noninteractive().fg_stroke,
+                    );
+                };
+
+                for tick in 0..self.tick_count {
+                    let is_major_tick = self
+                        .major_tick_every
+                        .is_some_and(|major_tick_every| tick % major_tick_every == 0);
+
+                    paint_tick(
+                        tick as f32 * (TAU / (self.tick_count as f32)),
+                        if is_major_tick {
+                            self.tick_length * 2.0
+                        } else {
+                            self.tick_length
+                        },
+                    );
+                }
+            }
+
+            if self.show_axes {
+                let paint_axis = |axis_angle, axis_stroke| {
                     ui.painter().add(Shape::dashed_line(
                         &[
                             rect.center(),
                             rect.center() + angle_to_shape_outline(axis_angle),
                         ],
-                        ui.visuals().window_stroke(), // TODO: Semantically correct color
+                        axis_stroke,
                         1.0,
                         1.0,
                     ));
                 };
 
-                if self.show_axes {
-                    for axis in 0..self.axis_count {
-                        paint_axis(axis as f32 * (TAU / (self.axis_count as f32)));
-                    }
+                let default_axis_stroke = ui.visuals().window_stroke(); // TODO: Semantically correct color
+
+                for (axis, &axis_angle) in axis_angles.iter().enumerate() {
+                    let axis_stroke = match self.axis_colors.get(axis) {
+                        Some(&axis_color) => Stroke::new(default_axis_stroke.width, axis_color),
+                        None => default_axis_stroke,
+                    };
+
+                    paint_axis(axis_angle, axis_stroke);
+                }
+            }
+
+            for (axis_label, &axis_angle) in self.axis_labels.iter().zip(&axis_angles) {
+                if axis_label.is_empty() {
+                    continue;
                 }
+
+                let axis_outline = angle_to_shape_outline(axis_angle);
+
+                ui.painter().text(
+                    rect.center()
+                        + axis_outline
+                        + axis_outline.normalized() * (axis_label_height / 2.0),
+                    Align2::CENTER_CENTER,
+                    axis_label,
+                    axis_label_font_id.clone(),
+                    ui.visuals().text_color(), // TODO: Semantically correct color
+                );
             }
 
             {
@@ -305,10 +981,9 @@ impl<'a> Widget for AngleKnob<'a> {
                                 .clamp(0.0, 1.0)
                                 .powf(5.0);
 
-                        // TODO: Semantically correct color
                         Stroke::new(
                             visuals.fg_stroke.width,
-                            visuals.fg_stroke.color.linear_multiply(stop_alpha),
+                            xt_colors(ui).warning.linear_multiply(stop_alpha),
                         )
                     };
 
@@ -330,7 +1005,52 @@ impl<'a> Widget for AngleKnob<'a> {
                 }
             }
 
-            {
+            if self.show_drag_origin && response.dragged() {
+                if let Some(drag_origin_value) =
+                    XtWidgetState::load(ui.ctx(), response.id).drag_origin_value
+                {
+                    ui.painter().line_segment(
+                        [
+                            rect.center(),
+                            rect.center() + angle_to_shape_outline(drag_origin_value),
+                        ],
+                        Stroke::new(
+                            visuals.fg_stroke.width,
+                            visuals.fg_stroke.color.linear_multiply(0.3),
+                        ), // TODO: Semantically correct color
+                    );
+                }
+            }
+
+            for &reference_value in &self.reference_values {
+                let reference_value = snap_wrap_constrain_angle(
+                    reference_value,
+                    reference_value,
+                    None,
+                    self.wrap,
+                    self.min,
+                    self.max,
+                );
+                let reference_outline = angle_to_shape_outline(reference_value);
+                let reference_stroke = Stroke::new(
+                    visuals.fg_stroke.width,
+                    visuals.fg_stroke.color.linear_multiply(0.3),
+                ); // TODO: Semantically correct color
+
+                ui.painter().line_segment(
+                    [rect.center(), rect.center() + reference_outline],
+                    reference_stroke,
+                );
+
+                ui.painter().circle(
+                    rect.center() + reference_outline,
+                    diameter / 24.0,
+                    visuals.text_color().linear_multiply(0.3), // TODO: Semantically correct color
+                    reference_stroke,
+                );
+            }
+
+            if !indeterminate {
                 ui.painter().line_segment(
                     [rect.center(), rect.center() + angle_to_shape_outline(value)],
                     visuals.fg_stroke, // TODO: Semantically correct color
@@ -338,20 +1058,50 @@ impl<'a> Widget for AngleKnob<'a> {
 
                 ui.painter().circle(
                     rect.center(),
-                    self.diameter / 24.0,
+                    diameter / 24.0,
                     visuals.text_color(), // TODO: Semantically correct color
                     visuals.fg_stroke,    // TODO: Semantically correct color
                 );
 
                 ui.painter().circle(
                     rect.center() + angle_to_shape_outline(value),
-                    self.diameter / 24.0,
+                    diameter / 24.0,
                     visuals.text_color(), // TODO: Semantically correct color
                     visuals.fg_stroke,    // TODO: Semantically correct color
                 );
             }
+
+            if self.show_turns && !indeterminate && value.abs() >= TAU {
+                ui.painter().text(
+                    rect.center() + vec2(0.0, radius * 0.5),
+                    Align2::CENTER_CENTER,
+                    localize(ui.ctx(), XtText::TurnCount(value / TAU)),
+                    FontId::proportional(diameter / 5.0),
+                    visuals.text_color(), // TODO: Semantically correct color
+                );
+            }
+
+            if self.show_value {
+                ui.painter().text(
+                    pos2(rect.center().x, rect.bottom() + axis_label_height),
+                    Align2::CENTER_TOP,
+                    match &self.value_formatter {
+                        _ if indeterminate => localize(ui.ctx(), XtText::Indeterminate),
+                        Some(value_formatter) => value_formatter(value),
+                        None => localize(ui.ctx(), XtText::Degrees(value.to_degrees())),
+                    },
+                    value_font_id,
+                    visuals.text_color(),
+                );
+            }
         }
 
-        response
+        let value_radians = get(&mut self.get_set_value);
+
+        AngleKnobResponse {
+            response,
+            value_radians,
+            value_degrees: self.wrap.wrap_angle(value_radians).to_degrees(),
+        }
     }
 }
@@ -1,10 +1,26 @@
 use std::f32::consts::TAU;
+use std::hash::Hash;
 use std::ops::RangeInclusive;
 
-use egui::{self, Response, Sense, Ui, Widget};
-use emath::{remap_clamp, Vec2};
+use ecolor::tint_color_towards;
+use egui::{
+    self, show_tooltip_at_pointer, show_tooltip_for, CursorIcon, Id, Response, Sense, Ui, Widget,
+    WidgetText,
+};
+use emath::{lerp, remap, remap_clamp, vec2, Numeric, Vec2};
+use epaint::{Color32, Shape, Stroke};
+use strum::{Display, EnumIter};
 
-use crate::common::{Orientation, WidgetShape, Winding};
+use crate::common::{
+    animate_value, detent_crossings, indeterminate_get_set, long_press_as_secondary_click,
+    paint_glow, peak_deflection, record_sample, settle_animation, slider_widget_info, spring_back,
+    Orientation, WidgetShape, Winding,
+};
+use crate::knobs::knob_group::{GroupMode, KnobGroup};
+use crate::knobs::value_drag::KnobValueDrag;
+use crate::knobs::value_entry::value_entry_popup;
+use crate::localization::localize;
+use crate::{xt_colors, XtText, XtWidgetState};
 
 // ----------------------------------------------------------------------------
 
@@ -20,117 +36,583 @@ fn set(get_set_value: &mut GetSetValue<'_>, value: f32) {
     (get_set_value)(Some(value));
 }
 
+type Easing<'a> = Box<dyn 'a + Fn(f32) -> f32>;
+
+type ValueFormatter<'a> = Box<dyn 'a + Fn(f32) -> String>;
+
+type DetentCallback<'a> = Box<dyn 'a + FnMut(i32)>;
+
+type ReleaseCallback<'a> = Box<dyn 'a + FnMut(f32)>;
+
+type FillGradient<'a> = Box<dyn 'a + Fn(f32) -> Color32>;
+
+// ----------------------------------------------------------------------------
+
+/// Mapping between the position of the knob and its value.
+#[derive(Clone, Copy, Debug, Display, PartialEq)]
+pub enum Taper {
+    #[strum(to_string = "Linear")]
+    Linear,
+
+    /// Slow start and fast end, like the volume controls of audio equipment.
+    #[strum(to_string = "Logarithmic")]
+    Logarithmic { base: f32 },
+
+    #[strum(to_string = "Exponential")]
+    Exponential { exponent: f32 },
+}
+
+impl Taper {
+    /// Maps the normalized position of the knob to the normalized value.
+    #[must_use]
+    pub fn position_to_value(&self, position: f32) -> f32 {
+        match *self {
+            Self::Linear => position,
+            Self::Logarithmic { base } => {
+                assert!(base > 1.0, "logarithmic tapers need a base above one");
+                (base.powf(position) - 1.0) / (base - 1.0)
+            }
+            Self::Exponential { exponent } => {
+                assert!(
+                    exponent > 0.0,
+                    "exponential tapers need a positive exponent"
+                );
+                position.powf(exponent)
+            }
+        }
+    }
+
+    /// Maps the normalized value to the normalized position of the knob,
+    /// the inverse of [`Taper::position_to_value`].
+    #[must_use]
+    pub fn value_to_position(&self, value: f32) -> f32 {
+        match *self {
+            Self::Linear => value,
+            Self::Logarithmic { base } => {
+                assert!(base > 1.0, "logarithmic tapers need a base above one");
+                (value * (base - 1.0)).ln_1p() / base.ln()
+            }
+            Self::Exponential { exponent } => {
+                assert!(
+                    exponent > 0.0,
+                    "exponential tapers need a positive exponent"
+                );
+                value.powf(exponent.recip())
+            }
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// Direction of pointer movement that changes the value of the knob.
+#[derive(Clone, Copy, Debug, Display, EnumIter, Eq, PartialEq)]
+pub enum DragAxis {
+    /// Dragging up increases the value.
+    #[strum(to_string = "Vertical")]
+    Vertical,
+
+    /// Dragging right increases the value, left when mirrored.
+    #[strum(to_string = "Horizontal")]
+    Horizontal,
+
+    /// Sum of the vertical and the horizontal movement.
+    #[strum(to_string = "Both")]
+    Both,
+}
+
+impl DragAxis {
+    fn delta(self, drag_delta: Vec2) -> f32 {
+        match self {
+            DragAxis::Vertical => -drag_delta.y,
+            DragAxis::Horizontal => drag_delta.x,
+            DragAxis::Both => drag_delta.x - drag_delta.y,
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+#[derive(Clone, Copy, Debug, Display, EnumIter, Eq, PartialEq)]
+pub enum KnobIndicator {
+    /// Arc filled up to the value.
+    #[strum(to_string = "Arc")]
+    Arc,
+
+    /// Line pointing at the value, like on hardware knobs.
+    #[strum(to_string = "Line")]
+    Line,
+
+    #[strum(to_string = "Arc and line")]
+    ArcAndLine,
+}
+
 // ----------------------------------------------------------------------------
 
 #[must_use = "You should put this widget in an ui with `ui.add(widget);`"]
 pub struct AudioKnob<'a> {
     get_set_value: GetSetValue<'a>,
     interactive: bool,
-    diameter: f32,
+    label: Option<WidgetText>,
+    editable: bool,
+    indeterminate: bool,
+    diameter: Option<f32>,
+    drag_axis: DragAxis,
     drag_length: f32,
+    lock_cursor: bool,
+    allow_value_drag: bool,
+    accept_value_drop: bool,
     winding: Winding,
+    mirrored: bool,
     orientation: Orientation,
     range: RangeInclusive<f32>,
+    integral: bool,
+    taper: Taper,
     spread: f32,
+    start_angle: Option<f32>,
     thickness: f32,
+    fill_colors: Option<(Color32, Color32)>,
+    fill_gradient: Option<FillGradient<'a>>,
+    indicator: KnobIndicator,
+    indicator_color: Option<Color32>,
     shape: WidgetShape,
+    glow: bool,
+    markers: Vec<f32>,
+    ticks: usize,
+    tick_length: Option<f32>,
+    tick_color: Option<Color32>,
+    reference_values: Vec<f32>,
+    measured_value: Option<f32>,
+    measured_color: Option<Color32>,
     animated: bool,
+    animation_time: Option<f32>,
+    easing: Option<Easing<'a>>,
     snap: Option<f32>,
     shift_snap: Option<f32>,
+    center_detent: Option<f32>,
+    show_tooltip: bool,
+    display_range: Option<RangeInclusive<f32>>,
+    value_formatter: Option<ValueFormatter<'a>>,
+    on_detent: Option<DetentCallback<'a>>,
+    spring_to: Option<f32>,
+    spring_speed: f32,
+    on_release: Option<ReleaseCallback<'a>>,
+    group: Option<(Id, GroupMode)>,
+    long_press_duration: Option<f32>,
+    recording: Option<&'a mut Vec<(f64, f32)>>,
+    reset_state: bool,
 }
 
 impl<'a> AudioKnob<'a> {
-    pub fn new(value: &'a mut f32) -> Self {
-        Self::from_get_set(move |v: Option<f32>| {
+    /// Spread of the common 180° knob layout.
+    pub const SPREAD_180: f32 = 0.5;
+
+    /// Spread of the common 270° knob layout.
+    pub const SPREAD_270: f32 = 0.75;
+
+    /// Spread of the common 300° knob layout, e.g. most guitar amplifiers.
+    pub const SPREAD_300: f32 = 300.0 / 360.0;
+
+    /// Integer values are stepped, one step per integer, and rounded on write-back.
+    pub fn new<Num: Numeric>(value: &'a mut Num) -> Self {
+        let mut knob = Self::from_get_set(move |v: Option<f32>| {
             if let Some(v) = v {
-                *value = v;
+                let v = if Num::INTEGRAL { v.round() } else { v };
+                *value = Num::from_f64(v as f64);
             }
-            *value
-        })
+            value.to_f64() as f32
+        });
+
+        if Num::INTEGRAL {
+            knob.integral = true;
+            knob.snap = Some(1.0);
+        }
+
+        knob
     }
 
     pub fn from_get_set(get_set_value: impl 'a + FnMut(Option<f32>) -> f32) -> Self {
         Self {
             get_set_value: Box::new(get_set_value),
             interactive: true,
-            diameter: 32.0,
-            drag_length: 1.0,
+            label: None,
+            editable: false,
+            indeterminate: false,
+            diameter: None,
+            drag_axis: DragAxis::Both,
+            drag_length: 200.0,
+            lock_cursor: false,
+            allow_value_drag: false,
+            accept_value_drop: false,
             orientation: Orientation::Top,
             winding: Winding::Clockwise,
+            mirrored: false,
             range: 0.0..=1.0,
+            integral: false,
+            taper: Taper::Linear,
             spread: 1.0,
+            start_angle: None,
             thickness: 0.66,
+            fill_colors: None,
+            fill_gradient: None,
+            indicator: KnobIndicator::Arc,
+            indicator_color: None,
             shape: WidgetShape::Squircle(4.0),
+            glow: false,
+            markers: Vec::new(),
+            ticks: 0,
+            tick_length: None,
+            tick_color: None,
+            reference_values: Vec::new(),
+            measured_value: None,
+            measured_color: None,
             animated: true,
+            animation_time: None,
+            easing: None,
             snap: None,
             shift_snap: None,
+            center_detent: None,
+            show_tooltip: false,
+            display_range: None,
+            value_formatter: None,
+            on_detent: None,
+            spring_to: None,
+            spring_speed: 4.0,
+            on_release: None,
+            group: None,
+            long_press_duration: Some(0.6),
+            recording: None,
+            reset_state: false,
         }
     }
 
+    /// Read-only when `false`: only hovering is sensed and the widget is painted with
+    /// the noninteractive visuals. Defaults to `true`.
     pub fn interactive(mut self, interactive: bool) -> Self {
         self.interactive = interactive;
         self
     }
 
+    /// Name of the widget announced by screen readers, e.g. "Heading".
+    pub fn label(mut self, label: impl Into<WidgetText>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// Lets the user type an exact value into a popup opened by right-click or double-click.
+    pub fn editable(mut self, editable: bool) -> Self {
+        self.editable = editable;
+        self
+    }
+
+    /// Shows the value as unknown, e.g. for a selection of parameters with differing
+    /// values. The knob is dimmed without an indicator and the value isn't read.
+    /// Dragging or entering a value resolves it and marks the response as changed,
+    /// the caller is expected to clear the indeterminate state then.
+    pub fn indeterminate(mut self, indeterminate: bool) -> Self {
+        self.indeterminate = indeterminate;
+        self
+    }
+
+    /// Defaults to 1.6 times the height of `ui.spacing().interact_size`.
     pub fn diameter(mut self, diameter: impl Into<f32>) -> Self {
-        self.diameter = diameter.into();
+        self.diameter = Some(diameter.into());
         self
     }
 
+    pub fn drag_axis(mut self, drag_axis: DragAxis) -> Self {
+        self.drag_axis = drag_axis;
+        self
+    }
+
+    /// Distance in points to drag for sweeping the full range, independent of the spread.
+    /// Holding Ctrl multiplies it by ten for fine adjustments.
     pub fn drag_length(mut self, drag_length: impl Into<f32>) -> Self {
         self.drag_length = drag_length.into();
         self
     }
 
+    /// Hides the pointer while dragging, so drags feel unbounded on backends
+    /// that keep reporting motion past the window edge.
+    pub fn lock_cursor(mut self, lock_cursor: bool) -> Self {
+        self.lock_cursor = lock_cursor;
+        self
+    }
+
+    /// Alt-dragging the knob carries its value over to knobs accepting drops,
+    /// instead of changing the value.
+    pub fn allow_value_drag(mut self, allow_value_drag: bool) -> Self {
+        self.allow_value_drag = allow_value_drag;
+        self
+    }
+
+    /// Accepts values alt-dragged from other knobs, mapped proportionally from the
+    /// displayed range of the source knob into the displayed range of this one.
+    pub fn accept_value_drop(mut self, accept_value_drop: bool) -> Self {
+        self.accept_value_drop = accept_value_drop;
+        self
+    }
+
     pub fn winding(mut self, winding: Winding) -> Self {
         self.winding = winding;
         self
     }
 
+    /// Mirrors the widget horizontally, e.g. for right-to-left layouts.
+    pub fn mirrored(mut self, mirrored: bool) -> Self {
+        self.mirrored = mirrored;
+        self
+    }
+
     pub fn orientation(mut self, orientation: Orientation) -> Self {
         self.orientation = orientation;
         self
     }
 
-    pub fn range(mut self, range: RangeInclusive<f32>) -> Self {
-        self.range = range;
+    pub fn range<Num: Numeric>(mut self, range: RangeInclusive<Num>) -> Self {
+        self.range = range.start().to_f64() as f32..=range.end().to_f64() as f32;
         self
     }
 
+    /// Mapping between the position of the knob and the value within `range`.
+    pub fn taper(mut self, taper: Taper) -> Self {
+        self.taper = taper;
+        self
+    }
+
+    /// Fraction of the full turn swept by the arc. Overridden by a later `arc_degrees`.
     pub fn spread(mut self, spread: impl Into<f32>) -> Self {
         self.spread = spread.into();
         self
     }
 
+    /// Angle swept by the arc, e.g. `270.0` for a 270° knob. Alternative to `spread`,
+    /// whichever is called last wins.
+    pub fn arc_degrees(mut self, arc_degrees: f32) -> Self {
+        self.spread = arc_degrees / 360.0;
+        self
+    }
+
+    /// Angle of the minimum position in radians, measured from the orientation in the
+    /// winding direction. By default the arc is centered on the orientation.
+    pub fn start_angle(mut self, start_angle: Option<f32>) -> Self {
+        self.start_angle = start_angle;
+        self
+    }
+
     pub fn thickness(mut self, thickness: impl Into<f32>) -> Self {
         self.thickness = thickness.into();
         self
     }
 
+    /// Paints the arc between zero and the value in `positive` or `negative`
+    /// depending on the sign of the value, e.g. to tell cuts from boosts.
+    pub fn fill_colors(mut self, positive: Color32, negative: Color32) -> Self {
+        self.fill_colors = Some((positive, negative));
+        self
+    }
+
+    /// Paints the arc between zero and the value with the color returned for each
+    /// normalized position along the arc, like a level meter. Takes precedence over
+    /// `fill_colors`.
+    pub fn fill_gradient(mut self, fill_gradient: impl 'a + Fn(f32) -> Color32) -> Self {
+        self.fill_gradient = Some(Box::new(fill_gradient));
+        self
+    }
+
+    pub fn indicator(mut self, indicator: KnobIndicator) -> Self {
+        self.indicator = indicator;
+        self
+    }
+
+    /// Color of the indicator line, defaults to the foreground stroke of the widget.
+    pub fn indicator_color(mut self, indicator_color: Color32) -> Self {
+        self.indicator_color = Some(indicator_color);
+        self
+    }
+
     pub fn shape(mut self, shape: WidgetShape) -> Self {
         self.shape = shape;
         self
     }
 
+    /// Surrounds the knob with a soft halo while it is being dragged.
+    pub fn glow(mut self, glow: bool) -> Self {
+        self.glow = glow;
+        self
+    }
+
+    /// Paints a tick outside the track at `value`, e.g. at unity gain.
+    /// Can be called multiple times, values outside of the range are ignored.
+    pub fn marker_at(mut self, value: f32) -> Self {
+        self.markers.push(value);
+        self
+    }
+
+    /// Paints `ticks` evenly spaced ticks outside the track, from one end of the arc
+    /// to the other, e.g. to show detent positions. The spacing follows the arc rather
+    /// than the taper.
+    pub fn ticks(mut self, ticks: usize) -> Self {
+        self.ticks = ticks;
+        self
+    }
+
+    /// Defaults to a sixteenth of the diameter.
+    pub fn tick_length(mut self, tick_length: f32) -> Self {
+        self.tick_length = Some(tick_length);
+        self
+    }
+
+    pub fn tick_color(mut self, tick_color: Color32) -> Self {
+        self.tick_color = Some(tick_color);
+        self
+    }
+
+    /// Paints a faint ghost of the indicator at `reference_value`, e.g. at the value
+    /// stored in a preset. Doesn't affect interaction.
+    pub fn reference_value(mut self, reference_value: Option<f32>) -> Self {
+        self.reference_values = reference_value.into_iter().collect();
+        self
+    }
+
+    /// Like [`Self::reference_value`], but with a ghost for every value,
+    /// e.g. for the bounds of an automation envelope.
+    pub fn reference_values(mut self, reference_values: &[f32]) -> Self {
+        self.reference_values = reference_values.to_vec();
+        self
+    }
+
+    /// Paints a read-only needle at `measured_value` alongside the knob's own value,
+    /// e.g. the room temperature next to the setpoint of a thermostat, with the arc
+    /// between the two shaded. The needle always eases towards new values.
+    pub fn measured_value(mut self, measured_value: Option<f32>) -> Self {
+        self.measured_value = measured_value;
+        self
+    }
+
+    /// Color of the measured value needle and arc, defaults to the info color.
+    pub fn measured_color(mut self, measured_color: Color32) -> Self {
+        self.measured_color = Some(measured_color);
+        self
+    }
+
     pub fn animated(mut self, animated: bool) -> Self {
         self.animated = animated;
         self
     }
 
-    pub fn snap(mut self, snap: Option<f32>) -> Self {
-        self.snap = snap;
+    /// Duration of the animations in seconds, defaults to the animation time of the style.
+    pub fn animation_time(mut self, animation_time: impl Into<f32>) -> Self {
+        self.animation_time = Some(animation_time.into());
+        self
+    }
+
+    /// Shapes the animations, mapping their linear progress to the eased progress,
+    /// both within `0.0..=1.0`.
+    pub fn easing(mut self, easing: impl 'a + Fn(f32) -> f32) -> Self {
+        self.easing = Some(Box::new(easing));
+        self
+    }
+
+    /// Snaps the value when dragging. No snapping by default.
+    pub fn snap(mut self, snap: impl Into<Option<f32>>) -> Self {
+        self.snap = snap.into();
+        self
+    }
+
+    /// Snaps the value while Shift is held. Off by default, as the value has no
+    /// natural step; pass `None` to keep it off after setting it.
+    pub fn shift_snap(mut self, shift_snap: impl Into<Option<f32>>) -> Self {
+        self.shift_snap = shift_snap.into();
+        self
+    }
+
+    /// Snaps the value to the middle of the range while dragging within `center_detent`
+    /// of it, like the pan knobs of mixing consoles.
+    pub fn center_detent(mut self, center_detent: Option<f32>) -> Self {
+        self.center_detent = center_detent;
+        self
+    }
+
+    /// Shows the value in a tooltip next to the knob while hovering or dragging it.
+    pub fn show_tooltip(mut self, show_tooltip: bool) -> Self {
+        self.show_tooltip = show_tooltip;
+        self
+    }
+
+    /// Remaps the value shown in the tooltip and in the value entry popup from `range`
+    /// into `display_range`, e.g. `0.0..=100.0` for percentages.
+    pub fn display_range(mut self, display_range: Option<RangeInclusive<f32>>) -> Self {
+        self.display_range = display_range;
+        self
+    }
+
+    /// Formats the value shown in the tooltip (remapped by `display_range`), e.g. as decibels.
+    /// Defaults to the value with two decimals and its percentage through the range.
+    pub fn value_formatter(mut self, value_formatter: impl 'a + Fn(f32) -> String) -> Self {
+        self.value_formatter = Some(Box::new(value_formatter));
+        self
+    }
+
+    /// Called with the number of snap detents crossed while dragging,
+    /// negative when the value decreases.
+    pub fn on_detent(mut self, on_detent: impl 'a + FnMut(i32)) -> Self {
+        self.on_detent = Some(Box::new(on_detent));
+        self
+    }
+
+    /// Makes the knob spring-loaded: once released, the value returns to `spring_to`.
+    pub fn spring_to(mut self, spring_to: Option<f32>) -> Self {
+        self.spring_to = spring_to;
+        self
+    }
+
+    /// Units of the value per second the value returns to the spring target with.
+    pub fn spring_speed(mut self, spring_speed: impl Into<f32>) -> Self {
+        self.spring_speed = spring_speed.into();
+        self
+    }
+
+    /// Called when a spring-loaded knob is released,
+    /// with the peak deflection from the spring target during the drag.
+    pub fn on_release(mut self, on_release: impl 'a + FnMut(f32)) -> Self {
+        self.on_release = Some(Box::new(on_release));
+        self
+    }
+
+    /// Gang this knob with every other knob using the same `group_id`.
+    pub fn group(mut self, group_id: impl Hash, mode: GroupMode) -> Self {
+        self.group = Some((Id::new(group_id), mode));
+        self
+    }
+
+    /// Seconds the pointer has to be held down to act as a right-click on touch screens,
+    /// `None` disables long-presses.
+    pub fn long_press_duration(mut self, long_press_duration: Option<f32>) -> Self {
+        self.long_press_duration = long_press_duration;
+        self
+    }
+
+    /// Appends a `(time, value)` sample to `recording` whenever the user changes the value.
+    /// Changes made within the same frame are coalesced into one sample,
+    /// thinning or compressing the recording is left to the caller.
+    pub fn record_into(mut self, recording: &'a mut Vec<(f64, f32)>) -> Self {
+        self.recording = Some(recording);
         self
     }
 
-    pub fn shift_snap(mut self, shift_snap: Option<f32>) -> Self {
-        self.shift_snap = shift_snap;
+    /// Forgets the transient state of the widget, see [`XtWidgetState`].
+    pub fn reset_state(mut self) -> Self {
+        self.reset_state = true;
         self
     }
 }
 
 impl<'a> Widget for AudioKnob<'a> {
     fn ui(mut self, ui: &mut Ui) -> Response {
-        let desired_size = Vec2::splat(self.diameter);
+        let diameter = self.diameter.unwrap_or(ui.spacing().interact_size.y * 1.6);
+
+        let desired_size = Vec2::splat(diameter);
 
         let (rect, mut response) = ui.allocate_exact_size(
             desired_size,
@@ -143,33 +625,190 @@ impl<'a> Widget for AudioKnob<'a> {
 
         let constrain_value = |value: f32| value.clamp(*self.range.start(), *self.range.end());
 
-        if response.dragged() {
-            let drag_delta = self.orientation.rot2().inverse() * response.drag_delta();
+        let value_to_position = |value: f32| {
+            self.taper
+                .value_to_position(remap_clamp(value, self.range.clone(), 0.0..=1.0))
+        };
+
+        let position_to_value = |position: f32| {
+            remap(
+                self.taper.position_to_value(position.clamp(0.0, 1.0)),
+                0.0..=1.0,
+                self.range.clone(),
+            )
+        };
+
+        let to_display = |value: f32| match &self.display_range {
+            Some(display_range) => remap(value, self.range.clone(), display_range.clone()),
+            None => value,
+        };
+
+        let from_display = |value: f32| match &self.display_range {
+            Some(display_range) => remap(value, display_range.clone(), self.range.clone()),
+            None => value,
+        };
+
+        if self.indeterminate {
+            let placeholder = position_to_value(0.5);
+            let get_set_value = std::mem::replace(&mut self.get_set_value, Box::new(|_| 0.0));
+            self.get_set_value = indeterminate_get_set(get_set_value, placeholder);
+        }
+
+        if self.reset_state {
+            XtWidgetState::reset(ui.ctx(), response.id);
+        }
+
+        if let (true, Some(long_press_duration)) = (self.interactive, self.long_press_duration) {
+            long_press_as_secondary_click(ui, &mut response, long_press_duration);
+        }
+
+        if let Some((group_id, group_mode)) = self.group {
+            let value = get(&mut self.get_set_value);
+            if let Some(new_value) =
+                KnobGroup::follow(ui.ctx(), group_id, response.id, group_mode, value)
+            {
+                set(&mut self.get_set_value, constrain_value(new_value));
+                response.mark_changed();
+            }
+        }
+
+        let value_before_interaction = get(&mut self.get_set_value);
+
+        let display_range = self
+            .display_range
+            .clone()
+            .unwrap_or_else(|| self.range.clone());
+
+        if self.interactive
+            && self.allow_value_drag
+            && response.drag_started()
+            && ui.input(|input| input.modifiers.alt)
+        {
+            KnobValueDrag::start(
+                ui.ctx(),
+                response.id,
+                to_display(value_before_interaction),
+                display_range.clone(),
+            );
+        }
 
-            let mut new_value = get(&mut self.get_set_value);
+        let value_drag = KnobValueDrag::current(ui.ctx());
 
-            let delta = drag_delta.x + drag_delta.y * self.winding.to_float();
-            new_value += delta * (self.range.end() - self.range.start()) / (self.diameter * self.drag_length);
+        let dragging_value = value_drag
+            .as_ref()
+            .is_some_and(|value_drag| value_drag.is_source(response.id));
 
-            set(&mut self.get_set_value, constrain_value(new_value));
+        // Knobs accepting the drop take the value in the frame the pointer is released,
+        // the source forgets it in the frame after.
+        if dragging_value && !response.dragged() && !response.drag_released() {
+            KnobValueDrag::clear(ui.ctx());
+        }
+
+        let mut drop_hovered = false;
+
+        if let (true, true, Some(value_drag)) = (
+            self.interactive,
+            self.accept_value_drop,
+            value_drag.filter(|value_drag| !value_drag.is_source(response.id)),
+        ) {
+            drop_hovered = ui.rect_contains_pointer(rect);
+
+            if drop_hovered && ui.input(|input| input.pointer.any_released()) {
+                let new_value = from_display(value_drag.value_in(display_range));
+                set(&mut self.get_set_value, constrain_value(new_value));
+                response.mark_changed();
+
+                KnobValueDrag::clear(ui.ctx());
+            }
+        }
+
+        if response.drag_started() {
+            XtWidgetState::update(ui.ctx(), response.id, |state| {
+                state.drag_accumulator = None;
+            });
+        }
+
+        // A press without movement already counts as a drag, it mustn't touch the value
+        // (nor resolve an indeterminate knob) when it only opens the value entry popup.
+        if response.dragged() && response.drag_delta() != Vec2::ZERO && !dragging_value {
+            if self.lock_cursor {
+                ui.ctx().set_cursor_icon(CursorIcon::None);
+            }
+
+            let screen_drag_delta = if self.mirrored {
+                response.drag_delta() * vec2(-1.0, 1.0)
+            } else {
+                response.drag_delta()
+            };
+
+            let prev_value = get(&mut self.get_set_value);
+
+            // Accumulate the value outside of the center detent (or between integers)
+            // in the widget state, otherwise small drag deltas would be captured by the
+            // detent (or rounded away) on every frame.
+            let accumulate = self.center_detent.is_some() || self.integral;
+            let mut state = XtWidgetState::load(ui.ctx(), response.id);
+
+            let accumulated_value = if accumulate {
+                state.drag_accumulator.unwrap_or(prev_value)
+            } else {
+                prev_value
+            };
+
+            let drag_length = if ui.input(|input| input.modifiers.ctrl) {
+                self.drag_length * 10.0
+            } else {
+                self.drag_length
+            };
+
+            // Drag along the position of the knob, the taper only applies to the value
+            let delta = self.drag_axis.delta(screen_drag_delta);
+            let new_position = value_to_position(accumulated_value) + delta / drag_length;
+            let mut new_value = constrain_value(position_to_value(new_position));
+
+            if accumulate {
+                state.drag_accumulator = Some(new_value);
+                state.store(ui.ctx(), response.id);
+            }
+
+            if let Some(center_detent) = self.center_detent {
+                let center = (self.range.start() + self.range.end()) / 2.0;
+                if (new_value - center).abs() <= center_detent {
+                    new_value = center;
+                }
+            }
+
+            let snap = if ui.input(|input| input.modifiers.shift_only()) {
+                self.shift_snap
+            } else {
+                self.snap
+            };
+
+            if let (Some(on_detent), Some(snap)) = (&mut self.on_detent, snap) {
+                let crossings = detent_crossings(prev_value, new_value, snap);
+                if crossings != 0 {
+                    on_detent(crossings);
+                }
+            }
+
+            set(&mut self.get_set_value, new_value);
             response.mark_changed();
         }
 
         if response.drag_released() {
             if self.animated {
-                ui.ctx().clear_animations();
-                ui.ctx().animate_value_with_time(
-                    response.id,
-                    get(&mut self.get_set_value),
-                    ui.style().animation_time,
-                );
+                // The value was shown without animation while dragging
+                settle_animation(ui.ctx(), response.id, get(&mut self.get_set_value));
             }
 
-            if let Some(snap_angle) = if ui.input(|input| input.modifiers.shift_only()) {
+            let snap = if ui.input(|input| input.modifiers.shift_only()) {
                 self.shift_snap
             } else {
                 self.snap
-            } {
+            };
+
+            // Snapping the placeholder would resolve a knob that was only pressed
+            if let (Some(snap_angle), false) = (snap, self.indeterminate && !response.changed()) {
                 assert!(
                     snap_angle > 0.0,
                     "non-positive snap angles are not supported"
@@ -180,50 +819,421 @@ impl<'a> Widget for AudioKnob<'a> {
             }
         }
 
+        // Springing back would resolve the value without the user picking one
+        if let (Some(spring_target), false) =
+            (self.spring_to.map(constrain_value), self.indeterminate)
+        {
+            let deflection = get(&mut self.get_set_value) - spring_target;
+
+            if response.dragged() {
+                XtWidgetState::update(ui.ctx(), response.id, |state| {
+                    state.spring_peak = Some(peak_deflection(state.spring_peak, deflection));
+                });
+            } else if deflection != 0.0 {
+                let new_value = spring_target + spring_back(ui, deflection, self.spring_speed);
+                set(&mut self.get_set_value, constrain_value(new_value));
+                response.mark_changed();
+            }
+
+            if response.drag_released() {
+                let spring_peak =
+                    XtWidgetState::update(ui.ctx(), response.id, |state| state.spring_peak.take());
+
+                if let (Some(on_release), Some(spring_peak)) = (&mut self.on_release, spring_peak) {
+                    on_release(spring_peak);
+                }
+            }
+        }
+
+        if self.interactive && self.editable {
+            let value = get(&mut self.get_set_value);
+
+            let entered_value = value_entry_popup(
+                ui,
+                &response,
+                || {
+                    if self.indeterminate {
+                        String::new()
+                    } else {
+                        to_display(value).to_string()
+                    }
+                },
+                |text| {
+                    text.trim().parse::<f32>().ok().map(|entered_value| {
+                        // Confirming the untouched text keeps the value as is,
+                        // instead of drifting through the remapping round trip.
+                        if entered_value == to_display(value) {
+                            value
+                        } else {
+                            from_display(entered_value)
+                        }
+                    })
+                },
+            );
+
+            if let Some(entered_value) = entered_value {
+                set(&mut self.get_set_value, constrain_value(entered_value));
+                response.mark_changed();
+            }
+        }
+
+        let value = get(&mut self.get_set_value);
+        if value != value_before_interaction {
+            if let Some((group_id, _)) = self.group {
+                KnobGroup::publish(
+                    ui.ctx(),
+                    group_id,
+                    response.id,
+                    value,
+                    value - value_before_interaction,
+                );
+            }
+
+            if let Some(recording) = &mut self.recording {
+                record_sample(recording, ui.input(|input| input.time), value);
+            }
+        }
+
+        // Shown resolved as soon as the user picks a value
+        let indeterminate = self.indeterminate && !response.changed();
+
+        let format_value = |value: f32| match &self.value_formatter {
+            _ if indeterminate => localize(ui.ctx(), XtText::Indeterminate),
+            Some(value_formatter) => value_formatter(to_display(value)),
+            None => localize(
+                ui.ctx(),
+                XtText::ValueWithPercentage {
+                    value: to_display(value),
+                    percentage: remap_clamp(value, self.range.clone(), 0.0..=100.0),
+                },
+            ),
+        };
+
+        if dragging_value && response.dragged() {
+            let value_text = format_value(get(&mut self.get_set_value));
+
+            show_tooltip_at_pointer(ui.ctx(), response.id.with("value_drag"), |ui| {
+                ui.label(value_text)
+            });
+        } else if self.show_tooltip && (response.hovered() || response.dragged()) {
+            let tooltip_text = format_value(get(&mut self.get_set_value));
+
+            show_tooltip_for(ui.ctx(), response.id.with("value_tooltip"), &rect, |ui| {
+                ui.label(tooltip_text)
+            });
+        }
+
+        {
+            let value = get(&mut self.get_set_value);
+            slider_widget_info(
+                ui,
+                &response,
+                self.label.as_ref(),
+                value as f64,
+                Some(*self.range.start() as f64),
+                Some(*self.range.end() as f64),
+                self.snap.map(|snap| snap as f64),
+            );
+        }
+
         if ui.is_rect_visible(rect) {
             let visuals = *ui.style().interact(&response);
 
             let value = if self.animated && !response.dragged() {
-                ui.ctx()
-                    .animate_value_with_time(response.id, get(&mut self.get_set_value), 0.1)
+                animate_value(
+                    ui.ctx(),
+                    response.id,
+                    get(&mut self.get_set_value),
+                    self.animation_time.unwrap_or(ui.style().animation_time),
+                    self.easing.as_deref(),
+                )
             } else {
                 get(&mut self.get_set_value)
             };
 
+            let arc_winding = self.winding.mirrored(self.mirrored);
+
             let center_angle = (self.orientation.rot2() * Vec2::RIGHT).angle();
-            let spread_angle = (TAU / 2.0) * self.spread.clamp(0.0, 1.0);
+            let spread_angle = TAU * self.spread.clamp(0.0, 1.0);
+            let start_angle = self.start_angle.unwrap_or(-spread_angle / 2.0);
 
             let (min_angle, max_angle) = (
-                center_angle - spread_angle * self.winding.to_float(),
-                center_angle + spread_angle * self.winding.to_float(),
+                center_angle + start_angle * arc_winding.to_float(),
+                center_angle + (start_angle + spread_angle) * arc_winding.to_float(),
             );
 
-            let outer_radius = self.diameter / 2.0;
+            let outer_radius = diameter / 2.0;
             let inner_radius = outer_radius * (1.0 - self.thickness.clamp(0.0, 1.0));
 
-            self.shape.paint_arc(
-                ui,
-                rect.center(),
-                inner_radius,
-                outer_radius,
-                min_angle,
-                max_angle,
-                ui.style().visuals.faint_bg_color,
-                ui.style().visuals.window_stroke(),
-                self.orientation.rot2(),
-            );
+            if self.glow && response.dragged() {
+                paint_glow(ui, rect.center(), outer_radius, 4.0, xt_colors(ui).info);
+            }
 
-            self.shape.paint_arc(
-                ui,
-                rect.center(),
-                (inner_radius - visuals.expansion).max(0.0),
-                outer_radius + visuals.expansion,
-                remap_clamp(0.0, self.range.clone(), min_angle..=max_angle),
-                remap_clamp(value, self.range, min_angle..=max_angle),
-                visuals.bg_fill,
-                visuals.fg_stroke,
-                self.orientation.rot2(),
-            );
+            if drop_hovered {
+                ui.painter().circle_stroke(
+                    rect.center(),
+                    outer_radius + visuals.expansion,
+                    ui.style().visuals.selection.stroke, // TODO: Semantically correct color
+                );
+            }
+
+            let shape_radius_at = |angle: f32| {
+                outer_radius
+                    * self
+                        .shape
+                        .eval(angle - (self.orientation.rot2() * Vec2::RIGHT).angle())
+            };
+
+            if indeterminate {
+                self.shape.paint_arc(
+                    ui,
+                    rect.center(),
+                    inner_radius,
+                    outer_radius,
+                    min_angle,
+                    max_angle,
+                    ui.style().visuals.faint_bg_color.linear_multiply(0.5), // TODO: Semantically correct color
+                    Stroke::NONE,
+                    self.orientation.rot2(),
+                );
+
+                let outline_angle = |point: usize| lerp(min_angle..=max_angle, point as f32 / 64.0);
+                let inner_ratio = inner_radius / outer_radius;
+
+                // Along the outer edge, back along the inner edge, then closed
+                let mut outline_points = (0..=64)
+                    .map(|point| {
+                        let angle = outline_angle(point);
+                        rect.center() + Vec2::angled(angle) * shape_radius_at(angle)
+                    })
+                    .chain((0..=64).rev().map(|point| {
+                        let angle = outline_angle(point);
+                        rect.center() + Vec2::angled(angle) * (shape_radius_at(angle) * inner_ratio)
+                    }))
+                    .collect::<Vec<_>>();
+                outline_points.push(outline_points[0]);
+
+                ui.painter().add(Shape::dashed_line(
+                    &outline_points,
+                    ui.style().visuals.window_stroke(),
+                    2.0,
+                    2.0,
+                ));
+            } else {
+                self.shape.paint_arc(
+                    ui,
+                    rect.center(),
+                    inner_radius,
+                    outer_radius,
+                    min_angle,
+                    max_angle,
+                    ui.style().visuals.faint_bg_color,
+                    ui.style().visuals.window_stroke(),
+                    self.orientation.rot2(),
+                );
+            }
+
+            for &marker in self
+                .markers
+                .iter()
+                .filter(|marker| self.range.contains(marker))
+            {
+                let marker_angle =
+                    remap_clamp(value_to_position(marker), 0.0..=1.0, min_angle..=max_angle);
+                let marker_radius = shape_radius_at(marker_angle);
+
+                ui.painter().line_segment(
+                    [
+                        rect.center() + Vec2::angled(marker_angle) * marker_radius,
+                        rect.center()
+                            + Vec2::angled(marker_angle) * (marker_radius + diameter / 10.0),
+                    ],
+                    ui.style().visuals.noninteractive().fg_stroke, // TODO: Semantically correct color
+                );
+            }
+
+            {
+                let tick_length = self.tick_length.unwrap_or(diameter / 16.0);
+                let tick_stroke = Stroke::new(
+                    ui.style().visuals.noninteractive().fg_stroke.width,
+                    self.tick_color
+                        .unwrap_or(ui.style().visuals.weak_text_color()),
+                );
+
+                for tick in 0..self.ticks {
+                    // A single tick sits in the middle of the arc
+                    let tick_position = if self.ticks > 1 {
+                        tick as f32 / (self.ticks - 1) as f32
+                    } else {
+                        0.5
+                    };
+
+                    let tick_angle = lerp(min_angle..=max_angle, tick_position);
+                    let tick_radius = shape_radius_at(tick_angle);
+
+                    ui.painter().line_segment(
+                        [
+                            rect.center() + Vec2::angled(tick_angle) * tick_radius,
+                            rect.center() + Vec2::angled(tick_angle) * (tick_radius + tick_length),
+                        ],
+                        tick_stroke,
+                    );
+                }
+            }
+
+            let value_angle =
+                remap_clamp(value_to_position(value), 0.0..=1.0, min_angle..=max_angle);
+
+            if self.indicator != KnobIndicator::Line && !indeterminate {
+                let highlighted = response.hovered() || response.dragged();
+
+                let tint = |fill_color: Color32| {
+                    if highlighted {
+                        tint_color_towards(fill_color, visuals.fg_stroke.color)
+                    } else {
+                        fill_color
+                    }
+                };
+
+                let (arc_inner_radius, arc_outer_radius) = (
+                    (inner_radius - visuals.expansion).max(0.0),
+                    outer_radius + visuals.expansion,
+                );
+
+                let (zero_position, value_position) =
+                    (value_to_position(0.0), value_to_position(value));
+
+                let fill_color = match (&self.fill_gradient, self.fill_colors) {
+                    (Some(fill_gradient), _) => {
+                        // Paint the gradient in short pieces of solid color, outlined below
+                        let piece_count = ((value_position - zero_position).abs() * 32.0)
+                            .ceil()
+                            .max(1.0);
+
+                        let piece_position =
+                            |t: f32| lerp(zero_position..=value_position, t / piece_count);
+
+                        for piece in 0..piece_count as usize {
+                            let (start, end) = (
+                                piece_position(piece as f32),
+                                piece_position(piece as f32 + 1.0),
+                            );
+
+                            self.shape.paint_arc(
+                                ui,
+                                rect.center(),
+                                arc_inner_radius,
+                                arc_outer_radius,
+                                remap_clamp(start, 0.0..=1.0, min_angle..=max_angle),
+                                remap_clamp(end, 0.0..=1.0, min_angle..=max_angle),
+                                tint(fill_gradient((start + end) / 2.0)),
+                                Stroke::NONE,
+                                self.orientation.rot2(),
+                            );
+                        }
+
+                        Color32::TRANSPARENT
+                    }
+                    (None, Some((positive, negative))) => {
+                        tint(if value >= 0.0 { positive } else { negative })
+                    }
+                    (None, None) => visuals.bg_fill,
+                };
+
+                self.shape.paint_arc(
+                    ui,
+                    rect.center(),
+                    arc_inner_radius,
+                    arc_outer_radius,
+                    remap_clamp(zero_position, 0.0..=1.0, min_angle..=max_angle),
+                    value_angle,
+                    fill_color,
+                    visuals.fg_stroke,
+                    self.orientation.rot2(),
+                );
+            }
+
+            for &reference_value in &self.reference_values {
+                let reference_angle = remap_clamp(
+                    value_to_position(reference_value),
+                    0.0..=1.0,
+                    min_angle..=max_angle,
+                );
+                let shape_radius = shape_radius_at(reference_angle);
+
+                ui.painter().line_segment(
+                    [
+                        rect.center() + Vec2::angled(reference_angle) * (shape_radius * 0.2),
+                        rect.center() + Vec2::angled(reference_angle) * shape_radius,
+                    ],
+                    Stroke::new(
+                        diameter / 16.0,
+                        self.indicator_color
+                            .unwrap_or(visuals.fg_stroke.color)
+                            .linear_multiply(0.3),
+                    ), // TODO: Semantically correct color
+                );
+            }
+
+            if let Some(measured_value) = self.measured_value {
+                let measured_value = animate_value(
+                    ui.ctx(),
+                    response.id.with("measured_value"),
+                    measured_value,
+                    self.animation_time.unwrap_or(ui.style().animation_time),
+                    self.easing.as_deref(),
+                );
+
+                let measured_angle = remap_clamp(
+                    value_to_position(measured_value),
+                    0.0..=1.0,
+                    min_angle..=max_angle,
+                );
+                let measured_color = self.measured_color.unwrap_or(xt_colors(ui).info);
+
+                // The shaded arc shows how far the measured value is from the setpoint
+                if !indeterminate {
+                    self.shape.paint_arc(
+                        ui,
+                        rect.center(),
+                        inner_radius,
+                        outer_radius,
+                        value_angle,
+                        measured_angle,
+                        measured_color.linear_multiply(0.25),
+                        Stroke::NONE,
+                        self.orientation.rot2(),
+                    );
+                }
+
+                let shape_radius = shape_radius_at(measured_angle);
+
+                ui.painter().line_segment(
+                    [
+                        rect.center() + Vec2::angled(measured_angle) * (shape_radius * 0.2),
+                        rect.center() + Vec2::angled(measured_angle) * shape_radius,
+                    ],
+                    Stroke::new(diameter / 24.0, measured_color),
+                );
+            }
+
+            if self.indicator != KnobIndicator::Arc && !indeterminate {
+                let shape_radius = shape_radius_at(value_angle);
+
+                // Scale with the diameter so knobs of every size look alike
+                let indicator_stroke = Stroke::new(
+                    diameter / 16.0,
+                    self.indicator_color.unwrap_or(visuals.fg_stroke.color),
+                );
+
+                ui.painter().line_segment(
+                    [
+                        rect.center() + Vec2::angled(value_angle) * (shape_radius * 0.2),
+                        rect.center() + Vec2::angled(value_angle) * shape_radius,
+                    ],
+                    indicator_stroke,
+                );
+            }
         }
 
         response
@@ -0,0 +1,243 @@
+use std::f32::consts::TAU;
+use std::ops::RangeInclusive;
+
+use egui::{self, Button, Response, Sense, Ui, Widget, WidgetText};
+use emath::{Rect, Vec2};
+
+use crate::common::slider_widget_info;
+use crate::displays::{DisplayKind, DisplayStyle, DisplayStylePreset, SegmentedDisplayWidget};
+use crate::knobs::{AngleKnob, KnobInteraction};
+use crate::localization::localize;
+use crate::XtText;
+
+// ----------------------------------------------------------------------------
+
+/// Active and standby frequencies of a radio stack, in MHz.
+///
+/// The standby frequency is tuned with a dual concentric knob, the outer ring
+/// stepping whole megahertz and the inner ring stepping channels, carrying over
+/// into the megahertz. The swap button exchanges the two frequencies.
+#[must_use = "You should put this widget in an ui with `ui.add(widget);`"]
+pub struct FrequencyEntry<'a> {
+    active: &'a mut f32,
+    standby: &'a mut f32,
+    interactive: bool,
+    label: Option<WidgetText>,
+    range: RangeInclusive<f32>,
+    channel_spacing: f32,
+    display_kind: DisplayKind,
+    digit_height: f32,
+    style: DisplayStyle,
+    knob_diameter: f32,
+    drag_step: f32,
+}
+
+impl<'a> FrequencyEntry<'a> {
+    pub fn new(active: &'a mut f32, standby: &'a mut f32) -> Self {
+        Self {
+            active,
+            standby,
+            interactive: true,
+            label: None,
+            range: 118.0..=136.975,
+            channel_spacing: 0.025,
+            display_kind: DisplayKind::SevenSegment,
+            digit_height: 32.0,
+            style: DisplayStylePreset::Default.style(),
+            knob_diameter: 48.0,
+            drag_step: 8.0,
+        }
+    }
+
+    /// Read-only when `false`: the knob only senses hovering and the frequencies
+    /// can't be swapped. Defaults to `true`.
+    pub fn interactive(mut self, interactive: bool) -> Self {
+        self.interactive = interactive;
+        self
+    }
+
+    /// Name of the widget announced by screen readers, e.g. "COM1".
+    pub fn label(mut self, label: impl Into<WidgetText>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// Tuning range, the standby frequency wraps around at both ends.
+    pub fn range(mut self, range: RangeInclusive<f32>) -> Self {
+        self.range = range;
+        self
+    }
+
+    /// Step of the inner ring in MHz, at least 0.001 (1 kHz).
+    pub fn channel_spacing(mut self, channel_spacing: f32) -> Self {
+        debug_assert!(channel_spacing > 0.0, "channel spacing must be positive");
+        self.channel_spacing = channel_spacing.max(0.001);
+        self
+    }
+
+    pub fn display_kind(mut self, display_kind: DisplayKind) -> Self {
+        self.display_kind = display_kind;
+        self
+    }
+
+    pub fn digit_height(mut self, digit_height: impl Into<f32>) -> Self {
+        self.digit_height = digit_height.into();
+        self
+    }
+
+    pub fn style(mut self, style: DisplayStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    pub fn style_preset(mut self, preset: DisplayStylePreset) -> Self {
+        self.style = preset.style();
+        self
+    }
+
+    pub fn knob_diameter(mut self, knob_diameter: impl Into<f32>) -> Self {
+        self.knob_diameter = knob_diameter.into();
+        self
+    }
+
+    /// Distance in points to drag the knob for a single step, at least one point.
+    pub fn drag_step(mut self, drag_step: impl Into<f32>) -> Self {
+        let drag_step = drag_step.into();
+        debug_assert!(drag_step > 0.0, "drag step must be positive");
+        self.drag_step = drag_step.max(1.0);
+        self
+    }
+}
+
+impl<'a> FrequencyEntry<'a> {
+    fn frequency_display(&self, frequency: f32) -> SegmentedDisplayWidget {
+        SegmentedDisplayWidget::new(self.display_kind)
+            .digit_height(self.digit_height)
+            .style(self.style)
+            .push_string(format!("{frequency:7.3}"))
+    }
+
+    /// Snaps `frequency` to the channel grid and wraps it around the tuning range.
+    fn constrain_frequency(&self, frequency: f32) -> f32 {
+        let (min, max) = (*self.range.start(), *self.range.end());
+        let channel_count = ((max - min) / self.channel_spacing).round() + 1.0;
+
+        let channel = ((frequency - min) / self.channel_spacing).round();
+        min + channel.rem_euclid(channel_count) * self.channel_spacing
+    }
+
+    /// One ring of the dual knob, turning once per `turn_size` MHz with a detent every
+    /// `step_size` MHz. Every detent crossed steps the standby frequency.
+    fn ring_knob<'k>(
+        &self,
+        angle: &'k mut f32,
+        steps: &'k mut i32,
+        diameter: f32,
+        step_size: f32,
+        turn_size: f32,
+    ) -> AngleKnob<'k> {
+        let step_angle = TAU * step_size / turn_size;
+
+        *angle = (*self.standby / turn_size).fract() * TAU;
+
+        AngleKnob::new(angle)
+            .interactive(self.interactive)
+            .interaction(KnobInteraction::RelativeVertical)
+            .drag_sensitivity(step_angle / self.drag_step)
+            .diameter(diameter)
+            .snap(step_angle)
+            .shift_snap(step_angle)
+            .show_axes(false)
+            .show_drag_origin(false)
+            .on_detent(move |crossings| *steps += crossings)
+    }
+
+    fn dual_knob_ui(&mut self, ui: &mut Ui) -> Response {
+        let (rect, _) = ui.allocate_exact_size(Vec2::splat(self.knob_diameter), Sense::hover());
+
+        let inner_diameter = self.knob_diameter * 0.6;
+        let inner_rect = Rect::from_center_size(rect.center(), Vec2::splat(inner_diameter));
+
+        let mut outer_ui = ui.child_ui_with_id_source(rect, *ui.layout(), "outer_ring");
+        let mut inner_ui = ui.child_ui_with_id_source(inner_rect, *ui.layout(), "inner_ring");
+
+        // The outer ring is painted first, but the inner ring lies on top of it.
+        // Claim presses on the inner ring for it before the outer ring can take them.
+        if self.interactive {
+            let inner_hovered = ui.rect_contains_pointer(inner_rect)
+                && ui.ctx().pointer_hover_pos().is_some_and(|pointer_pos| {
+                    pointer_pos.distance(rect.center()) <= inner_diameter / 2.0
+                });
+
+            ui.interact_with_hovered(
+                inner_rect,
+                inner_hovered,
+                inner_ui.next_auto_id(),
+                Sense::click_and_drag(),
+            );
+        }
+
+        // The outer ring turns once per 10 MHz, the inner ring once per MHz
+        let (mut outer_angle, mut outer_steps) = (0.0, 0);
+        let outer_response = outer_ui.add(self.ring_knob(
+            &mut outer_angle,
+            &mut outer_steps,
+            self.knob_diameter,
+            1.0,
+            10.0,
+        ));
+
+        let (mut inner_angle, mut inner_steps) = (0.0, 0);
+        let inner_response = inner_ui.add(self.ring_knob(
+            &mut inner_angle,
+            &mut inner_steps,
+            inner_diameter,
+            self.channel_spacing,
+            1.0,
+        ));
+
+        let steps = outer_steps as f32 + inner_steps as f32 * self.channel_spacing;
+        if steps != 0.0 {
+            *self.standby = self.constrain_frequency(*self.standby + steps);
+        }
+
+        outer_response.union(inner_response)
+    }
+}
+
+impl<'a> Widget for FrequencyEntry<'a> {
+    fn ui(mut self, ui: &mut Ui) -> Response {
+        ui.horizontal(|ui| {
+            let active_response = ui.add(self.frequency_display(*self.active));
+
+            let mut swap_response = ui
+                .add_enabled(self.interactive, Button::new("\u{21C4}"))
+                .on_hover_text(localize(ui.ctx(), XtText::SwapFrequencies));
+            if swap_response.clicked() {
+                std::mem::swap(self.active, self.standby);
+                swap_response.mark_changed();
+            }
+
+            let standby_response = ui.add(self.frequency_display(*self.standby));
+            let knob_response = self.dual_knob_ui(ui);
+
+            let response = active_response
+                .union(swap_response)
+                .union(standby_response)
+                .union(knob_response);
+
+            slider_widget_info(
+                ui,
+                &response,
+                self.label.as_ref(),
+                *self.standby as f64,
+                Some(*self.range.start() as f64),
+                Some(*self.range.end() as f64),
+                Some(self.channel_spacing as f64),
+            );
+
+            response
+        })
+        .inner
+    }
+}
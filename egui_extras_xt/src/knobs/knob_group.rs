@@ -0,0 +1,89 @@
+use egui::{Context, Id};
+use strum::{Display, EnumIter};
+
+use crate::XtWidgetState;
+
+// ----------------------------------------------------------------------------
+
+#[derive(Clone, Copy, Debug, Display, EnumIter, Eq, PartialEq)]
+pub enum GroupMode {
+    /// Follow the value of the last changed member.
+    #[strum(to_string = "Absolute")]
+    Absolute,
+
+    /// Move by the same amount as the last changed member.
+    #[strum(to_string = "Relative delta")]
+    RelativeDelta,
+
+    /// Move by the same amount as the last changed member, in the opposite direction.
+    #[strum(to_string = "Inverted")]
+    Inverted,
+}
+
+impl GroupMode {
+    fn delta_sign(self) -> f32 {
+        match self {
+            GroupMode::Absolute | GroupMode::RelativeDelta => 1.0,
+            GroupMode::Inverted => -1.0,
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// Shared state of ganged knobs, stored in egui memory under the group id.
+///
+/// Every member remembers how much of `total_delta` it has already applied in its
+/// [`XtWidgetState`], so members painted before the changed one catch up on the next frame.
+#[derive(Clone, Copy, Debug, Default)]
+pub(crate) struct KnobGroup {
+    value: f32,
+    total_delta: f32,
+}
+
+impl KnobGroup {
+    /// Returns the new value of the member if another member has changed since its last call.
+    pub(crate) fn follow(
+        ctx: &Context,
+        group_id: Id,
+        member_id: Id,
+        mode: GroupMode,
+        value: f32,
+    ) -> Option<f32> {
+        let group = ctx.data_mut(|data| data.get_temp::<KnobGroup>(group_id))?;
+
+        XtWidgetState::update(ctx, member_id, |state| {
+            let seen_delta = state.group_seen_delta.get_or_insert(group.total_delta);
+
+            if *seen_delta == group.total_delta {
+                return None;
+            }
+
+            let pending_delta = group.total_delta - *seen_delta;
+            *seen_delta = group.total_delta;
+
+            Some(match mode {
+                GroupMode::Absolute => group.value,
+                GroupMode::RelativeDelta | GroupMode::Inverted => {
+                    value + pending_delta * mode.delta_sign()
+                }
+            })
+        })
+    }
+
+    /// Records a change made by one of the members.
+    ///
+    /// The delta is recorded as is, the following members apply their own [`GroupMode`].
+    pub(crate) fn publish(ctx: &Context, group_id: Id, member_id: Id, value: f32, delta: f32) {
+        let total_delta = ctx.data_mut(|data| {
+            let group = data.get_temp_mut_or_default::<KnobGroup>(group_id);
+            group.value = value;
+            group.total_delta += delta;
+            group.total_delta
+        });
+
+        XtWidgetState::update(ctx, member_id, |state| {
+            state.group_seen_delta = Some(total_delta);
+        });
+    }
+}
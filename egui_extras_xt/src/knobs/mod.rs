@@ -1,7 +1,17 @@
 mod angle_knob;
 mod audio_knob;
+#[cfg(feature = "displays")]
+mod frequency_entry;
+mod knob_group;
 mod thumbstick_widget;
+mod value_drag;
+mod value_entry;
 
-pub use angle_knob::{AngleKnob, AngleKnobPreset};
-pub use audio_knob::AudioKnob;
+pub use angle_knob::{AngleKnob, AngleKnobPreset, AngleKnobResponse, KnobInteraction};
+pub use audio_knob::{AudioKnob, DragAxis, KnobIndicator, Taper};
+#[cfg(feature = "displays")]
+pub use frequency_entry::FrequencyEntry;
+pub use knob_group::GroupMode;
+pub(crate) use knob_group::KnobGroup;
 pub use thumbstick_widget::{ThumbstickDeadZone, ThumbstickSnap, ThumbstickWidget};
+pub(crate) use value_drag::KnobValueDrag;
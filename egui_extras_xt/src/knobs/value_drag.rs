@@ -0,0 +1,64 @@
+use std::ops::RangeInclusive;
+
+use egui::{Context, Id};
+use emath::remap;
+
+// ----------------------------------------------------------------------------
+
+/// Value carried while alt-dragging from one knob onto another, stored in egui memory
+/// until a knob accepting drops takes it or the source knob is released.
+#[derive(Clone, Debug)]
+pub(crate) struct KnobValueDrag {
+    source_id: Id,
+
+    /// Value as displayed by the source knob.
+    value: f32,
+
+    /// Displayed range of the source knob.
+    display_range: RangeInclusive<f32>,
+}
+
+impl KnobValueDrag {
+    fn id() -> Id {
+        Id::new("egui_extras_xt::knob_value_drag")
+    }
+
+    pub(crate) fn start(
+        ctx: &Context,
+        source_id: Id,
+        value: f32,
+        display_range: RangeInclusive<f32>,
+    ) {
+        ctx.data_mut(|data| {
+            data.insert_temp(
+                Self::id(),
+                KnobValueDrag {
+                    source_id,
+                    value,
+                    display_range,
+                },
+            );
+        });
+    }
+
+    pub(crate) fn current(ctx: &Context) -> Option<KnobValueDrag> {
+        ctx.data_mut(|data| data.get_temp::<KnobValueDrag>(Self::id()))
+    }
+
+    pub(crate) fn clear(ctx: &Context) {
+        ctx.data_mut(|data| data.remove::<KnobValueDrag>(Self::id()));
+    }
+
+    pub(crate) fn is_source(&self, id: Id) -> bool {
+        self.source_id == id
+    }
+
+    /// The carried value mapped proportionally into the displayed range of the target.
+    pub(crate) fn value_in(&self, display_range: RangeInclusive<f32>) -> f32 {
+        if self.display_range == display_range {
+            self.value
+        } else {
+            remap(self.value, self.display_range.clone(), display_range)
+        }
+    }
+}
@@ -0,0 +1,71 @@
+use egui::{Area, Frame, Key, Order, Response, TextEdit, Ui};
+
+use crate::XtWidgetState;
+
+// ----------------------------------------------------------------------------
+
+/// Opens a text entry popup below the knob on right-click or double-click, and returns
+/// the parsed value once the user confirms it with Enter.
+/// Escape or clicking elsewhere closes the popup without changing the value.
+pub(crate) fn value_entry_popup(
+    ui: &Ui,
+    response: &Response,
+    format: impl FnOnce() -> String,
+    parse: impl FnOnce(&str) -> Option<f32>,
+) -> Option<f32> {
+    let open_requested = response.secondary_clicked() || response.double_clicked();
+
+    let mut edit_text = XtWidgetState::load(ui.ctx(), response.id).edit_text;
+
+    if open_requested {
+        edit_text = Some(format());
+    }
+
+    let mut text = edit_text?;
+
+    let text_response = Area::new(response.id.with("value_entry"))
+        .order(Order::Foreground)
+        .constrain(true)
+        .fixed_pos(response.rect.left_bottom())
+        .show(ui.ctx(), |ui| {
+            Frame::popup(ui.style())
+                .show(ui, |ui| {
+                    ui.add(TextEdit::singleline(&mut text).desired_width(64.0))
+                })
+                .inner
+        })
+        .inner;
+
+    if open_requested {
+        text_response.request_focus();
+    }
+
+    let closed = text_response.lost_focus();
+
+    let new_value = if closed && ui.input(|input| input.key_pressed(Key::Enter)) {
+        parse(&text)
+    } else {
+        None
+    };
+
+    XtWidgetState::update(ui.ctx(), response.id, |state| {
+        state.edit_text = (!closed).then_some(text);
+    });
+
+    new_value
+}
+
+/// Parses an angle typed in degrees (`45`, `45°`, `45deg`) or radians (`0.785rad`).
+pub(crate) fn parse_angle(text: &str) -> Option<f32> {
+    let text = text.trim();
+
+    if let Some(radians) = text.strip_suffix("rad") {
+        radians.trim_end().parse::<f32>().ok()
+    } else {
+        let degrees = text
+            .strip_suffix('°')
+            .or_else(|| text.strip_suffix("deg"))
+            .unwrap_or(text);
+        degrees.trim_end().parse::<f32>().ok().map(f32::to_radians)
+    }
+}
@@ -1,9 +1,18 @@
 #![feature(step_trait)]
 
 mod hash;
+mod localization;
+mod snapshot;
+mod style;
+mod widget_state;
 
 pub mod common;
 
+pub use localization::{clear_localization, set_localization, XtText};
+pub use snapshot::render_to_shapes;
+pub use style::{set_backlight, set_xt_style, xt_colors, xt_style, XtColors, XtPalette, XtStyle};
+pub use widget_state::{clear_widget_state, XtWidgetState};
+
 #[cfg(feature = "barcodes")]
 pub mod barcodes;
 
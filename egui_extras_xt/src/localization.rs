@@ -0,0 +1,103 @@
+use std::fmt::{self, Display};
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use egui::{Context, Id};
+
+// ----------------------------------------------------------------------------
+
+/// Every user-visible string generated by the widgets of this crate.
+///
+/// The [`Display`] implementation produces the default English text.
+#[derive(Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub enum XtText {
+    /// Angle readout of knobs and compass cursors, in degrees.
+    Degrees(f32),
+
+    /// Number of full turns of an angle knob.
+    TurnCount(f32),
+
+    /// Default value tooltip of audio knobs.
+    ValueWithPercentage {
+        value: f32,
+        percentage: f32,
+    },
+
+    /// Value readout of knobs in the indeterminate state, e.g. for mixed selections.
+    Indeterminate,
+
+    AboutWindowTitle,
+    Version(String),
+    HomePage,
+    Repository,
+    Authors,
+    License,
+    LicenseOperator(String),
+    LicenseFileNote(String),
+
+    RootDirectory,
+    EmptyDirectory,
+    DirectoryContents(PathBuf),
+
+    /// Hover text of the swap button of frequency entries.
+    SwapFrequencies,
+}
+
+impl Display for XtText {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            // Adding zero turns the negative zero of tiny negative angles into a positive one
+            XtText::Degrees(degrees) => write!(f, "{:.0}°", degrees.round() + 0.0),
+            XtText::TurnCount(turns) => write!(f, "×{}", turns.trunc()),
+            XtText::ValueWithPercentage { value, percentage } => {
+                write!(f, "{value:.2} ({percentage:.0}%)")
+            }
+            XtText::Indeterminate => write!(f, "—"),
+            XtText::AboutWindowTitle => write!(f, "About"),
+            XtText::Version(version) => write!(f, "Version {version:}"),
+            XtText::HomePage => write!(f, "Home page"),
+            XtText::Repository => write!(f, "Repository"),
+            XtText::Authors => write!(f, "Authors"),
+            XtText::License => write!(f, "License: "),
+            XtText::LicenseOperator(operator) => write!(f, " {} ", operator.to_lowercase()),
+            XtText::LicenseFileNote(license_file) => {
+                write!(f, "License: See the {license_file:} file for details.")
+            }
+            XtText::RootDirectory => write!(f, "Root directory"),
+            XtText::EmptyDirectory => write!(f, "Empty directory"),
+            XtText::DirectoryContents(path) => write!(f, "Contents of {path:?}"),
+            XtText::SwapFrequencies => write!(f, "Swap active and standby"),
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+type Localizer = Arc<dyn Fn(XtText) -> String + Send + Sync>;
+
+fn localizer_id() -> Id {
+    Id::new("egui_extras_xt::localization")
+}
+
+/// Routes the strings generated by the widgets of this crate through `localizer`.
+///
+/// Texts the localizer doesn't care about can be passed on to [`XtText::to_string`].
+pub fn set_localization(
+    ctx: &Context,
+    localizer: impl Fn(XtText) -> String + Send + Sync + 'static,
+) {
+    ctx.data_mut(|data| data.insert_temp::<Localizer>(localizer_id(), Arc::new(localizer)));
+}
+
+/// Restores the default English texts.
+pub fn clear_localization(ctx: &Context) {
+    ctx.data_mut(|data| data.remove::<Localizer>(localizer_id()));
+}
+
+pub(crate) fn localize(ctx: &Context, text: XtText) -> String {
+    match ctx.data_mut(|data| data.get_temp::<Localizer>(localizer_id())) {
+        Some(localizer) => localizer(text),
+        None => text.to_string(),
+    }
+}
@@ -0,0 +1,43 @@
+use std::sync::Arc;
+
+use egui::{Context, Id, LayerId, RawInput, Rect, Style, Ui};
+use epaint::{ClippedShape, Shape};
+
+// ----------------------------------------------------------------------------
+
+/// Run `add_contents` against a throwaway headless [`Context`] and return the
+/// shapes it painted, e.g. for exporting widget faces to SVG or PNG.
+///
+/// The shapes are positioned in the coordinate space of `rect`.
+/// Only the font atlas of the throwaway context is used, no windowing backend is required.
+#[must_use]
+pub fn render_to_shapes(
+    add_contents: impl FnOnce(&mut Ui),
+    rect: Rect,
+    style: impl Into<Arc<Style>>,
+) -> Vec<Shape> {
+    let ctx = Context::default();
+    ctx.set_style(style);
+
+    let raw_input = RawInput {
+        screen_rect: Some(rect),
+        ..Default::default()
+    };
+
+    let full_output = ctx.run(raw_input, |ctx| {
+        let mut ui = Ui::new(
+            ctx.clone(),
+            LayerId::background(),
+            Id::new("render_to_shapes"),
+            rect,
+            rect,
+        );
+        add_contents(&mut ui);
+    });
+
+    full_output
+        .shapes
+        .into_iter()
+        .map(|ClippedShape(_clip_rect, shape)| shape)
+        .collect()
+}
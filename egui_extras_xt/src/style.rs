@@ -0,0 +1,152 @@
+use ecolor::{Color32, Rgba};
+use egui::{Context, Id, Ui, Visuals};
+use strum::{Display, EnumIter};
+
+// ----------------------------------------------------------------------------
+
+/// Palette the semantic colors of this crate are derived from.
+#[derive(Clone, Copy, Debug, Default, Display, EnumIter, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum XtPalette {
+    /// Colors of the egui theme.
+    #[default]
+    #[strum(to_string = "Standard")]
+    Standard,
+
+    /// The Okabe-Ito palette, distinguishable with all common forms of color blindness.
+    #[strum(to_string = "Color-blind safe")]
+    ColorBlindSafe,
+}
+
+/// Crate-wide style shared by every widget of this crate.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct XtStyle {
+    pub palette: XtPalette,
+
+    /// Backlight level within `0.0..=1.0`, fading the system display style
+    /// from its dim night variant to its day variant.
+    pub backlight: f32,
+}
+
+impl Default for XtStyle {
+    fn default() -> Self {
+        Self {
+            palette: XtPalette::default(),
+            backlight: 1.0,
+        }
+    }
+}
+
+fn style_id() -> Id {
+    Id::new("egui_extras_xt::style")
+}
+
+#[must_use]
+pub fn xt_style(ctx: &Context) -> XtStyle {
+    ctx.data_mut(|data| data.get_temp(style_id()))
+        .unwrap_or_default()
+}
+
+pub fn set_xt_style(ctx: &Context, style: XtStyle) {
+    ctx.data_mut(|data| data.insert_temp(style_id(), style));
+}
+
+pub fn set_backlight(ctx: &Context, backlight: f32) {
+    set_xt_style(
+        ctx,
+        XtStyle {
+            backlight: backlight.clamp(0.0, 1.0),
+            ..xt_style(ctx)
+        },
+    );
+}
+
+// ----------------------------------------------------------------------------
+
+/// Semantic colors used by the widgets of this crate for markers, stops and value ranges.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct XtColors {
+    pub ok: Color32,
+    pub warning: Color32,
+    pub error: Color32,
+    pub info: Color32,
+    pub neutral: Color32,
+}
+
+impl XtColors {
+    #[must_use]
+    pub fn new(palette: XtPalette, visuals: &Visuals) -> Self {
+        let colors = match palette {
+            XtPalette::Standard => Self {
+                ok: if visuals.dark_mode {
+                    Color32::from_rgb(0x60, 0xC0, 0x60)
+                } else {
+                    Color32::from_rgb(0x20, 0x80, 0x20)
+                },
+                warning: visuals.warn_fg_color,
+                error: visuals.error_fg_color,
+                info: visuals.hyperlink_color,
+                neutral: visuals.text_color(),
+            },
+            XtPalette::ColorBlindSafe => Self {
+                ok: Color32::from_rgb(0x00, 0x9E, 0x73),
+                warning: Color32::from_rgb(0xE6, 0x9F, 0x00),
+                error: Color32::from_rgb(0xD5, 0x5E, 0x00),
+                info: if visuals.dark_mode {
+                    Color32::from_rgb(0x56, 0xB4, 0xE9)
+                } else {
+                    Color32::from_rgb(0x00, 0x72, 0xB2)
+                },
+                neutral: visuals.text_color(),
+            },
+        };
+
+        let background = visuals.extreme_bg_color;
+        let adjust = |color| with_contrast(color, background, visuals.text_color());
+
+        Self {
+            ok: adjust(colors.ok),
+            warning: adjust(colors.warning),
+            error: adjust(colors.error),
+            info: adjust(colors.info),
+            neutral: colors.neutral,
+        }
+    }
+
+    /// The non-neutral colors, for telling apart unrelated items like markers.
+    #[must_use]
+    pub fn categorical(&self) -> [Color32; 4] {
+        [self.info, self.ok, self.warning, self.error]
+    }
+}
+
+/// Semantic colors for the current theme and crate-level palette.
+#[must_use]
+pub fn xt_colors(ui: &Ui) -> XtColors {
+    XtColors::new(xt_style(ui.ctx()).palette, ui.visuals())
+}
+
+fn relative_luminance(color: Color32) -> f32 {
+    let color = Rgba::from(color);
+    0.2126 * color.r() + 0.7152 * color.g() + 0.0722 * color.b()
+}
+
+fn contrast_ratio(a: Color32, b: Color32) -> f32 {
+    let (a, b) = (relative_luminance(a), relative_luminance(b));
+    (a.max(b) + 0.05) / (a.min(b) + 0.05)
+}
+
+/// Pulls `color` towards the text color until it reaches the 3:1 contrast ratio
+/// recommended for graphical objects against `background`.
+fn with_contrast(color: Color32, background: Color32, text_color: Color32) -> Color32 {
+    const MIN_CONTRAST_RATIO: f32 = 3.0;
+
+    (0..=4)
+        .map(|step| {
+            let t = step as f32 / 4.0;
+            Color32::from(egui::lerp(Rgba::from(color)..=Rgba::from(text_color), t))
+        })
+        .find(|candidate| contrast_ratio(*candidate, background) >= MIN_CONTRAST_RATIO)
+        .unwrap_or(text_color)
+}
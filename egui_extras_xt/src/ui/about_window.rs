@@ -1,10 +1,14 @@
-use egui::{Context, Vec2, Window};
+use egui::{Align, Context, Layout, Ui, Vec2, Window};
 
+use crate::localization::localize;
 use crate::ui::hyperlink_with_icon::HyperlinkWithIcon;
+use crate::XtText;
 
 // ----------------------------------------------------------------------------
 
-#[doc(hidden)]
+/// Cargo metadata shown by [`AboutWindow`], usually filled in by [`crate::package_info`]
+/// from the package being compiled.
+#[derive(Clone, Copy, Debug)]
 pub struct PackageInfo {
     pub name: &'static str,
     pub version: &'static str,
@@ -16,7 +20,7 @@ pub struct PackageInfo {
     pub license_file: Option<&'static str>,
 }
 
-#[doc(hidden)]
+/// [`PackageInfo`] of the calling package, read from the `CARGO_PKG_*` variables.
 #[macro_export]
 macro_rules! package_info {
     () => {{
@@ -56,94 +60,142 @@ impl PackageInfo {
 
 // ----------------------------------------------------------------------------
 
+/// Shorthand for showing the [`AboutWindow`] of the calling package.
 #[macro_export]
 macro_rules! show_about_window {
     ($ctx:expr, $open:expr) => {{
-        $crate::ui::about_window::show_about_window_impl($ctx, $open, &$crate::package_info!());
+        $crate::show_about_window!($ctx, $open, false);
     }};
+    ($ctx:expr, $open:expr, $mirrored:expr) => {{
+        $crate::ui::about_window::AboutWindow::new($crate::package_info!())
+            .mirrored($mirrored)
+            .show($ctx, $open);
+    }};
+}
+
+fn link_row(ui: &mut Ui, mirrored: bool, add_contents: impl FnOnce(&mut Ui)) {
+    if mirrored {
+        ui.with_layout(Layout::right_to_left(Align::Center), add_contents);
+    } else {
+        ui.horizontal(add_contents);
+    }
 }
 
-#[doc(hidden)]
-pub fn show_about_window_impl(ctx: &Context, open: &mut bool, package_info: &PackageInfo) {
-    Window::new("About")
-        .open(open)
-        .resizable(false)
-        .collapsible(false)
-        .show(ctx, |ui| {
-            ui.heading(package_info.name);
-            ui.label(format!("Version {}", package_info.version));
+/// Window with the name, version, description, links, authors and license of a package.
+#[must_use = "You should show this window with `window.show(ctx, &mut open);`"]
+pub struct AboutWindow {
+    package_info: PackageInfo,
+    mirrored: bool,
+}
 
-            ui.separator();
+impl AboutWindow {
+    pub fn new(package_info: PackageInfo) -> Self {
+        Self {
+            package_info,
+            mirrored: false,
+        }
+    }
+
+    /// Mirrors the rows of links, e.g. for right-to-left layouts.
+    pub fn mirrored(mut self, mirrored: bool) -> Self {
+        self.mirrored = mirrored;
+        self
+    }
+
+    /// Shows the window while `open` is set, closing it clears `open`.
+    pub fn show(self, ctx: &Context, open: &mut bool) {
+        let AboutWindow {
+            package_info,
+            mirrored,
+        } = self;
+
+        Window::new(localize(ctx, XtText::AboutWindowTitle))
+            .open(open)
+            .resizable(false)
+            .collapsible(false)
+            .show(ctx, |ui| {
+                ui.heading(package_info.name);
+                ui.label(localize(
+                    ctx,
+                    XtText::Version(package_info.version.to_owned()),
+                ));
 
-            if let Some(description) = package_info.description {
-                ui.label(description);
                 ui.separator();
-            }
 
-            ui.horizontal(|ui| {
-                if let Some(homepage) = package_info.homepage {
-                    ui.hyperlink_with_icon_to("Home page", homepage);
+                if let Some(description) = package_info.description {
+                    ui.label(description);
+                    ui.separator();
                 }
 
-                if let Some(repository) = package_info.repository {
-                    ui.hyperlink_with_icon_to("Repository", repository);
-                }
-            });
+                link_row(ui, mirrored, |ui| {
+                    if let Some(homepage) = package_info.homepage {
+                        ui.hyperlink_with_icon_to(localize(ctx, XtText::HomePage), homepage);
+                    }
 
-            ui.separator();
-
-            ui.collapsing("Authors", |ui| {
-                ui.horizontal(|ui| {
-                    for (author_name, author_email) in package_info.authors() {
-                        if let Some(author_email) = author_email {
-                            if !["noreply@", "no-reply@", "@users.noreply."]
-                                .iter()
-                                .any(|no_reply| author_email.contains(no_reply))
-                            {
-                                ui.hyperlink_with_icon_to(
-                                    author_name,
-                                    format!("mailto:{author_email:}"),
-                                );
-                            } else {
-                                ui.label(format!("\u{1F464} {author_name:}"));
-                            }
-                        } else {
-                            ui.label(format!("\u{1F464} {author_name:}"));
-                        }
+                    if let Some(repository) = package_info.repository {
+                        ui.hyperlink_with_icon_to(localize(ctx, XtText::Repository), repository);
                     }
                 });
 
-                // (!) Rust incremental compilation bug:
-                // When the 'license' field is changed in the crate's Cargo.toml,
-                // source files that include that field through `env!()` macros
-                // are not picked up for recompilation.
-                // Always do `cargo clean` + full rebuild when changing Cargo.toml metadata.
-                if let Some(license) = package_info.license {
-                    ui.separator();
-                    ui.horizontal(|ui| {
-                        ui.spacing_mut().item_spacing = Vec2::splat(0.0);
-                        ui.label("License: ");
+                ui.separator();
 
-                        license.split_whitespace().for_each(|s| match s {
-                            operator @ ("OR" | "AND" | "WITH") => {
-                                ui.label(format!(" {} ", operator.to_lowercase()));
-                            }
-                            license => {
-                                ui.hyperlink_with_icon_to(
-                                    license,
-                                    format!("https://spdx.org/licenses/{license:}.html"),
-                                );
+                ui.collapsing(localize(ctx, XtText::Authors), |ui| {
+                    link_row(ui, mirrored, |ui| {
+                        for (author_name, author_email) in package_info.authors() {
+                            if let Some(author_email) = author_email {
+                                if !["noreply@", "no-reply@", "@users.noreply."]
+                                    .iter()
+                                    .any(|no_reply| author_email.contains(no_reply))
+                                {
+                                    ui.hyperlink_with_icon_to(
+                                        author_name,
+                                        format!("mailto:{author_email:}"),
+                                    );
+                                } else {
+                                    ui.label(format!("\u{1F464} {author_name:}"));
+                                }
+                            } else {
+                                ui.label(format!("\u{1F464} {author_name:}"));
                             }
-                        });
+                        }
                     });
-                };
 
-                if let Some(license_file) = package_info.license_file {
-                    ui.separator();
-                    ui.label(format!(
-                        "License: See the {license_file:} file for details."
-                    ));
-                };
+                    // (!) Rust incremental compilation bug:
+                    // When the 'license' field is changed in the crate's Cargo.toml,
+                    // source files that include that field through `env!()` macros
+                    // are not picked up for recompilation.
+                    // Always do `cargo clean` + full rebuild when changing Cargo.toml metadata.
+                    if let Some(license) = package_info.license {
+                        ui.separator();
+                        link_row(ui, mirrored, |ui| {
+                            ui.spacing_mut().item_spacing = Vec2::splat(0.0);
+                            ui.label(localize(ctx, XtText::License));
+
+                            license.split_whitespace().for_each(|s| match s {
+                                operator @ ("OR" | "AND" | "WITH") => {
+                                    ui.label(localize(
+                                        ctx,
+                                        XtText::LicenseOperator(operator.to_owned()),
+                                    ));
+                                }
+                                license => {
+                                    ui.hyperlink_with_icon_to(
+                                        license,
+                                        format!("https://spdx.org/licenses/{license:}.html"),
+                                    );
+                                }
+                            });
+                        });
+                    };
+
+                    if let Some(license_file) = package_info.license_file {
+                        ui.separator();
+                        ui.label(localize(
+                            ctx,
+                            XtText::LicenseFileNote(license_file.to_owned()),
+                        ));
+                    };
+                });
             });
-        });
+    }
 }
@@ -1,4 +1,6 @@
-use egui::{Hyperlink, Response, Ui, Widget};
+use std::sync::Arc;
+
+use egui::{Context, Hyperlink, Id, Response, Ui, Widget};
 
 pub trait HyperlinkWithIcon {
     fn hyperlink_with_icon(&mut self, url: impl ToString) -> Response;
@@ -111,10 +113,45 @@ fn hyperlink_icon(url: &str) -> char {
     '\u{2BA9}'
 }
 
+// ----------------------------------------------------------------------------
+
+type IconMapping = Arc<dyn Fn(&str) -> Option<char> + Send + Sync>;
+
+fn icon_mapping_id() -> Id {
+    Id::new("egui_extras_xt::hyperlink_icons")
+}
+
+/// Overrides the icons of hyperlinks with the ones returned by `icon_for`.
+///
+/// URLs for which `icon_for` returns `None` fall back to the built-in table.
+pub fn set_hyperlink_icons(
+    ctx: &Context,
+    icon_for: impl Fn(&str) -> Option<char> + Send + Sync + 'static,
+) {
+    ctx.data_mut(|data| data.insert_temp::<IconMapping>(icon_mapping_id(), Arc::new(icon_for)));
+}
+
+/// Restores the built-in icon table.
+pub fn clear_hyperlink_icons(ctx: &Context) {
+    ctx.data_mut(|data| data.remove::<IconMapping>(icon_mapping_id()));
+}
+
+fn icon_for(ctx: &Context, url: &str) -> char {
+    ctx.data_mut(|data| data.get_temp::<IconMapping>(icon_mapping_id()))
+        .and_then(|icon_mapping| icon_mapping(url))
+        .unwrap_or_else(|| hyperlink_icon(url))
+}
+
+// ----------------------------------------------------------------------------
+
 impl HyperlinkWithIcon for Ui {
     fn hyperlink_with_icon(&mut self, url: impl ToString) -> Response {
         Hyperlink::from_label_and_url(
-            format!("{} {}", hyperlink_icon(&url.to_string()), url.to_string()),
+            format!(
+                "{} {}",
+                icon_for(self.ctx(), &url.to_string()),
+                url.to_string()
+            ),
             url,
         )
         .ui(self)
@@ -122,7 +159,11 @@ impl HyperlinkWithIcon for Ui {
 
     fn hyperlink_with_icon_to(&mut self, label: impl ToString, url: impl ToString) -> Response {
         Hyperlink::from_label_and_url(
-            format!("{} {}", hyperlink_icon(&url.to_string()), label.to_string()),
+            format!(
+                "{} {}",
+                icon_for(self.ctx(), &url.to_string()),
+                label.to_string()
+            ),
             url,
         )
         .ui(self)
@@ -0,0 +1,87 @@
+use egui::{Context, Id};
+
+// ----------------------------------------------------------------------------
+
+/// Transient state of a widget, stored in egui memory under the id of the widget.
+///
+/// Every widget of this crate keeps its in-between-frames state (drag transactions,
+/// marquee and blink phases, ganged knob bookkeeping, numeric entry popups) here,
+/// so it can be inspected, persisted or reset in one place.
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct XtWidgetState {
+    /// Value of the widget when the current drag started.
+    pub(crate) drag_origin_value: Option<f32>,
+
+    /// Unsnapped value accumulated by relative drags.
+    pub(crate) drag_accumulator: Option<f32>,
+
+    /// Scroll distance accumulated towards the next whole nudge step.
+    pub(crate) scroll_accumulator: Option<f32>,
+
+    /// Time when the widget started its marquee and blink cycles.
+    pub(crate) animation_start_time: Option<f64>,
+
+    /// Portion of the knob group delta already applied by this member.
+    pub(crate) group_seen_delta: Option<f32>,
+
+    /// Contents of the numeric entry popup while it is open.
+    pub(crate) edit_text: Option<String>,
+
+    /// Angular sector selected by dragging, as start angle and signed sweep.
+    pub(crate) sector_selection: Option<(f32, f32)>,
+
+    /// Angular interval selected by Ctrl-dragging, as start and end angles.
+    pub(crate) interval_selection: Option<(f32, f32)>,
+
+    /// Whether the current drag selects an interval rather than changing the value.
+    pub(crate) interval_selecting: bool,
+
+    /// Whether the current drag moves the target bug rather than the value.
+    pub(crate) target_dragging: bool,
+
+    /// Largest deflection from the spring target during the current drag.
+    pub(crate) spring_peak: Option<f32>,
+
+    /// Value animation in flight, as start value, target value and start time.
+    pub(crate) animation: Option<(f32, f32, f64)>,
+}
+
+impl XtWidgetState {
+    #[must_use]
+    pub fn load(ctx: &Context, id: Id) -> Self {
+        ctx.data_mut(|data| data.get_temp(id)).unwrap_or_default()
+    }
+
+    pub fn store(self, ctx: &Context, id: Id) {
+        ctx.data_mut(|data| data.insert_temp(id, self));
+    }
+
+    pub fn reset(ctx: &Context, id: Id) {
+        ctx.data_mut(|data| data.remove::<Self>(id));
+    }
+
+    /// Loads the state of `id`, lets `f` modify it and stores it back.
+    pub(crate) fn update<R>(ctx: &Context, id: Id, f: impl FnOnce(&mut Self) -> R) -> R {
+        let mut state = Self::load(ctx, id);
+        let result = f(&mut state);
+        state.store(ctx, id);
+        result
+    }
+}
+
+/// Forgets the transient state of every widget in this crate, including running
+/// value animations, e.g. when switching scenes or setting up deterministic tests.
+///
+/// Only the state owned by this crate is removed, egui's own animations are left alone.
+pub fn clear_widget_state(ctx: &Context) {
+    ctx.data_mut(|data| {
+        data.remove_by_type::<XtWidgetState>();
+
+        #[cfg(feature = "knobs")]
+        data.remove_by_type::<crate::knobs::KnobGroup>();
+    });
+
+    #[cfg(feature = "knobs")]
+    crate::knobs::KnobValueDrag::clear(ctx);
+}
@@ -0,0 +1,89 @@
+//! Headless check that `LinearCompass` keeps its painting to its own rect.
+
+use egui::{pos2, Context, Id, LayerId, RawInput, Rect, Ui};
+use epaint::{ClippedShape, Shape};
+
+use egui_extras_xt::compasses::{CompassMarker, LinearCompass};
+
+// ----------------------------------------------------------------------------
+
+const LABEL_TEXT: &str = "After the compass";
+
+fn flatten(clip_rect: Rect, shape: Shape) -> Vec<(Rect, Shape)> {
+    match shape {
+        Shape::Vec(shapes) => shapes
+            .into_iter()
+            .flat_map(|shape| flatten(clip_rect, shape))
+            .collect(),
+        shape => vec![(clip_rect, shape)],
+    }
+}
+
+#[test]
+fn linear_compass_keeps_shapes_inside_rect() {
+    let screen_rect = Rect::from_min_max(pos2(0.0, 0.0), pos2(640.0, 256.0));
+
+    let ctx = Context::default();
+    let raw_input = RawInput {
+        screen_rect: Some(screen_rect),
+        ..Default::default()
+    };
+
+    let (mut compass_rect, mut label_rect) = (Rect::NOTHING, Rect::NOTHING);
+
+    let full_output = ctx.run(raw_input, |ctx| {
+        // Leave room around the compass, so overhanging shapes aren't hidden by the screen edges
+        let mut ui = Ui::new(
+            ctx.clone(),
+            LayerId::background(),
+            Id::new("linear_compass_clip"),
+            screen_rect.shrink(64.0),
+            screen_rect,
+        );
+
+        // Markers near the edges, with labels sticking out of the compass
+        let mut value = 0.0;
+        let markers = [
+            CompassMarker::new(-88.0f32.to_radians()).label("Left edge marker"),
+            CompassMarker::new(88.0f32.to_radians()).label("Right edge marker"),
+        ];
+
+        compass_rect = ui
+            .add(LinearCompass::new(&mut value).markers(&markers))
+            .rect;
+        label_rect = ui.label(LABEL_TEXT).rect;
+    });
+
+    let shapes = full_output
+        .shapes
+        .into_iter()
+        .flat_map(|ClippedShape(clip_rect, shape)| flatten(clip_rect, shape))
+        .collect::<Vec<_>>();
+
+    let label_index = shapes
+        .iter()
+        .position(|(_, shape)| {
+            matches!(shape, Shape::Text(text_shape) if text_shape.galley.text() == LABEL_TEXT)
+        })
+        .expect("the label after the compass wasn't painted");
+
+    // The label is laid out after the compass and painted unclipped by it
+    assert!(
+        !label_rect.intersects(compass_rect),
+        "the label at {label_rect:?} overlaps the compass at {compass_rect:?}"
+    );
+    assert!(
+        shapes[label_index].0.contains_rect(label_rect),
+        "the label at {label_rect:?} is clipped to {:?}",
+        shapes[label_index].0
+    );
+
+    for (clip_rect, shape) in &shapes[..label_index] {
+        let painted_rect = shape.visual_bounding_rect().intersect(*clip_rect);
+
+        assert!(
+            !painted_rect.is_positive() || compass_rect.expand(0.5).contains_rect(painted_rect),
+            "compass shape painted at {painted_rect:?} escapes the compass at {compass_rect:?}"
+        );
+    }
+}
@@ -0,0 +1,155 @@
+//! Pins the defaults of `LinearCompass` shared with the other angular widgets.
+
+use egui::{
+    pos2, Color32, Context, Event, Id, Key, LayerId, Modifiers, PointerButton, RawInput, Rect,
+    Style, Ui,
+};
+use epaint::Shape;
+
+use egui_extras_xt::common::DEFAULT_ANGLE_SHIFT_SNAP;
+use egui_extras_xt::compasses::{CompassMarker, DefaultCompassMarkerColor, LinearCompass};
+use egui_extras_xt::{render_to_shapes, xt_colors};
+
+// ----------------------------------------------------------------------------
+
+const SCREEN_RECT: Rect = Rect {
+    min: pos2(0.0, 0.0),
+    max: pos2(512.0, 128.0),
+};
+
+fn flatten(shapes: Vec<Shape>) -> Vec<Shape> {
+    shapes
+        .into_iter()
+        .flat_map(|shape| match shape {
+            Shape::Vec(shapes) => flatten(shapes),
+            shape => vec![shape],
+        })
+        .collect()
+}
+
+fn fill_color(shape: &Shape) -> Option<Color32> {
+    match shape {
+        Shape::Circle(circle) => Some(circle.fill),
+        Shape::Rect(rect) => Some(rect.fill),
+        Shape::Path(path) => Some(path.fill),
+        _ => None,
+    }
+}
+
+/// Runs a single frame with the compass, returning its rect.
+fn run_frame(ctx: &Context, value: &mut f32, events: Vec<Event>, modifiers: Modifiers) -> Rect {
+    let raw_input = RawInput {
+        screen_rect: Some(SCREEN_RECT),
+        events,
+        modifiers,
+        ..Default::default()
+    };
+
+    let mut compass_rect = Rect::NOTHING;
+
+    let _ = ctx.run(raw_input, |ctx| {
+        let mut ui = Ui::new(
+            ctx.clone(),
+            LayerId::background(),
+            Id::new("linear_compass_defaults"),
+            SCREEN_RECT,
+            SCREEN_RECT,
+        );
+        compass_rect = ui.add(LinearCompass::new(value)).rect;
+    });
+
+    compass_rect
+}
+
+#[test]
+fn linear_compass_shift_snap_default() {
+    let ctx = Context::default();
+    let mut value = 0.0;
+
+    // Click the compass to focus it
+    let compass_rect = run_frame(&ctx, &mut value, Vec::new(), Modifiers::NONE);
+    let pos = compass_rect.center();
+
+    let button_event = |pressed| Event::PointerButton {
+        pos,
+        button: PointerButton::Primary,
+        pressed,
+        modifiers: Modifiers::NONE,
+    };
+
+    run_frame(
+        &ctx,
+        &mut value,
+        vec![Event::PointerMoved(pos), button_event(true)],
+        Modifiers::NONE,
+    );
+    run_frame(&ctx, &mut value, vec![button_event(false)], Modifiers::NONE);
+    assert_eq!(value, 0.0, "clicking the compass must not change its value");
+
+    // Shift + arrow nudges by `shift_snap`
+    run_frame(
+        &ctx,
+        &mut value,
+        vec![Event::Key {
+            key: Key::ArrowRight,
+            pressed: true,
+            repeat: false,
+            modifiers: Modifiers::SHIFT,
+        }],
+        Modifiers::SHIFT,
+    );
+
+    assert!(
+        (value - DEFAULT_ANGLE_SHIFT_SNAP).abs() < 1e-6,
+        "shift nudged to {value} instead of the default shift snap of {DEFAULT_ANGLE_SHIFT_SNAP}"
+    );
+}
+
+#[test]
+fn linear_compass_default_marker_color_is_palette() {
+    let render = |default_marker_color: Option<DefaultCompassMarkerColor>| {
+        let mut palette = Vec::new();
+
+        let shapes = flatten(render_to_shapes(
+            |ui| {
+                palette = xt_colors(ui).categorical().to_vec();
+
+                let mut value = 0.0;
+                let markers = [CompassMarker::new(0.0).label("Mars")];
+
+                let mut compass = LinearCompass::new(&mut value).markers(&markers);
+                if let Some(default_marker_color) = default_marker_color {
+                    compass = compass.default_marker_color(default_marker_color);
+                }
+
+                ui.add(compass);
+            },
+            SCREEN_RECT,
+            Style::default(),
+        ));
+
+        (shapes, palette)
+    };
+
+    let (default_shapes, palette) = render(None);
+
+    assert!(
+        default_shapes
+            .iter()
+            .filter_map(fill_color)
+            .any(|fill| palette.contains(&fill)),
+        "no marker painted with a palette color"
+    );
+
+    assert_eq!(
+        default_shapes,
+        render(Some(DefaultCompassMarkerColor::Palette)).0,
+        "the default marker color differs from `DefaultCompassMarkerColor::Palette`"
+    );
+
+    assert_ne!(
+        default_shapes,
+        render(Some(DefaultCompassMarkerColor::System)).0,
+        "the default marker color is `DefaultCompassMarkerColor::System`"
+    );
+}
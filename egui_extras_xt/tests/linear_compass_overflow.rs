@@ -0,0 +1,127 @@
+//! Headless check of `LinearCompass` markers outside of the visible spread.
+
+use egui::{pos2, Color32, Rect, Style};
+use epaint::{PathShape, Shape};
+
+use egui_extras_xt::compasses::{CompassMarker, LinearCompass};
+use egui_extras_xt::render_to_shapes;
+
+// ----------------------------------------------------------------------------
+
+const VISIBLE_COLOR: Color32 = Color32::from_rgb(0x12, 0x34, 0x56);
+const HIDDEN_COLOR: Color32 = Color32::from_rgb(0x65, 0x43, 0x21);
+
+fn flatten(shapes: Vec<Shape>) -> Vec<Shape> {
+    shapes
+        .into_iter()
+        .flat_map(|shape| match shape {
+            Shape::Vec(shapes) => flatten(shapes),
+            shape => vec![shape],
+        })
+        .collect()
+}
+
+fn fill_color(shape: &Shape) -> Option<Color32> {
+    match shape {
+        Shape::Circle(circle) => Some(circle.fill),
+        Shape::Rect(rect) => Some(rect.fill),
+        Shape::Path(path) => Some(path.fill),
+        _ => None,
+    }
+}
+
+fn text(shape: &Shape) -> Option<&str> {
+    match shape {
+        Shape::Text(text_shape) => Some(text_shape.galley.text()),
+        _ => None,
+    }
+}
+
+/// Paints a compass looking at 0° with the default spread of 180°, along with one marker
+/// inside and one far outside of the visible spread.
+fn render(show_overflow_markers: bool) -> (Rect, Vec<Shape>) {
+    let mut compass_rect = Rect::NOTHING;
+
+    let shapes = flatten(render_to_shapes(
+        |ui| {
+            let mut value = 0.0;
+            let markers = [
+                CompassMarker::new(10.0f32.to_radians())
+                    .label("Visible")
+                    .color(VISIBLE_COLOR),
+                CompassMarker::new(150.0f32.to_radians())
+                    .label("Hidden")
+                    .color(HIDDEN_COLOR),
+            ];
+
+            compass_rect = ui
+                .add(
+                    LinearCompass::new(&mut value)
+                        .markers(&markers)
+                        .show_overflow_markers(show_overflow_markers),
+                )
+                .rect;
+        },
+        Rect::from_min_max(pos2(0.0, 0.0), pos2(512.0, 128.0)),
+        Style::default(),
+    ));
+
+    (compass_rect, shapes)
+}
+
+#[test]
+fn linear_compass_skips_markers_outside_spread() {
+    let (_, shapes) = render(false);
+
+    assert!(
+        shapes.iter().any(|shape| text(shape) == Some("Visible")),
+        "the visible marker label wasn't painted"
+    );
+    assert!(
+        shapes
+            .iter()
+            .any(|shape| fill_color(shape) == Some(VISIBLE_COLOR)),
+        "the visible marker wasn't painted"
+    );
+
+    assert!(
+        shapes.iter().all(|shape| text(shape) != Some("Hidden")),
+        "the label of the marker outside of the spread was painted"
+    );
+    assert!(
+        shapes
+            .iter()
+            .all(|shape| fill_color(shape) != Some(HIDDEN_COLOR)),
+        "the marker outside of the spread was painted"
+    );
+}
+
+#[test]
+fn linear_compass_paints_overflow_chevron() {
+    let (compass_rect, shapes) = render(true);
+
+    assert!(
+        shapes.iter().all(|shape| text(shape) != Some("Hidden")),
+        "the label of the marker outside of the spread was painted"
+    );
+
+    let hidden_shapes = shapes
+        .iter()
+        .filter(|shape| fill_color(shape) == Some(HIDDEN_COLOR))
+        .collect::<Vec<_>>();
+
+    match hidden_shapes[..] {
+        [Shape::Path(PathShape {
+            points,
+            closed: true,
+            ..
+        })] => {
+            assert_eq!(points.len(), 3, "the overflow chevron isn't a triangle");
+            assert!(
+                points.iter().all(|point| compass_rect.contains(*point)),
+                "the overflow chevron {points:?} is outside of the compass {compass_rect:?}"
+            );
+        }
+        _ => panic!("expected a single overflow chevron, got {hidden_shapes:?}"),
+    }
+}
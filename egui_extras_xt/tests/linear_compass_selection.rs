@@ -0,0 +1,103 @@
+//! Key bindings of `LinearCompass` drags: Ctrl selects an interval, Shift snaps the value.
+
+use egui::{pos2, vec2, Context, Event, Id, LayerId, Modifiers, PointerButton, RawInput, Rect, Ui};
+
+use egui_extras_xt::common::DEFAULT_ANGLE_SHIFT_SNAP;
+use egui_extras_xt::compasses::LinearCompass;
+
+// ----------------------------------------------------------------------------
+
+const SCREEN_RECT: Rect = Rect {
+    min: pos2(0.0, 0.0),
+    max: pos2(512.0, 128.0),
+};
+
+/// Runs a single frame with the compass, returning its rect and selection.
+fn run_frame(
+    ctx: &Context,
+    value: &mut f32,
+    events: Vec<Event>,
+    modifiers: Modifiers,
+) -> (Rect, Option<(f32, f32)>) {
+    let raw_input = RawInput {
+        screen_rect: Some(SCREEN_RECT),
+        events,
+        modifiers,
+        ..Default::default()
+    };
+
+    let (mut compass_rect, mut selection) = (Rect::NOTHING, None);
+
+    let _ = ctx.run(raw_input, |ctx| {
+        let mut ui = Ui::new(
+            ctx.clone(),
+            LayerId::background(),
+            Id::new("linear_compass_selection"),
+            SCREEN_RECT,
+            SCREEN_RECT,
+        );
+
+        let compass_response = LinearCompass::new(value).show(&mut ui);
+        compass_rect = compass_response.response.rect;
+        selection = compass_response.selection;
+    });
+
+    (compass_rect, selection)
+}
+
+/// Drags across the compass with `modifiers` held, returning the final value and selection.
+fn drag(modifiers: Modifiers) -> (f32, Option<(f32, f32)>) {
+    let ctx = Context::default();
+    let mut value = 0.0;
+
+    let (compass_rect, _) = run_frame(&ctx, &mut value, Vec::new(), modifiers);
+    let start = compass_rect.center();
+
+    let button_event = |pos, pressed| Event::PointerButton {
+        pos,
+        button: PointerButton::Primary,
+        pressed,
+        modifiers,
+    };
+
+    run_frame(
+        &ctx,
+        &mut value,
+        vec![Event::PointerMoved(start), button_event(start, true)],
+        modifiers,
+    );
+
+    let mut pos = start;
+    for _ in 0..8 {
+        pos += vec2(5.0, 0.0);
+        run_frame(&ctx, &mut value, vec![Event::PointerMoved(pos)], modifiers);
+    }
+
+    let (_, selection) = run_frame(&ctx, &mut value, vec![button_event(pos, false)], modifiers);
+    (value, selection)
+}
+
+#[test]
+fn linear_compass_ctrl_drag_selects_interval() {
+    let (value, selection) = drag(Modifiers::COMMAND);
+
+    assert_eq!(value, 0.0, "selecting an interval moved the compass");
+    assert!(
+        matches!(selection, Some((start, end)) if start < end),
+        "no interval was selected: {selection:?}"
+    );
+}
+
+#[test]
+fn linear_compass_shift_drag_snaps_value() {
+    let (value, selection) = drag(Modifiers::SHIFT);
+
+    assert_eq!(selection, None, "shift-dragging selected an interval");
+    assert_ne!(value, 0.0, "shift-dragging didn't move the compass");
+
+    let snap_steps = value / DEFAULT_ANGLE_SHIFT_SNAP;
+    assert!(
+        (snap_steps - snap_steps.round()).abs() < 1e-4,
+        "shift-dragging left the compass at {value}, off the shift snap grid"
+    );
+}
@@ -0,0 +1,43 @@
+//! Snapping to an explicit, unevenly spaced list of angles.
+
+use std::f32::consts::PI;
+
+use egui_extras_xt::common::nearest_angle;
+
+// ----------------------------------------------------------------------------
+
+#[test]
+fn nearest_angle_without_angles() {
+    assert_eq!(nearest_angle(&[], 1.0), None);
+}
+
+#[test]
+fn nearest_angle_uneven_spacing() {
+    let angles = [0.0, 0.3, 2.0];
+
+    assert_eq!(nearest_angle(&angles, 0.1), Some(0.0));
+    assert_eq!(nearest_angle(&angles, 0.2), Some(0.3));
+
+    // The midpoint between 0.3 and 2.0 is at 1.15
+    assert_eq!(nearest_angle(&angles, 1.1), Some(0.3));
+    assert_eq!(nearest_angle(&angles, 1.2), Some(2.0));
+    assert_eq!(nearest_angle(&angles, 2.5), Some(2.0));
+}
+
+#[test]
+fn nearest_angle_wraps_around_pi() {
+    let angles = [-3.0, 0.0, 2.0];
+
+    // 3.1 is 1.1 away from 2.0, but only ~0.18 away from -3.0 across +π
+    assert_eq!(nearest_angle(&angles, 3.1), Some(-3.0));
+    assert_eq!(nearest_angle(&angles, PI), Some(-3.0));
+    assert_eq!(nearest_angle(&angles, -PI), Some(-3.0));
+
+    // Likewise from the other side of -π
+    let angles = [-1.0, 3.0];
+    assert_eq!(nearest_angle(&angles, -3.0), Some(3.0));
+    assert_eq!(nearest_angle(&angles, -2.0), Some(-1.0));
+
+    // Angles outside of ±π are compared by their direction
+    assert_eq!(nearest_angle(&angles, -3.0 + 2.0 * PI), Some(3.0));
+}
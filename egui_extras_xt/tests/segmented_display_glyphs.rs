@@ -0,0 +1,85 @@
+//! Headless check of the glyph tables of the segmented display kinds.
+
+use egui::{pos2, Color32, Rect, Stroke, Style};
+use epaint::{PathShape, Shape};
+
+use egui_extras_xt::displays::{DisplayKind, DisplayStyle, SegmentedDisplayWidget};
+use egui_extras_xt::render_to_shapes;
+
+// ----------------------------------------------------------------------------
+
+const ACTIVE_COLOR: Color32 = Color32::from_rgb(0x12, 0x34, 0x56);
+
+fn flatten(shapes: Vec<Shape>) -> Vec<Shape> {
+    shapes
+        .into_iter()
+        .flat_map(|shape| match shape {
+            Shape::Vec(shapes) => flatten(shapes),
+            shape => vec![shape],
+        })
+        .collect()
+}
+
+/// Bounding rects of the lit segments of a single digit.
+fn lit_segments(display_kind: DisplayKind, c: char) -> Vec<Rect> {
+    let display_style = DisplayStyle {
+        background_color: Color32::BLACK,
+        active_foreground_color: ACTIVE_COLOR,
+        active_foreground_stroke: Stroke::NONE,
+        inactive_foreground_color: Color32::DARK_GRAY,
+        inactive_foreground_stroke: Stroke::NONE,
+        brightness: 1.0,
+        flicker: None,
+    };
+
+    let shapes = flatten(render_to_shapes(
+        |ui| {
+            ui.add(
+                SegmentedDisplayWidget::new(display_kind)
+                    .push_string(c.to_string())
+                    .style(display_style)
+                    .show_dots(false)
+                    .show_colons(false)
+                    .show_apostrophes(false),
+            );
+        },
+        Rect::from_min_max(pos2(0.0, 0.0), pos2(256.0, 256.0)),
+        Style::default(),
+    ));
+
+    shapes
+        .iter()
+        .filter_map(|shape| match shape {
+            Shape::Path(PathShape { points, fill, .. }) if *fill == ACTIVE_COLOR => {
+                Some(Rect::from_points(points))
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+#[test]
+fn eight_segment_minus_lights_both_middle_halves() {
+    let [middle_left, middle_right] = lit_segments(DisplayKind::EightSegment, '-')[..] else {
+        panic!("'-' doesn't light exactly two segments");
+    };
+
+    assert!(
+        middle_left.max.x <= middle_right.min.x,
+        "the halves of the middle bar overlap: {middle_left:?} {middle_right:?}"
+    );
+    assert!(
+        (middle_left.center().y - middle_right.center().y).abs() < 0.5,
+        "the halves of the middle bar aren't level: {middle_left:?} {middle_right:?}"
+    );
+}
+
+#[test]
+fn eight_segment_lights_every_segment_for_eight() {
+    assert_eq!(lit_segments(DisplayKind::EightSegment, '8').len(), 8);
+}
+
+#[test]
+fn seven_segment_minus_lights_single_middle_bar() {
+    assert_eq!(lit_segments(DisplayKind::SevenSegment, '-').len(), 1);
+}
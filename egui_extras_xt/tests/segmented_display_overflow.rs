@@ -0,0 +1,89 @@
+//! Headless check of the overflow marker of `SegmentedDisplayWidget`.
+
+use egui::{pos2, Rect, Style};
+use epaint::Shape;
+
+use egui_extras_xt::displays::{DisplayAlignment, SegmentedDisplayWidget};
+use egui_extras_xt::render_to_shapes;
+
+// ----------------------------------------------------------------------------
+
+const DIGIT_COUNT: usize = 4;
+
+fn flatten(shapes: Vec<Shape>) -> Vec<Shape> {
+    shapes
+        .into_iter()
+        .flat_map(|shape| match shape {
+            Shape::Vec(shapes) => flatten(shapes),
+            shape => vec![shape],
+        })
+        .collect()
+}
+
+fn render(
+    value: &str,
+    alignment: DisplayAlignment,
+    overflow_character: Option<char>,
+) -> (Rect, Vec<Shape>) {
+    let mut display_rect = Rect::NOTHING;
+
+    let shapes = flatten(render_to_shapes(
+        |ui| {
+            display_rect = ui
+                .add(
+                    SegmentedDisplayWidget::seven_segment(value)
+                        .digit_count(DIGIT_COUNT)
+                        .alignment(alignment)
+                        .overflow_character(overflow_character),
+                )
+                .rect;
+        },
+        Rect::from_min_max(pos2(0.0, 0.0), pos2(512.0, 256.0)),
+        Style::default(),
+    ));
+
+    (display_rect, shapes)
+}
+
+#[test]
+fn segmented_display_marks_overflow_in_leftmost_digit() {
+    for alignment in [DisplayAlignment::Left, DisplayAlignment::Right] {
+        let (display_rect, marked_shapes) = render("123456", alignment, Some('E'));
+        let (_, unmarked_shapes) = render("123456", alignment, None);
+
+        assert_eq!(marked_shapes.len(), unmarked_shapes.len());
+
+        let changed_shapes = marked_shapes
+            .iter()
+            .zip(&unmarked_shapes)
+            .filter(|(marked_shape, unmarked_shape)| marked_shape != unmarked_shape)
+            .map(|(marked_shape, _)| marked_shape)
+            .collect::<Vec<_>>();
+
+        assert!(
+            !changed_shapes.is_empty(),
+            "{alignment}: the overflow wasn't marked"
+        );
+
+        let leftmost_digit_right = display_rect.left() + display_rect.width() / DIGIT_COUNT as f32;
+        for shape in changed_shapes {
+            let shape_rect = shape.visual_bounding_rect();
+            assert!(
+                shape_rect.center().x < leftmost_digit_right,
+                "{alignment}: the overflow was marked at {shape_rect:?}, \
+                 outside of the leftmost digit"
+            );
+        }
+    }
+}
+
+#[test]
+fn segmented_display_doesnt_mark_fitting_strings() {
+    for alignment in [DisplayAlignment::Left, DisplayAlignment::Right] {
+        assert_eq!(
+            render("1234", alignment, Some('E')).1,
+            render("1234", alignment, None).1,
+            "{alignment}: a string that fits was marked as overflowing"
+        );
+    }
+}
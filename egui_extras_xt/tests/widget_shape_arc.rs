@@ -0,0 +1,138 @@
+//! Headless visual check of the `AudioKnob` track painted along non-circular widget shapes.
+//!
+//! The outer edge of the track has to lie on the shape boundary, and its inner edge has to
+//! be inset from the boundary by `thickness * radius`, so thick tracks keep an even width
+//! along the corners of the shape. A thickness of 1.0 paints a pie meeting in the center.
+
+use std::f32::consts::TAU;
+
+use egui::{pos2, Pos2, Rect, Style};
+use epaint::{PathShape, Shape};
+
+use egui_extras_xt::common::WidgetShape;
+use egui_extras_xt::knobs::AudioKnob;
+use egui_extras_xt::render_to_shapes;
+
+// ----------------------------------------------------------------------------
+
+const DIAMETER: f32 = 64.0;
+
+/// Angle of `Orientation::Top`, the default orientation of the knob.
+const ORIENTATION_ANGLE: f32 = TAU * 0.75;
+
+/// Offset of the inner edge used by `paint_arc` to avoid degenerate polygons.
+const CENTER_EPSILON: f32 = 0.2;
+
+const TOLERANCE: f32 = 0.01;
+
+fn flatten(shapes: Vec<Shape>) -> Vec<Shape> {
+    shapes
+        .into_iter()
+        .flat_map(|shape| match shape {
+            Shape::Vec(shapes) => flatten(shapes),
+            shape => vec![shape],
+        })
+        .collect()
+}
+
+fn squircle(theta: f32, factor: f32) -> f32 {
+    (theta.cos().abs().powf(factor) + theta.sin().abs().powf(factor)).powf(-1.0 / factor)
+}
+
+fn square(theta: f32) -> f32 {
+    (1.0 / theta.cos().abs()).min(1.0 / theta.sin().abs())
+}
+
+/// Paints the knob and returns its center along with the `(outer, inner)` point pairs
+/// of the track, which is filled with quads of `[outer_1, inner_1, inner_2, outer_2]`.
+fn track_edges(widget_shape: &WidgetShape, thickness: f32) -> (Pos2, Vec<(Pos2, Pos2)>) {
+    let style = Style::default();
+    let track_fill = style.visuals.faint_bg_color;
+
+    let mut knob_rect = Rect::NOTHING;
+
+    let painted_shapes = flatten(render_to_shapes(
+        |ui| {
+            // The value arc is empty at the start of the range
+            let mut value = 0.0;
+            knob_rect = ui
+                .add(
+                    AudioKnob::new(&mut value)
+                        .diameter(DIAMETER)
+                        .thickness(thickness)
+                        .shape(widget_shape.clone()),
+                )
+                .rect;
+        },
+        Rect::from_min_max(pos2(0.0, 0.0), pos2(256.0, 256.0)),
+        style,
+    ));
+
+    let edges = painted_shapes
+        .iter()
+        .filter_map(|shape| match shape {
+            Shape::Path(PathShape {
+                points,
+                closed: true,
+                fill,
+                ..
+            }) if points.len() == 4 && *fill == track_fill => Some(points.clone()),
+            _ => None,
+        })
+        .flat_map(|points| [(points[0], points[1]), (points[3], points[2])])
+        .collect::<Vec<_>>();
+
+    (knob_rect.center(), edges)
+}
+
+#[test]
+fn audio_knob_track_follows_widget_shape() {
+    let shapes: [(WidgetShape, &dyn Fn(f32) -> f32); 2] = [
+        (WidgetShape::Squircle(4.0), &|theta| squircle(theta, 4.0)),
+        (
+            WidgetShape::Rotated(Box::new(WidgetShape::Square), TAU / 8.0),
+            &|theta| square(theta - TAU / 8.0),
+        ),
+    ];
+
+    let radius = DIAMETER / 2.0;
+
+    for (widget_shape, shape_eval) in shapes {
+        for thickness in [0.3, 1.0] {
+            let (center, edges) = track_edges(&widget_shape, thickness);
+
+            assert!(
+                !edges.is_empty(),
+                "{widget_shape} at thickness {thickness}: no track painted"
+            );
+
+            for (outer, inner) in edges {
+                let angle = (outer - center).angle();
+                let boundary_radius = radius * shape_eval(angle - ORIENTATION_ANGLE);
+
+                let outer_radius = (outer - center).length();
+                assert!(
+                    (outer_radius - boundary_radius).abs() < TOLERANCE,
+                    "{widget_shape} at thickness {thickness}: outer edge at {outer_radius} \
+                     is off the boundary at {boundary_radius} ({angle} rad)"
+                );
+
+                let inner_radius = (inner - center).length();
+                if thickness < 1.0 {
+                    let inset_radius = boundary_radius - thickness * radius;
+                    assert!(
+                        (inner_radius - inset_radius).abs() < TOLERANCE,
+                        "{widget_shape} at thickness {thickness}: inner edge at {inner_radius} \
+                         is not inset to {inset_radius} ({angle} rad)"
+                    );
+                } else {
+                    assert!(
+                        inner_radius < CENTER_EPSILON,
+                        "{widget_shape} at thickness {thickness}: inner edge at {inner_radius} \
+                         doesn't meet the center ({angle} rad)"
+                    );
+                }
+            }
+        }
+    }
+}
@@ -1,20 +1,25 @@
 use eframe::egui;
 use eframe::emath::vec2;
 
-use egui_extras_xt::show_about_window;
+use egui_extras_xt::package_info;
+use egui_extras_xt::ui::about_window::AboutWindow;
 
 #[derive(Default)]
 struct AboutWindowExample {
     about_window_open: bool,
+    mirrored: bool,
 }
 
 impl eframe::App for AboutWindowExample {
     fn update(&mut self, ctx: &egui::Context, _frame: &mut eframe::Frame) {
         egui::CentralPanel::default().show(ctx, |ui| {
             ui.toggle_value(&mut self.about_window_open, "About");
+            ui.checkbox(&mut self.mirrored, "Mirrored");
         });
 
-        show_about_window!(ctx, &mut self.about_window_open);
+        AboutWindow::new(package_info!())
+            .mirrored(self.mirrored)
+            .show(ctx, &mut self.about_window_open);
     }
 }
 
@@ -1,7 +1,9 @@
 use eframe::egui::{self, global_dark_light_mode_switch};
 use eframe::emath::vec2;
 
-use egui_extras_xt::compasses::{CompassMarker, CompassMarkerShape, LinearCompass, PolarCompass};
+use egui_extras_xt::compasses::{
+    CompassMarker, CompassMarkerShape, LinearCompass, PolarCompass, PolarCompassLabelVisibility,
+};
 use egui_extras_xt::ui::standard_buttons::StandardButtons;
 
 struct GpsPosition(f32, f32);
@@ -103,7 +105,7 @@ impl eframe::App for CompassWidgetsExample {
                     .axis_labels(["N", "E", "S", "W"].into())
                     .markers(&markers)
                     .diameter(512.0)
-                    .show_marker_labels(true)
+                    .show_marker_labels(PolarCompassLabelVisibility::Always)
                     .show_marker_lines(true)
                     .default_marker_shape(CompassMarkerShape::Star(5, 0.5))
                     .max_distance(1000.0),
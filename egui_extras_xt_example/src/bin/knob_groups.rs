@@ -0,0 +1,79 @@
+use eframe::egui::{self, Ui};
+use eframe::emath::vec2;
+
+use egui_extras_xt::knobs::{AudioKnob, GroupMode};
+use egui_extras_xt::ui::widgets_from_iter::SelectableValueFromIter;
+use strum::IntoEnumIterator;
+
+struct KnobGroupsApp {
+    left_volume: f32,
+    right_volume: f32,
+    linked: bool,
+    group_mode: GroupMode,
+}
+
+impl Default for KnobGroupsApp {
+    fn default() -> Self {
+        Self {
+            left_volume: 0.5,
+            right_volume: 0.5,
+            linked: true,
+            group_mode: GroupMode::RelativeDelta,
+        }
+    }
+}
+
+impl KnobGroupsApp {
+    fn channel_ui(ui: &mut Ui, label: &str, value: &mut f32, group: Option<GroupMode>) {
+        ui.vertical(|ui| {
+            let mut knob = AudioKnob::new(value)
+                .diameter(48.0)
+                .allow_value_drag(true)
+                .accept_value_drop(true);
+
+            if let Some(group_mode) = group {
+                knob = knob.group("stereo_volume", group_mode);
+            }
+
+            ui.add(knob);
+            ui.label(label);
+        });
+    }
+}
+
+impl eframe::App for KnobGroupsApp {
+    fn update(&mut self, ctx: &egui::Context, _frame: &mut eframe::Frame) {
+        egui::CentralPanel::default().show(ctx, |ui| {
+            ui.horizontal(|ui| {
+                ui.checkbox(&mut self.linked, "Link");
+                ui.add_enabled_ui(self.linked, |ui| {
+                    ui.selectable_value_from_iter(&mut self.group_mode, GroupMode::iter());
+                });
+            });
+
+            ui.separator();
+
+            let group = self.linked.then_some(self.group_mode);
+
+            ui.horizontal(|ui| {
+                Self::channel_ui(ui, "Left", &mut self.left_volume, group);
+                Self::channel_ui(ui, "Right", &mut self.right_volume, group);
+            });
+
+            ui.label("Alt-drag a knob onto the other one to copy its value.");
+        });
+    }
+}
+
+fn main() -> Result<(), eframe::Error> {
+    let options = eframe::NativeOptions {
+        initial_window_size: Some(vec2(320.0, 180.0)),
+        ..Default::default()
+    };
+
+    eframe::run_native(
+        "Knob Groups",
+        options,
+        Box::new(|_| Box::<KnobGroupsApp>::default()),
+    )
+}
@@ -0,0 +1,69 @@
+use eframe::egui::{self, Button, DragValue, Slider};
+use eframe::emath::vec2;
+
+use egui_extras_xt::compasses::LinearCompass;
+use egui_extras_xt::displays::{IndicatorButton, LedDisplay};
+use egui_extras_xt::knobs::{AngleKnob, AudioKnob};
+
+struct StyleAlignmentApp {
+    interact_height: f32,
+    value: f32,
+    angle: f32,
+    heading: f32,
+    enabled: bool,
+}
+
+impl Default for StyleAlignmentApp {
+    fn default() -> Self {
+        Self {
+            interact_height: 20.0,
+            value: 0.5,
+            angle: 0.0,
+            heading: 0.0,
+            enabled: true,
+        }
+    }
+}
+
+impl eframe::App for StyleAlignmentApp {
+    fn update(&mut self, ctx: &egui::Context, _frame: &mut eframe::Frame) {
+        egui::CentralPanel::default().show(ctx, |ui| {
+            ui.horizontal(|ui| {
+                ui.label("Interact height");
+                ui.add(DragValue::new(&mut self.interact_height).clamp_range(8.0..=64.0));
+            });
+
+            ui.separator();
+
+            // None of the crate widgets get explicit sizes, they all follow the theme
+            ui.spacing_mut().interact_size.y = self.interact_height;
+
+            ui.horizontal(|ui| {
+                ui.add(Button::new("Button"));
+                ui.add(Slider::new(&mut self.value, 0.0..=1.0));
+                ui.add(AudioKnob::new(&mut self.value));
+                ui.add(AngleKnob::new(&mut self.angle));
+                ui.add(LedDisplay::new(self.value));
+                ui.add(IndicatorButton::toggle(&mut self.enabled).label("ON"));
+            });
+
+            ui.horizontal(|ui| {
+                ui.add(Button::new("Button"));
+                ui.add(LinearCompass::new(&mut self.heading).width(256.0));
+            });
+        });
+    }
+}
+
+fn main() -> Result<(), eframe::Error> {
+    let options = eframe::NativeOptions {
+        initial_window_size: Some(vec2(640.0, 200.0)),
+        ..Default::default()
+    };
+
+    eframe::run_native(
+        "Style Alignment",
+        options,
+        Box::new(|_| Box::<StyleAlignmentApp>::default()),
+    )
+}
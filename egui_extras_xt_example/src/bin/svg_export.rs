@@ -0,0 +1,152 @@
+use std::fmt::Write;
+
+use eframe::egui::{pos2, Rect, Style, Visuals};
+use eframe::epaint::{Color32, Pos2, Shape, Stroke};
+
+use egui_extras_xt::compasses::{CompassMarker, PolarCompass};
+use egui_extras_xt::displays::SegmentedDisplayWidget;
+use egui_extras_xt::knobs::AngleKnob;
+use egui_extras_xt::render_to_shapes;
+
+fn svg_color(color: Color32) -> String {
+    let [r, g, b, a] = color.to_srgba_unmultiplied();
+    format!("rgba({r},{g},{b},{:.3})", a as f32 / 255.0)
+}
+
+fn svg_paint(fill: Color32, stroke: Stroke) -> String {
+    format!(
+        "fill=\"{}\" stroke=\"{}\" stroke-width=\"{}\"",
+        svg_color(fill),
+        svg_color(stroke.color),
+        stroke.width
+    )
+}
+
+fn svg_points(points: &[Pos2]) -> String {
+    points
+        .iter()
+        .map(|point| format!("{},{}", point.x, point.y))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+// Text, meshes, curves and paint callbacks are skipped, only the simple shapes are converted.
+fn write_svg_shape(svg: &mut String, shape: &Shape) {
+    match shape {
+        Shape::Vec(shapes) => {
+            for shape in shapes {
+                write_svg_shape(svg, shape);
+            }
+        }
+        Shape::Circle(circle) => {
+            writeln!(
+                svg,
+                "<circle cx=\"{}\" cy=\"{}\" r=\"{}\" {}/>",
+                circle.center.x,
+                circle.center.y,
+                circle.radius,
+                svg_paint(circle.fill, circle.stroke)
+            )
+            .unwrap();
+        }
+        Shape::LineSegment { points, stroke } => {
+            writeln!(
+                svg,
+                "<polyline points=\"{}\" {}/>",
+                svg_points(points),
+                svg_paint(Color32::TRANSPARENT, *stroke)
+            )
+            .unwrap();
+        }
+        Shape::Path(path) => {
+            writeln!(
+                svg,
+                "<{} points=\"{}\" {}/>",
+                if path.closed { "polygon" } else { "polyline" },
+                svg_points(&path.points),
+                svg_paint(path.fill, path.stroke)
+            )
+            .unwrap();
+        }
+        Shape::Rect(rect) => {
+            writeln!(
+                svg,
+                "<rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\" rx=\"{}\" {}/>",
+                rect.rect.left(),
+                rect.rect.top(),
+                rect.rect.width(),
+                rect.rect.height(),
+                rect.rounding.nw,
+                svg_paint(rect.fill, rect.stroke)
+            )
+            .unwrap();
+        }
+        _ => {}
+    }
+}
+
+fn export_svg(rect: Rect, add_contents: impl FnOnce(&mut eframe::egui::Ui)) -> String {
+    let style = Style {
+        visuals: Visuals::dark(),
+        ..Default::default()
+    };
+
+    let mut svg = String::new();
+
+    writeln!(
+        svg,
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" viewBox=\"{} {} {} {}\">",
+        rect.left(),
+        rect.top(),
+        rect.width(),
+        rect.height()
+    )
+    .unwrap();
+
+    for shape in render_to_shapes(add_contents, rect, style) {
+        write_svg_shape(&mut svg, &shape);
+    }
+
+    writeln!(svg, "</svg>").unwrap();
+    svg
+}
+
+fn main() {
+    let mut heading = 30.0f32.to_radians();
+    let mut angle = 45.0f32.to_radians();
+
+    let exports = [
+        (
+            "angle_knob.svg",
+            export_svg(Rect::from_min_max(pos2(0.0, 0.0), pos2(64.0, 64.0)), |ui| {
+                ui.add(AngleKnob::new(&mut angle).diameter(64.0));
+            }),
+        ),
+        (
+            "polar_compass.svg",
+            export_svg(
+                Rect::from_min_max(pos2(0.0, 0.0), pos2(304.0, 304.0)),
+                |ui| {
+                    ui.add(PolarCompass::new(&mut heading).markers(&[
+                        CompassMarker::new(0.0f32.to_radians()).distance(10.0),
+                        CompassMarker::new(90.0f32.to_radians()).distance(1000.0),
+                    ]));
+                },
+            ),
+        ),
+        (
+            "segmented_display.svg",
+            export_svg(
+                Rect::from_min_max(pos2(0.0, 0.0), pos2(512.0, 96.0)),
+                |ui| {
+                    ui.add(SegmentedDisplayWidget::sixteen_segment("EGUI"));
+                },
+            ),
+        ),
+    ];
+
+    for (file_name, svg) in exports {
+        std::fs::write(file_name, svg).expect("failed to write SVG file");
+        println!("Exported {file_name:}");
+    }
+}
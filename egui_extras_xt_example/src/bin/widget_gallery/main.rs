@@ -6,8 +6,8 @@ use eframe::egui::panel::Side;
 use eframe::egui::{self, TextEdit};
 use eframe::emath::vec2;
 
-use egui_extras_xt::show_about_window;
 use egui_extras_xt::ui::standard_buttons::StandardButtons;
+use egui_extras_xt::{set_xt_style, show_about_window, xt_style, XtPalette};
 
 use itertools::Itertools;
 use strum::{EnumProperty, IntoEnumIterator};
@@ -19,6 +19,7 @@ struct WidgetGallery {
     current_page: PageId,
     pages: HashMap<PageId, Box<dyn PageImpl>>,
     search_query: String,
+    show_source: bool,
 
     // Sub-windows
     settings_window_open: bool,
@@ -36,6 +37,7 @@ impl Default for WidgetGallery {
                 PageId::iter().map(|page_id| (page_id, page_id.create_page())),
             ),
             search_query: String::new(),
+            show_source: false,
 
             // Sub-windows
             settings_window_open: false,
@@ -56,6 +58,17 @@ impl eframe::App for WidgetGallery {
                     ui.checkbox(&mut self.memory_window_open, "\u{1F4DD} Memory");
                 });
 
+                ui.menu_button("Palette", |ui| {
+                    let mut xt_style = xt_style(ctx);
+                    for palette in XtPalette::iter() {
+                        ui.selectable_value(&mut xt_style.palette, palette, palette.to_string());
+                    }
+
+                    ui.separator();
+                    ui.add(egui::Slider::new(&mut xt_style.backlight, 0.0..=1.0).text("Backlight"));
+                    set_xt_style(ctx, xt_style);
+                });
+
                 if ui.button("About").clicked() {
                     self.about_window_open = true;
                 }
@@ -120,13 +133,29 @@ impl eframe::App for WidgetGallery {
             ui.separator();
 
             egui::ScrollArea::both().show(ui, |ui| {
-                self.pages
+                let page = self
+                    .pages
                     .get_mut(&self.current_page)
-                    .expect("failed to get page")
-                    .ui(ui);
+                    .expect("failed to get page");
+
+                page.ui(ui);
 
                 ui.separator();
 
+                if let Some(source) = page.source() {
+                    ui.checkbox(&mut self.show_source, "Show source");
+
+                    if self.show_source {
+                        ui.add(
+                            TextEdit::multiline(&mut source.as_str())
+                                .code_editor()
+                                .desired_width(f32::INFINITY),
+                        );
+                    }
+
+                    ui.separator();
+                }
+
                 if ui.reset_button().clicked() {
                     self.pages
                         .insert(self.current_page, self.current_page.create_page());
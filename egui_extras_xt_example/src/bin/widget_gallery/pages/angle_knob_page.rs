@@ -1,30 +1,62 @@
+use std::f32::consts::TAU;
+
 use eframe::egui::{DragValue, Grid, Ui};
+use eframe::epaint::Color32;
 use egui_extras_xt::common::{Orientation, WidgetShape, Winding, WrapMode};
-use egui_extras_xt::knobs::{AngleKnob, AngleKnobPreset};
+use egui_extras_xt::knobs::{AngleKnob, AngleKnobPreset, KnobInteraction};
 use egui_extras_xt::ui::optional_value_widget::OptionalValueWidget;
 use egui_extras_xt::ui::standard_buttons::StandardButtons;
 use egui_extras_xt::ui::widgets_from_iter::{ComboBoxFromIter, SelectableValueFromIter};
+use itertools::Itertools;
 use strum::IntoEnumIterator;
 
-use crate::pages::ui::{widget_orientation_ui, widget_shape_ui};
+use crate::pages::source::{Angle, BuilderSource, Verbatim};
+use crate::pages::ui::{easing_curve, widget_orientation_ui, widget_shape_ui};
 use crate::pages::PageImpl;
 
 pub struct AngleKnobPage {
     value: f32,
     interactive: bool,
+    long_press_duration: Option<f32>,
+    editable: bool,
+    indeterminate: bool,
+    interaction: KnobInteraction,
+    drag_sensitivity: f32,
+    lock_cursor: bool,
     diameter: f32,
     preset: AngleKnobPreset,
     orientation: Orientation,
     winding: Winding,
+    mirrored: bool,
     wrap: WrapMode,
     shape: WidgetShape,
+    glow: bool,
     min: Option<f32>,
     max: Option<f32>,
     snap: Option<f32>,
     shift_snap: Option<f32>,
+    irregular_snap: bool,
     animated: bool,
+    animation_time: f32,
+    smooth_easing: bool,
     show_axes: bool,
     axis_count: usize,
+    axis_offset: f32,
+    colored_axes: bool,
+    show_axis_labels: bool,
+    show_value: bool,
+    tick_count: usize,
+    tick_length: f32,
+    major_tick_every: Option<usize>,
+    show_range: bool,
+    show_drag_origin: bool,
+    reference_value: Option<f32>,
+    show_turns: bool,
+    spring_to: Option<f32>,
+    spring_speed: f32,
+    peak_deflection: f32,
+    detent_crossings: i32,
+    recording: Vec<(f64, f32)>,
 }
 
 impl Default for AngleKnobPage {
@@ -33,42 +65,130 @@ impl Default for AngleKnobPage {
             value: 0.0,
             preset: AngleKnobPreset::AdobePhotoshop,
             interactive: true,
+            long_press_duration: Some(0.6),
+            editable: true,
+            indeterminate: false,
+            interaction: KnobInteraction::Absolute,
+            drag_sensitivity: 0.01,
+            lock_cursor: false,
             diameter: 32.0,
             orientation: Orientation::Top,
             winding: Winding::Clockwise,
+            mirrored: false,
             wrap: WrapMode::Unsigned,
             shape: WidgetShape::Circle,
+            glow: false,
             min: None,
             max: None,
             snap: None,
             shift_snap: Some(15.0f32.to_radians()),
+            irregular_snap: false,
             animated: false,
+            animation_time: 0.1,
+            smooth_easing: false,
             show_axes: true,
             axis_count: 4,
+            axis_offset: 0.0,
+            colored_axes: false,
+            show_axis_labels: false,
+            show_value: false,
+            tick_count: 0,
+            tick_length: 0.15,
+            major_tick_every: None,
+            show_range: false,
+            show_drag_origin: true,
+            reference_value: None,
+            show_turns: false,
+            spring_to: None,
+            spring_speed: TAU * 2.0,
+            peak_deflection: 0.0,
+            detent_crossings: 0,
+            recording: Vec::new(),
+        }
+    }
+}
+
+impl AngleKnobPage {
+    fn axis_colors(&self) -> Vec<Color32> {
+        if self.colored_axes {
+            [Color32::RED, Color32::YELLOW, Color32::BLUE]
+                .into_iter()
+                .cycle()
+                .take(self.axis_count)
+                .collect()
+        } else {
+            Vec::new()
+        }
+    }
+
+    fn snap_angles(&self) -> Vec<f32> {
+        if self.irregular_snap {
+            [0.0f32, 20.0, 45.0, 90.0, 180.0, 300.0]
+                .map(f32::to_radians)
+                .to_vec()
+        } else {
+            Vec::new()
         }
     }
 }
 
 impl PageImpl for AngleKnobPage {
     fn ui(&mut self, ui: &mut Ui) {
-        ui.add(
-            AngleKnob::new(&mut self.value)
-                .interactive(self.interactive)
-                .diameter(self.diameter)
-                .orientation(self.orientation)
-                .winding(self.winding)
-                .shape(self.shape.clone())
-                .wrap(self.wrap)
-                .min(self.min)
-                .max(self.max)
-                .snap(self.snap)
-                .shift_snap(self.shift_snap)
-                .animated(self.animated)
-                .show_axes(self.show_axes)
-                .axis_count(self.axis_count),
-        );
+        let axis_colors = self.axis_colors();
+        let snap_angles = self.snap_angles();
+
+        let knob_response = AngleKnob::new(&mut self.value)
+            .interactive(self.interactive)
+            .long_press_duration(self.long_press_duration)
+            .editable(self.editable)
+            .indeterminate(self.indeterminate)
+            .interaction(self.interaction)
+            .drag_sensitivity(self.drag_sensitivity)
+            .lock_cursor(self.lock_cursor)
+            .diameter(self.diameter)
+            .orientation(self.orientation)
+            .winding(self.winding)
+            .mirrored(self.mirrored)
+            .shape(self.shape.clone())
+            .glow(self.glow)
+            .wrap(self.wrap)
+            .min(self.min)
+            .max(self.max)
+            .snap(self.snap)
+            .shift_snap(self.shift_snap)
+            .snap_angles(&snap_angles)
+            .animated(self.animated)
+            .animation_time(self.animation_time)
+            .easing(easing_curve(self.smooth_easing).0)
+            .show_axes(self.show_axes)
+            .axis_count(self.axis_count)
+            .axis_offset(self.axis_offset)
+            .axis_colors(&axis_colors)
+            .axis_labels(
+                (0..self.axis_count)
+                    .filter(|_| self.show_axis_labels)
+                    .map(|axis| format!("{}°", axis * 360 / self.axis_count)),
+            )
+            .show_value(self.show_value)
+            .tick_count(self.tick_count)
+            .tick_length(self.tick_length)
+            .major_tick_every(self.major_tick_every)
+            .show_range(self.show_range)
+            .show_drag_origin(self.show_drag_origin)
+            .reference_value(self.reference_value)
+            .show_turns(self.show_turns)
+            .on_detent(|crossings| self.detent_crossings += crossings)
+            .spring_to(self.spring_to)
+            .spring_speed(self.spring_speed)
+            .on_release(|peak_deflection| self.peak_deflection = peak_deflection)
+            .record_into(&mut self.recording)
+            .show(ui);
         ui.separator();
 
+        if knob_response.response.changed() {
+            self.indeterminate = false;
+        }
+
         Grid::new("angle_knob_properties")
             .num_columns(2)
             .spacing([20.0, 10.0])
@@ -78,10 +198,59 @@ impl PageImpl for AngleKnobPage {
                 ui.drag_angle(&mut self.value);
                 ui.end_row();
 
+                ui.label("Value in degrees");
+                ui.label(format!("{:.2}°", knob_response.value_degrees));
+                ui.end_row();
+
+                ui.label("Detents crossed");
+                ui.label(self.detent_crossings.to_string());
+                ui.end_row();
+
+                ui.label("Peak deflection");
+                ui.label(format!("{:.2}°", self.peak_deflection.to_degrees()));
+                ui.end_row();
+
+                ui.label("Recorded samples");
+                ui.horizontal(|ui| {
+                    ui.label(self.recording.len().to_string());
+                    if ui.delete_button().clicked() {
+                        self.recording.clear();
+                    }
+                });
+                ui.end_row();
+
                 ui.label("Interactive");
                 ui.checkbox(&mut self.interactive, "");
                 ui.end_row();
 
+                ui.label("Long press duration");
+                ui.optional_value_widget(&mut self.long_press_duration, |ui, value| {
+                    ui.add(DragValue::new(value).speed(0.01).suffix(" s"))
+                });
+                ui.end_row();
+
+                ui.label("Editable");
+                ui.checkbox(&mut self.editable, "");
+                ui.end_row();
+
+                ui.label("Indeterminate");
+                ui.checkbox(&mut self.indeterminate, "");
+                ui.end_row();
+
+                ui.label("Interaction");
+                ui.horizontal(|ui| {
+                    ui.selectable_value_from_iter(&mut self.interaction, KnobInteraction::iter());
+                });
+                ui.end_row();
+
+                ui.label("Drag sensitivity");
+                ui.add(DragValue::new(&mut self.drag_sensitivity).speed(0.001));
+                ui.end_row();
+
+                ui.label("Lock cursor");
+                ui.checkbox(&mut self.lock_cursor, "");
+                ui.end_row();
+
                 ui.label("Diameter");
                 ui.add(DragValue::new(&mut self.diameter));
                 ui.end_row();
@@ -111,6 +280,10 @@ impl PageImpl for AngleKnobPage {
                 });
                 ui.end_row();
 
+                ui.label("Mirrored");
+                ui.checkbox(&mut self.mirrored, "");
+                ui.end_row();
+
                 ui.label("Wrap");
                 ui.horizontal(|ui| {
                     ui.selectable_value_from_iter(&mut self.wrap, WrapMode::iter());
@@ -121,6 +294,10 @@ impl PageImpl for AngleKnobPage {
                 widget_shape_ui(ui, &mut self.shape);
                 ui.end_row();
 
+                ui.label("Glow");
+                ui.checkbox(&mut self.glow, "");
+                ui.end_row();
+
                 ui.label("Minimum");
                 ui.optional_value_widget(&mut self.min, Ui::drag_angle);
                 ui.end_row();
@@ -129,6 +306,10 @@ impl PageImpl for AngleKnobPage {
                 ui.optional_value_widget(&mut self.max, Ui::drag_angle);
                 ui.end_row();
 
+                ui.label("Show range");
+                ui.checkbox(&mut self.show_range, "");
+                ui.end_row();
+
                 ui.label("Snap");
                 ui.optional_value_widget(&mut self.snap, Ui::drag_angle);
                 ui.end_row();
@@ -137,10 +318,26 @@ impl PageImpl for AngleKnobPage {
                 ui.optional_value_widget(&mut self.shift_snap, Ui::drag_angle);
                 ui.end_row();
 
+                ui.label("Irregular snap");
+                ui.checkbox(&mut self.irregular_snap, "");
+                ui.end_row();
+
                 ui.label("Animated");
                 ui.checkbox(&mut self.animated, "");
                 ui.end_row();
 
+                ui.label("Animation time");
+                ui.add(
+                    DragValue::new(&mut self.animation_time)
+                        .speed(0.01)
+                        .suffix(" s"),
+                );
+                ui.end_row();
+
+                ui.label("Smooth easing");
+                ui.checkbox(&mut self.smooth_easing, "");
+                ui.end_row();
+
                 ui.label("Show axes");
                 ui.checkbox(&mut self.show_axes, "");
                 ui.end_row();
@@ -148,6 +345,118 @@ impl PageImpl for AngleKnobPage {
                 ui.label("Axis count");
                 ui.add(DragValue::new(&mut self.axis_count));
                 ui.end_row();
+
+                ui.label("Axis offset");
+                ui.drag_angle(&mut self.axis_offset);
+                ui.end_row();
+
+                ui.label("Colored axes");
+                ui.checkbox(&mut self.colored_axes, "");
+                ui.end_row();
+
+                ui.label("Show axis labels");
+                ui.checkbox(&mut self.show_axis_labels, "");
+                ui.end_row();
+
+                ui.label("Show value");
+                ui.checkbox(&mut self.show_value, "");
+                ui.end_row();
+
+                ui.label("Show drag origin");
+                ui.checkbox(&mut self.show_drag_origin, "");
+                ui.end_row();
+
+                ui.label("Reference value");
+                ui.optional_value_widget(&mut self.reference_value, Ui::drag_angle);
+                ui.end_row();
+
+                ui.label("Show turns");
+                ui.checkbox(&mut self.show_turns, "");
+                ui.end_row();
+
+                ui.label("Spring to");
+                ui.optional_value_widget(&mut self.spring_to, Ui::drag_angle);
+                ui.end_row();
+
+                ui.label("Spring speed");
+                ui.drag_angle(&mut self.spring_speed);
+                ui.end_row();
+
+                ui.label("Tick count");
+                ui.add(DragValue::new(&mut self.tick_count));
+                ui.end_row();
+
+                ui.label("Tick length");
+                ui.add(DragValue::new(&mut self.tick_length).speed(0.01));
+                ui.end_row();
+
+                ui.label("Major tick every");
+                ui.optional_value_widget(&mut self.major_tick_every, |ui, value| {
+                    ui.add(DragValue::new(value).clamp_range(1..=usize::MAX))
+                });
+                ui.end_row();
             });
     }
+
+    fn source(&self) -> Option<String> {
+        let mut source = BuilderSource::new("AngleKnob::new(&mut value)")
+            .call("interactive", self.interactive)
+            .call("long_press_duration", self.long_press_duration)
+            .call("editable", self.editable)
+            .call("indeterminate", self.indeterminate)
+            .call("interaction", self.interaction)
+            .call("drag_sensitivity", self.drag_sensitivity)
+            .call("lock_cursor", self.lock_cursor)
+            .call("diameter", self.diameter)
+            .call("orientation", self.orientation)
+            .call("winding", self.winding)
+            .call("mirrored", self.mirrored)
+            .call("shape", &self.shape)
+            .call("glow", self.glow)
+            .call("wrap", self.wrap)
+            .call("min", self.min.map(Angle))
+            .call("max", self.max.map(Angle))
+            .call("snap", self.snap.map(Angle))
+            .call("shift_snap", self.shift_snap.map(Angle))
+            .call("animated", self.animated)
+            .call("animation_time", self.animation_time)
+            .call("easing", Verbatim(easing_curve(self.smooth_easing).1))
+            .call("show_axes", self.show_axes)
+            .call("axis_count", self.axis_count)
+            .call("axis_offset", Angle(self.axis_offset));
+
+        if self.irregular_snap {
+            source = source.call(
+                "snap_angles",
+                &self.snap_angles().into_iter().map(Angle).collect_vec()[..],
+            );
+        }
+
+        if self.colored_axes {
+            source = source.call("axis_colors", &self.axis_colors()[..]);
+        }
+
+        if self.show_axis_labels {
+            source = source.call(
+                "axis_labels",
+                (0..self.axis_count)
+                    .map(|axis| format!("{}°", axis * 360 / self.axis_count))
+                    .collect_vec(),
+            );
+        }
+
+        source = source
+            .call("show_value", self.show_value)
+            .call("tick_count", self.tick_count)
+            .call("tick_length", self.tick_length)
+            .call("major_tick_every", self.major_tick_every)
+            .call("show_range", self.show_range)
+            .call("show_drag_origin", self.show_drag_origin)
+            .call("reference_value", self.reference_value.map(Angle))
+            .call("show_turns", self.show_turns)
+            .call("spring_to", self.spring_to.map(Angle))
+            .call("spring_speed", Angle(self.spring_speed));
+
+        Some(source.build())
+    }
 }
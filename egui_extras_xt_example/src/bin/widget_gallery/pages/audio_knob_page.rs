@@ -1,30 +1,61 @@
 use std::ops::RangeInclusive;
 
 use eframe::egui::{DragValue, Grid, Ui};
+use eframe::epaint::Color32;
 use egui_extras_xt::common::{Orientation, WidgetShape, Winding};
-use egui_extras_xt::knobs::AudioKnob;
+use egui_extras_xt::knobs::{AudioKnob, DragAxis, KnobIndicator, Taper};
 use egui_extras_xt::ui::drag_rangeinclusive::DragRangeInclusive;
 use egui_extras_xt::ui::optional_value_widget::OptionalValueWidget;
 use egui_extras_xt::ui::widgets_from_iter::SelectableValueFromIter;
 use strum::IntoEnumIterator;
 
-use crate::pages::ui::{widget_orientation_ui, widget_shape_ui};
+use crate::pages::source::{Angle, BuilderSource, ToSource, Verbatim};
+use crate::pages::ui::{
+    easing_curve, level_meter_gradient, taper_ui, widget_orientation_ui, widget_shape_ui,
+};
 use crate::pages::PageImpl;
 
 pub struct AudioKnobPage {
     value: f32,
     interactive: bool,
+    long_press_duration: Option<f32>,
+    editable: bool,
+    indeterminate: bool,
     diameter: f32,
+    drag_axis: DragAxis,
     drag_length: f32,
+    lock_cursor: bool,
     winding: Winding,
+    mirrored: bool,
     orientation: Orientation,
     range: RangeInclusive<f32>,
-    spread: f32,
+    taper: Taper,
+    arc_degrees: f32,
+    start_angle: Option<f32>,
     thickness: f32,
+    bipolar_colors: bool,
+    level_meter_gradient: bool,
+    positive_color: Color32,
+    negative_color: Color32,
     shape: WidgetShape,
+    glow: bool,
+    marker: Option<f32>,
+    ticks: usize,
+    reference_value: Option<f32>,
+    measured_value: Option<f32>,
+    indicator: KnobIndicator,
     animated: bool,
+    animation_time: f32,
+    smooth_easing: bool,
     snap: Option<f32>,
     shift_snap: Option<f32>,
+    center_detent: Option<f32>,
+    show_tooltip: bool,
+    display_percentage: bool,
+    detent_crossings: i32,
+    spring_to: Option<f32>,
+    spring_speed: f32,
+    peak_deflection: f32,
 }
 
 impl Default for AudioKnobPage {
@@ -32,38 +63,101 @@ impl Default for AudioKnobPage {
         AudioKnobPage {
             value: 0.0,
             interactive: true,
+            long_press_duration: Some(0.6),
+            editable: true,
+            indeterminate: false,
             diameter: 32.0,
-            drag_length: 1.0,
+            drag_axis: DragAxis::Both,
+            drag_length: 200.0,
+            lock_cursor: false,
             orientation: Orientation::Top,
             winding: Winding::Clockwise,
+            mirrored: false,
             range: 0.0..=1.0,
-            spread: 1.0,
+            taper: Taper::Linear,
+            arc_degrees: 360.0,
+            start_angle: None,
             thickness: 0.66,
+            bipolar_colors: false,
+            level_meter_gradient: false,
+            positive_color: Color32::from_rgb(0x56, 0xB4, 0xE9),
+            negative_color: Color32::from_rgb(0xE6, 0x9F, 0x00),
             shape: WidgetShape::Squircle(4.0),
+            glow: false,
+            marker: Some(0.5),
+            ticks: 0,
+            reference_value: None,
+            measured_value: None,
+            indicator: KnobIndicator::Arc,
             animated: true,
+            animation_time: 0.1,
+            smooth_easing: false,
             snap: None,
             shift_snap: None,
+            center_detent: None,
+            show_tooltip: true,
+            display_percentage: false,
+            detent_crossings: 0,
+            spring_to: None,
+            spring_speed: 4.0,
+            peak_deflection: 0.0,
         }
     }
 }
 
 impl PageImpl for AudioKnobPage {
     fn ui(&mut self, ui: &mut Ui) {
-        ui.add(
-            AudioKnob::new(&mut self.value)
-                .interactive(self.interactive)
-                .diameter(self.diameter)
-                .drag_length(self.drag_length)
-                .orientation(self.orientation)
-                .winding(self.winding)
-                .range(self.range.clone())
-                .spread(self.spread)
-                .thickness(self.thickness)
-                .shape(self.shape.clone())
-                .animated(self.animated)
-                .snap(self.snap)
-                .shift_snap(self.shift_snap),
-        );
+        let mut audio_knob = AudioKnob::new(&mut self.value)
+            .interactive(self.interactive)
+            .long_press_duration(self.long_press_duration)
+            .editable(self.editable)
+            .indeterminate(self.indeterminate)
+            .diameter(self.diameter)
+            .drag_axis(self.drag_axis)
+            .drag_length(self.drag_length)
+            .lock_cursor(self.lock_cursor)
+            .orientation(self.orientation)
+            .winding(self.winding)
+            .mirrored(self.mirrored)
+            .range(self.range.clone())
+            .taper(self.taper)
+            .arc_degrees(self.arc_degrees)
+            .start_angle(self.start_angle)
+            .thickness(self.thickness)
+            .shape(self.shape.clone())
+            .glow(self.glow)
+            .indicator(self.indicator)
+            .animated(self.animated)
+            .animation_time(self.animation_time)
+            .easing(easing_curve(self.smooth_easing).0)
+            .snap(self.snap)
+            .shift_snap(self.shift_snap)
+            .center_detent(self.center_detent)
+            .show_tooltip(self.show_tooltip)
+            .display_range(self.display_percentage.then_some(0.0..=100.0))
+            .on_detent(|crossings| self.detent_crossings += crossings)
+            .spring_to(self.spring_to)
+            .spring_speed(self.spring_speed)
+            .on_release(|peak_deflection| self.peak_deflection = peak_deflection)
+            .reference_value(self.reference_value)
+            .measured_value(self.measured_value)
+            .ticks(self.ticks);
+
+        if self.bipolar_colors {
+            audio_knob = audio_knob.fill_colors(self.positive_color, self.negative_color);
+        }
+
+        if self.level_meter_gradient {
+            audio_knob = audio_knob.fill_gradient(level_meter_gradient().0);
+        }
+
+        if let Some(marker) = self.marker {
+            audio_knob = audio_knob.marker_at(marker);
+        }
+
+        if ui.add(audio_knob).changed() {
+            self.indeterminate = false;
+        }
         ui.separator();
 
         Grid::new("audio_knob_properties")
@@ -75,24 +169,60 @@ impl PageImpl for AudioKnobPage {
                 ui.add(DragValue::new(&mut self.value));
                 ui.end_row();
 
+                ui.label("Detents crossed");
+                ui.label(self.detent_crossings.to_string());
+                ui.end_row();
+
+                ui.label("Peak deflection");
+                ui.label(format!("{:.2}", self.peak_deflection));
+                ui.end_row();
+
                 ui.label("Interactive");
                 ui.checkbox(&mut self.interactive, "");
                 ui.end_row();
 
+                ui.label("Long press duration");
+                ui.optional_value_widget(&mut self.long_press_duration, |ui, value| {
+                    ui.add(DragValue::new(value).speed(0.01).suffix(" s"))
+                });
+                ui.end_row();
+
+                ui.label("Editable");
+                ui.checkbox(&mut self.editable, "");
+                ui.end_row();
+
+                ui.label("Indeterminate");
+                ui.checkbox(&mut self.indeterminate, "");
+                ui.end_row();
+
                 ui.label("Diameter");
                 ui.add(DragValue::new(&mut self.diameter));
                 ui.end_row();
 
+                ui.label("Drag axis");
+                ui.horizontal(|ui| {
+                    ui.selectable_value_from_iter(&mut self.drag_axis, DragAxis::iter());
+                });
+                ui.end_row();
+
                 ui.label("Drag length");
                 ui.add(DragValue::new(&mut self.drag_length));
                 ui.end_row();
 
+                ui.label("Lock cursor");
+                ui.checkbox(&mut self.lock_cursor, "");
+                ui.end_row();
+
                 ui.label("Winding");
                 ui.horizontal(|ui| {
                     ui.selectable_value_from_iter(&mut self.winding, Winding::iter());
                 });
                 ui.end_row();
 
+                ui.label("Mirrored");
+                ui.checkbox(&mut self.mirrored, "");
+                ui.end_row();
+
                 ui.label("Orientation");
                 widget_orientation_ui(ui, &mut self.orientation);
                 ui.end_row();
@@ -101,22 +231,92 @@ impl PageImpl for AudioKnobPage {
                 ui.drag_rangeinclusive(&mut self.range);
                 ui.end_row();
 
-                ui.label("Spread");
-                ui.add(DragValue::new(&mut self.spread));
+                ui.label("Taper");
+                taper_ui(ui, &mut self.taper);
+                ui.end_row();
+
+                ui.label("Arc");
+                ui.add(
+                    DragValue::new(&mut self.arc_degrees)
+                        .clamp_range(0.0..=360.0)
+                        .suffix("°"),
+                );
+                ui.end_row();
+
+                ui.label("Start angle");
+                ui.optional_value_widget(&mut self.start_angle, Ui::drag_angle);
                 ui.end_row();
 
                 ui.label("Thickness");
                 ui.add(DragValue::new(&mut self.thickness));
                 ui.end_row();
 
+                ui.label("Fill colors");
+                ui.horizontal(|ui| {
+                    ui.checkbox(&mut self.bipolar_colors, "");
+                    ui.add_enabled_ui(self.bipolar_colors, |ui| {
+                        ui.color_edit_button_srgba(&mut self.positive_color);
+                        ui.color_edit_button_srgba(&mut self.negative_color);
+                    });
+                });
+                ui.end_row();
+
+                ui.label("Fill gradient");
+                ui.checkbox(&mut self.level_meter_gradient, "");
+                ui.end_row();
+
                 ui.label("Shape");
                 widget_shape_ui(ui, &mut self.shape);
                 ui.end_row();
 
+                ui.label("Glow");
+                ui.checkbox(&mut self.glow, "");
+                ui.end_row();
+
+                ui.label("Marker");
+                ui.optional_value_widget(&mut self.marker, |ui, value| {
+                    ui.add(DragValue::new(value).speed(0.01))
+                });
+                ui.end_row();
+
+                ui.label("Ticks");
+                ui.add(DragValue::new(&mut self.ticks).clamp_range(0..=64));
+                ui.end_row();
+
+                ui.label("Reference value");
+                ui.optional_value_widget(&mut self.reference_value, |ui, value| {
+                    ui.add(DragValue::new(value).speed(0.01))
+                });
+                ui.end_row();
+
+                ui.label("Measured value");
+                ui.optional_value_widget(&mut self.measured_value, |ui, value| {
+                    ui.add(DragValue::new(value).speed(0.01))
+                });
+                ui.end_row();
+
+                ui.label("Indicator");
+                ui.horizontal(|ui| {
+                    ui.selectable_value_from_iter(&mut self.indicator, KnobIndicator::iter());
+                });
+                ui.end_row();
+
                 ui.label("Animated");
                 ui.checkbox(&mut self.animated, "");
                 ui.end_row();
 
+                ui.label("Animation time");
+                ui.add(
+                    DragValue::new(&mut self.animation_time)
+                        .speed(0.01)
+                        .suffix(" s"),
+                );
+                ui.end_row();
+
+                ui.label("Smooth easing");
+                ui.checkbox(&mut self.smooth_easing, "");
+                ui.end_row();
+
                 ui.label("Snap");
                 ui.optional_value_widget(&mut self.snap, |ui, value| ui.add(DragValue::new(value)));
                 ui.end_row();
@@ -126,6 +326,94 @@ impl PageImpl for AudioKnobPage {
                     ui.add(DragValue::new(value))
                 });
                 ui.end_row();
+
+                ui.label("Center detent");
+                ui.optional_value_widget(&mut self.center_detent, |ui, value| {
+                    ui.add(DragValue::new(value).speed(0.01))
+                });
+                ui.end_row();
+
+                ui.label("Show tooltip");
+                ui.checkbox(&mut self.show_tooltip, "");
+                ui.end_row();
+
+                ui.label("Display as percentage");
+                ui.checkbox(&mut self.display_percentage, "");
+                ui.end_row();
+
+                ui.label("Spring to");
+                ui.optional_value_widget(&mut self.spring_to, |ui, value| {
+                    ui.add(DragValue::new(value).speed(0.01))
+                });
+                ui.end_row();
+
+                ui.label("Spring speed");
+                ui.add(DragValue::new(&mut self.spring_speed).speed(0.1));
+                ui.end_row();
             });
     }
+
+    fn source(&self) -> Option<String> {
+        let mut source = BuilderSource::new("AudioKnob::new(&mut value)")
+            .call("interactive", self.interactive)
+            .call("long_press_duration", self.long_press_duration)
+            .call("editable", self.editable)
+            .call("indeterminate", self.indeterminate)
+            .call("diameter", self.diameter)
+            .call("drag_axis", self.drag_axis)
+            .call("drag_length", self.drag_length)
+            .call("lock_cursor", self.lock_cursor)
+            .call("orientation", self.orientation)
+            .call("winding", self.winding)
+            .call("mirrored", self.mirrored)
+            .call("range", self.range.clone())
+            .call("taper", self.taper)
+            .call("arc_degrees", self.arc_degrees)
+            .call("start_angle", self.start_angle.map(Angle))
+            .call("thickness", self.thickness);
+
+        if self.bipolar_colors {
+            source = source.call(
+                "fill_colors",
+                Verbatim(&format!(
+                    "{}, {}",
+                    self.positive_color.to_source(),
+                    self.negative_color.to_source()
+                )),
+            );
+        }
+
+        if self.level_meter_gradient {
+            source = source.call("fill_gradient", Verbatim(level_meter_gradient().1));
+        }
+
+        source = source.call("shape", &self.shape).call("glow", self.glow);
+
+        if let Some(marker) = self.marker {
+            source = source.call("marker_at", marker);
+        }
+
+        source = source
+            .call("ticks", self.ticks)
+            .call("reference_value", self.reference_value)
+            .call("measured_value", self.measured_value);
+
+        source = source
+            .call("indicator", self.indicator)
+            .call("animated", self.animated)
+            .call("animation_time", self.animation_time)
+            .call("easing", Verbatim(easing_curve(self.smooth_easing).1))
+            .call("snap", self.snap)
+            .call("shift_snap", self.shift_snap)
+            .call("center_detent", self.center_detent)
+            .call("show_tooltip", self.show_tooltip)
+            .call(
+                "display_range",
+                self.display_percentage.then_some(0.0f32..=100.0),
+            )
+            .call("spring_to", self.spring_to)
+            .call("spring_speed", self.spring_speed);
+
+        Some(source.build())
+    }
 }
@@ -4,6 +4,7 @@ use egui_extras_xt::barcodes::{BarcodeKind, BarcodeWidget};
 use egui_extras_xt::ui::widgets_from_iter::ComboBoxFromIter;
 use strum::IntoEnumIterator;
 
+use crate::pages::source::{BuilderSource, ToSource};
 use crate::pages::PageImpl;
 
 pub struct BarcodePage {
@@ -105,4 +106,20 @@ impl PageImpl for BarcodePage {
                 ui.end_row();
             });
     }
+
+    fn source(&self) -> Option<String> {
+        let source = BuilderSource::new(&format!("BarcodeWidget::new({})", self.value.to_source()))
+            .call("barcode_kind", self.barcode_kind)
+            .call("bar_width", self.bar_width)
+            .call("bar_height", self.bar_height)
+            .call("horizontal_padding", self.horizontal_padding)
+            .call("vertical_padding", self.vertical_padding)
+            .call("label", &self.label)
+            .call("label_height", self.label_height)
+            .call("label_top_margin", self.label_top_margin)
+            .call("foreground_color", self.foreground_color)
+            .call("background_color", self.background_color);
+
+        Some(source.build())
+    }
 }
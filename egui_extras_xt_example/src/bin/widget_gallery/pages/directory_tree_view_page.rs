@@ -6,6 +6,7 @@ use egui_extras_xt::ui::optional_value_widget::OptionalValueWidget;
 
 use crate::pages::PageImpl;
 
+use crate::pages::source::{BuilderSource, ToSource};
 use crate::pages::ui::pathbuf_ui;
 
 pub struct DirectoryTreeViewPage {
@@ -72,4 +73,17 @@ impl PageImpl for DirectoryTreeViewPage {
                 ui.end_row();
             });
     }
+
+    fn source(&self) -> Option<String> {
+        let source = BuilderSource::new(&format!(
+            "DirectoryTreeViewWidget::new(&mut selected_path, &{})",
+            self.root_path.to_source()
+        ))
+        .call("force_selected_open", self.force_selected_open)
+        .call("hide_file_extensions", self.hide_file_extensions)
+        .call("file_selectable", self.file_selectable)
+        .call("directory_selectable", self.directory_selectable);
+
+        Some(source.build_with("ui.add_sized([300.0, 300.0],"))
+    }
 }
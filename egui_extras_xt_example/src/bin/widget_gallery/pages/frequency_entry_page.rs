@@ -0,0 +1,127 @@
+use std::ops::RangeInclusive;
+
+use eframe::egui::{DragValue, Grid, Ui};
+use egui_extras_xt::displays::{DisplayKind, DisplayStyle, DisplayStylePreset};
+use egui_extras_xt::knobs::FrequencyEntry;
+use egui_extras_xt::ui::drag_rangeinclusive::DragRangeInclusive;
+use egui_extras_xt::ui::widgets_from_iter::SelectableValueFromIter;
+use strum::IntoEnumIterator;
+
+use crate::pages::source::BuilderSource;
+use crate::pages::ui::display_style_ui;
+use crate::pages::PageImpl;
+
+pub struct FrequencyEntryPage {
+    active: f32,
+    standby: f32,
+    interactive: bool,
+    range: RangeInclusive<f32>,
+    channel_spacing: f32,
+    display_kind: DisplayKind,
+    digit_height: f32,
+    style: DisplayStyle,
+    style_preset: DisplayStylePreset,
+    knob_diameter: f32,
+    drag_step: f32,
+}
+
+impl Default for FrequencyEntryPage {
+    fn default() -> FrequencyEntryPage {
+        FrequencyEntryPage {
+            active: 121.5,
+            standby: 118.0,
+            interactive: true,
+            range: 118.0..=136.975,
+            channel_spacing: 0.025,
+            display_kind: DisplayKind::SevenSegment,
+            digit_height: 32.0,
+            style: DisplayStylePreset::Default.style(),
+            style_preset: DisplayStylePreset::Default,
+            knob_diameter: 48.0,
+            drag_step: 8.0,
+        }
+    }
+}
+
+impl PageImpl for FrequencyEntryPage {
+    fn ui(&mut self, ui: &mut Ui) {
+        ui.add(
+            FrequencyEntry::new(&mut self.active, &mut self.standby)
+                .interactive(self.interactive)
+                .range(self.range.clone())
+                .channel_spacing(self.channel_spacing)
+                .display_kind(self.display_kind)
+                .digit_height(self.digit_height)
+                .style(self.style)
+                .knob_diameter(self.knob_diameter)
+                .drag_step(self.drag_step),
+        );
+        ui.separator();
+
+        Grid::new("frequency_entry_properties")
+            .num_columns(2)
+            .spacing([20.0, 10.0])
+            .striped(true)
+            .show(ui, |ui| {
+                ui.label("Active");
+                ui.add(DragValue::new(&mut self.active).speed(0.025));
+                ui.end_row();
+
+                ui.label("Standby");
+                ui.add(DragValue::new(&mut self.standby).speed(0.025));
+                ui.end_row();
+
+                ui.label("Interactive");
+                ui.checkbox(&mut self.interactive, "");
+                ui.end_row();
+
+                ui.label("Range");
+                ui.drag_rangeinclusive(&mut self.range);
+                ui.end_row();
+
+                ui.label("Channel spacing");
+                ui.add(
+                    DragValue::new(&mut self.channel_spacing)
+                        .speed(0.001)
+                        .clamp_range(0.001..=1.0),
+                );
+                ui.end_row();
+
+                ui.label("Display kind");
+                ui.horizontal(|ui| {
+                    ui.selectable_value_from_iter(&mut self.display_kind, DisplayKind::iter());
+                });
+                ui.end_row();
+
+                ui.label("Digit height");
+                ui.add(DragValue::new(&mut self.digit_height));
+                ui.end_row();
+
+                ui.label("Style");
+                display_style_ui(ui, &mut self.style, &mut self.style_preset);
+                ui.end_row();
+
+                ui.label("Knob diameter");
+                ui.add(DragValue::new(&mut self.knob_diameter));
+                ui.end_row();
+
+                ui.label("Drag step");
+                ui.add(DragValue::new(&mut self.drag_step).clamp_range(1.0..=f32::MAX));
+                ui.end_row();
+            });
+    }
+
+    fn source(&self) -> Option<String> {
+        let source = BuilderSource::new("FrequencyEntry::new(&mut active, &mut standby)")
+            .call("interactive", self.interactive)
+            .call("range", self.range.clone())
+            .call("channel_spacing", self.channel_spacing)
+            .call("display_kind", self.display_kind)
+            .call("digit_height", self.digit_height)
+            .call("style", self.style)
+            .call("knob_diameter", self.knob_diameter)
+            .call("drag_step", self.drag_step);
+
+        Some(source.build())
+    }
+}
@@ -1,11 +1,15 @@
 use eframe::egui::{Grid, TextStyle, Ui};
-use egui_extras_xt::ui::hyperlink_with_icon::HyperlinkWithIcon;
+use egui_extras_xt::ui::hyperlink_with_icon::{
+    clear_hyperlink_icons, set_hyperlink_icons, HyperlinkWithIcon,
+};
 
+use crate::pages::source::ToSource;
 use crate::pages::PageImpl;
 
 pub struct HyperlinkWithIconPage {
     label: String,
     url: String,
+    custom_icons: bool,
 }
 
 impl Default for HyperlinkWithIconPage {
@@ -13,6 +17,7 @@ impl Default for HyperlinkWithIconPage {
         HyperlinkWithIconPage {
             label: "egui_extras_xt".to_owned(),
             url: "https://github.com/xTibor/egui_extras_xt".to_owned(),
+            custom_icons: false,
         }
     }
 }
@@ -37,6 +42,38 @@ impl PageImpl for HyperlinkWithIconPage {
                 ui.label("URL");
                 ui.text_edit_singleline(&mut self.url);
                 ui.end_row();
+
+                ui.label("Custom icons");
+                if ui.checkbox(&mut self.custom_icons, "").changed() {
+                    if self.custom_icons {
+                        set_hyperlink_icons(ui.ctx(), |url| {
+                            url.starts_with("https://github.com/xTibor/")
+                                .then_some('\u{2B50}')
+                        });
+                    } else {
+                        clear_hyperlink_icons(ui.ctx());
+                    }
+                }
+                ui.end_row();
             });
     }
+
+    fn source(&self) -> Option<String> {
+        let mut source = String::new();
+
+        if self.custom_icons {
+            source += "set_hyperlink_icons(ui.ctx(), |url| {\n";
+            source +=
+                "    url.starts_with(\"https://github.com/xTibor/\").then_some('\\u{2B50}')\n";
+            source += "});\n\n";
+        }
+
+        source += &format!(
+            "ui.hyperlink_with_icon_to({}, {});",
+            self.label.to_source(),
+            self.url.to_source()
+        );
+
+        Some(source)
+    }
 }
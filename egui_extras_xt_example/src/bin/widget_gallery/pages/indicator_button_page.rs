@@ -5,6 +5,7 @@ use egui_extras_xt::displays::{
 use egui_extras_xt::ui::widgets_from_iter::SelectableValueFromIter;
 use strum::IntoEnumIterator;
 
+use crate::pages::source::BuilderSource;
 use crate::pages::ui::display_style_ui;
 use crate::pages::PageImpl;
 
@@ -100,4 +101,18 @@ impl PageImpl for IndicatorButtonPage {
                 ui.end_row();
             });
     }
+
+    fn source(&self) -> Option<String> {
+        let source = BuilderSource::new("IndicatorButton::new(&mut value)")
+            .call("width", self.width)
+            .call("height", self.height)
+            .call("label", &self.label)
+            .call("style", self.style)
+            .call("animated", self.animated)
+            .call("interactive", self.interactive)
+            .call("margin", self.margin)
+            .call("behavior", self.behavior);
+
+        Some(source.build())
+    }
 }
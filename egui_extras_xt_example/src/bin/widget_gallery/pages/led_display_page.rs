@@ -4,6 +4,7 @@ use eframe::egui::{DragValue, Grid, Ui};
 use egui_extras_xt::displays::{DisplayStyle, DisplayStylePreset, LedDisplay};
 use egui_extras_xt::ui::drag_rangeinclusive::DragRangeInclusive;
 
+use crate::pages::source::{BuilderSource, ToSource};
 use crate::pages::ui::display_style_ui;
 use crate::pages::PageImpl;
 
@@ -73,4 +74,15 @@ impl PageImpl for LedDisplayPage {
                 ui.end_row();
             });
     }
+
+    fn source(&self) -> Option<String> {
+        let source = BuilderSource::new(&format!("LedDisplay::new({})", self.value.to_source()))
+            .call("diameter", self.diameter)
+            .call("padding", self.padding)
+            .call("range", self.range.clone())
+            .call("style", self.style)
+            .call("animated", self.animated);
+
+        Some(source.build())
+    }
 }
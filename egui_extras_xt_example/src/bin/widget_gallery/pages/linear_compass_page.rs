@@ -2,36 +2,56 @@ use eframe::egui::{DragValue, Grid, Ui};
 use eframe::epaint::Color32;
 use egui_extras_xt::common::{Winding, WrapMode};
 use egui_extras_xt::compasses::{
-    CompassAxisLabels, CompassMarker, CompassMarkerShape, DefaultCompassMarkerColor, LinearCompass,
+    CompassAxisLabels, CompassCursorMode, CompassMarker, CompassMarkerShape,
+    DefaultCompassMarkerColor, LinearCompass,
 };
 use egui_extras_xt::ui::optional_value_widget::OptionalValueWidget;
 use egui_extras_xt::ui::widgets_from_iter::SelectableValueFromIter;
+use itertools::Itertools;
 use strum::IntoEnumIterator;
 
+use crate::pages::source::{Angle, BuilderSource, ToSource, Verbatim};
 use crate::pages::ui::{
     compass_axis_labels_ui, default_compass_marker_color_ui, default_compass_marker_shape_ui,
+    easing_curve, tick_spec,
 };
 use crate::pages::PageImpl;
 
 pub struct LinearCompassPage {
     value: f32,
     interactive: bool,
+    long_press_duration: Option<f32>,
     wrap: WrapMode,
     winding: Winding,
+    mirrored: bool,
     width: f32,
     height: f32,
     spread: f32,
+    fixed_tape_start: Option<f32>,
+    drag_acceleration: Option<f32>,
+    click_to_set: bool,
     axis_labels: Vec<String>,
     snap: Option<f32>,
     shift_snap: Option<f32>,
     min: Option<f32>,
     max: Option<f32>,
     animated: bool,
+    animation_time: f32,
+    smooth_easing: bool,
     show_cursor: bool,
+    mils: bool,
     show_ticks: bool,
     show_axes: bool,
+    show_overflow_markers: bool,
+    hover_highlight: bool,
+    cluster_markers: Option<f32>,
+    target: Option<f32>,
+    target_interactive: bool,
     default_marker_color: DefaultCompassMarkerColor,
     default_marker_shape: CompassMarkerShape,
+    selection: Option<(f32, f32)>,
+    selected_markers: Vec<usize>,
+    clicked_marker: Option<usize>,
 }
 
 impl Default for LinearCompassPage {
@@ -39,11 +59,16 @@ impl Default for LinearCompassPage {
         LinearCompassPage {
             value: 0.0,
             interactive: true,
+            long_press_duration: Some(0.6),
             wrap: WrapMode::Unsigned,
             winding: Winding::Clockwise,
+            mirrored: false,
             width: 512.0,
             height: 48.0,
             spread: 180.0f32.to_radians(),
+            fixed_tape_start: None,
+            drag_acceleration: None,
+            click_to_set: true,
             axis_labels: vec![
                 "N".to_owned(),
                 "E".to_owned(),
@@ -51,113 +76,151 @@ impl Default for LinearCompassPage {
                 "W".to_owned(),
             ],
             snap: None,
-            shift_snap: Some(10.0f32.to_radians()),
+            shift_snap: Some(15.0f32.to_radians()),
             min: None,
             max: None,
             animated: false,
+            animation_time: 0.1,
+            smooth_easing: false,
             show_cursor: true,
+            mils: false,
             show_ticks: true,
             show_axes: true,
-            default_marker_color: DefaultCompassMarkerColor::HsvByAngle {
-                hue_phase: 0.0,
-                saturation: 1.0,
-                value: 1.0,
-            },
+            show_overflow_markers: true,
+            hover_highlight: true,
+            cluster_markers: None,
+            target: Some(45.0f32.to_radians()),
+            target_interactive: true,
+            default_marker_color: DefaultCompassMarkerColor::Palette,
             default_marker_shape: CompassMarkerShape::Square,
+            selection: None,
+            selected_markers: Vec::new(),
+            clicked_marker: None,
+        }
+    }
+}
+
+impl LinearCompassPage {
+    fn cursor_mode(&self) -> CompassCursorMode {
+        match self.fixed_tape_start {
+            Some(start) => CompassCursorMode::FixedTape { start },
+            None => CompassCursorMode::CenteredCursor,
         }
     }
 }
 
 impl PageImpl for LinearCompassPage {
     fn ui(&mut self, ui: &mut Ui) {
-        ui.add(
-            LinearCompass::new(&mut self.value)
-                .interactive(self.interactive)
-                .wrap(self.wrap)
-                .winding(self.winding)
-                .width(self.width)
-                .height(self.height)
-                .spread(self.spread)
-                .snap(self.snap)
-                .axis_labels(CompassAxisLabels::from_slice(&self.axis_labels))
-                .shift_snap(self.shift_snap)
-                .min(self.min)
-                .max(self.max)
-                .animated(self.animated)
-                .show_cursor(self.show_cursor)
-                .show_ticks(self.show_ticks)
-                .show_axes(self.show_axes)
-                .default_marker_color(self.default_marker_color)
-                .default_marker_shape(self.default_marker_shape)
-                .markers(&[
-                    CompassMarker::new(0.0f32.to_radians()).label("Default"),
-                    // Grand Theft Auto style markers
-                    CompassMarker::new(70.0f32.to_radians())
-                        .shape(CompassMarkerShape::Square)
-                        .label("Sweet")
-                        .color(Color32::from_rgb(0x00, 0x00, 0xFF)),
-                    CompassMarker::new(85.0f32.to_radians())
-                        .shape(CompassMarkerShape::DownArrow)
-                        .label("Reece's")
-                        .color(Color32::from_rgb(0xFF, 0xFF, 0x00)),
-                    CompassMarker::new(100.0f32.to_radians())
-                        .shape(CompassMarkerShape::UpArrow)
-                        .label("Big Smoke")
-                        .color(Color32::from_rgb(0xFF, 0x00, 0x00)),
-                    // Emoji markers
-                    CompassMarker::new(553.0f32.to_radians())
-                        .shape(CompassMarkerShape::Emoji('🐱'))
-                        .label("Cat")
-                        .color(Color32::from_rgb(0xF8, 0xE9, 0xFF)),
-                    CompassMarker::new(563.0f32.to_radians())
-                        .shape(CompassMarkerShape::Emoji('🐶'))
-                        .label("Dog")
-                        .color(Color32::from_rgb(0xC0, 0x8C, 0x85)),
-                    // All marker shapes
-                    CompassMarker::new(240.0f32.to_radians())
-                        .shape(CompassMarkerShape::Square)
-                        .label("A"),
-                    CompassMarker::new(250.0f32.to_radians())
-                        .shape(CompassMarkerShape::Circle)
-                        .label("B"),
-                    CompassMarker::new(260.0f32.to_radians())
-                        .shape(CompassMarkerShape::RightArrow)
-                        .label("C"),
-                    CompassMarker::new(270.0f32.to_radians())
-                        .shape(CompassMarkerShape::UpArrow)
-                        .label("D"),
-                    CompassMarker::new(280.0f32.to_radians())
-                        .shape(CompassMarkerShape::LeftArrow)
-                        .label("E"),
-                    CompassMarker::new(290.0f32.to_radians())
-                        .shape(CompassMarkerShape::DownArrow)
-                        .label("F"),
-                    CompassMarker::new(300.0f32.to_radians())
-                        .shape(CompassMarkerShape::Diamond)
-                        .label("G"),
-                    CompassMarker::new(310.0f32.to_radians())
-                        .shape(CompassMarkerShape::Star(5, 0.5))
-                        .label("H"),
-                    CompassMarker::new(320.0f32.to_radians())
-                        .shape(CompassMarkerShape::Emoji('🗿'))
-                        .label("I"),
-                    // Transparent colors
-                    CompassMarker::new(30.0f32.to_radians())
-                        .shape(CompassMarkerShape::Square)
-                        .label("Near")
-                        .color(Color32::from_rgb(0x40, 0x80, 0x80).linear_multiply(1.0)),
-                    CompassMarker::new(40.0f32.to_radians())
-                        .shape(CompassMarkerShape::Square)
-                        .label("Far")
-                        .color(Color32::from_rgb(0x40, 0x80, 0x80).linear_multiply(0.5)),
-                    CompassMarker::new(50.0f32.to_radians())
-                        .shape(CompassMarkerShape::Square)
-                        .label("Very far")
-                        .color(Color32::from_rgb(0x40, 0x80, 0x80).linear_multiply(0.25)),
-                ]),
-        );
+        let cursor_mode = self.cursor_mode();
+
+        let compass_response = LinearCompass::new(&mut self.value)
+            .interactive(self.interactive)
+            .long_press_duration(self.long_press_duration)
+            .wrap(self.wrap)
+            .winding(self.winding)
+            .mirrored(self.mirrored)
+            .width(self.width)
+            .height(self.height)
+            .spread(self.spread)
+            .cursor_mode(cursor_mode)
+            .drag_acceleration(self.drag_acceleration)
+            .click_to_set(self.click_to_set)
+            .snap(self.snap)
+            .axis_labels(CompassAxisLabels::from_slice(&self.axis_labels))
+            .shift_snap(self.shift_snap)
+            .min(self.min)
+            .max(self.max)
+            .animated(self.animated)
+            .animation_time(self.animation_time)
+            .easing(easing_curve(self.smooth_easing).0)
+            .show_cursor(self.show_cursor)
+            .ticks(tick_spec(self.mils).0)
+            .show_ticks(self.show_ticks)
+            .show_axes(self.show_axes)
+            .show_overflow_markers(self.show_overflow_markers)
+            .hover_highlight(self.hover_highlight)
+            .cluster_markers(self.cluster_markers)
+            .target(self.target)
+            .target_interactive(self.target_interactive)
+            .default_marker_color(self.default_marker_color)
+            .default_marker_shape(self.default_marker_shape)
+            .selection(Some(&mut self.selection))
+            .markers(&[
+                CompassMarker::new(0.0f32.to_radians()).label("Default"),
+                // Grand Theft Auto style markers
+                CompassMarker::new(70.0f32.to_radians())
+                    .shape(CompassMarkerShape::Square)
+                    .label("Sweet")
+                    .color(Color32::from_rgb(0x00, 0x00, 0xFF)),
+                CompassMarker::new(85.0f32.to_radians())
+                    .shape(CompassMarkerShape::DownArrow)
+                    .label("Reece's")
+                    .color(Color32::from_rgb(0xFF, 0xFF, 0x00)),
+                CompassMarker::new(100.0f32.to_radians())
+                    .shape(CompassMarkerShape::UpArrow)
+                    .label("Big Smoke")
+                    .color(Color32::from_rgb(0xFF, 0x00, 0x00)),
+                // Emoji markers
+                CompassMarker::new(553.0f32.to_radians())
+                    .shape(CompassMarkerShape::Emoji('🐱'))
+                    .label("Cat")
+                    .color(Color32::from_rgb(0xF8, 0xE9, 0xFF)),
+                CompassMarker::new(563.0f32.to_radians())
+                    .shape(CompassMarkerShape::Emoji('🐶'))
+                    .label("Dog")
+                    .color(Color32::from_rgb(0xC0, 0x8C, 0x85)),
+                // All marker shapes
+                CompassMarker::new(240.0f32.to_radians())
+                    .shape(CompassMarkerShape::Square)
+                    .label("A"),
+                CompassMarker::new(250.0f32.to_radians())
+                    .shape(CompassMarkerShape::Circle)
+                    .label("B"),
+                CompassMarker::new(260.0f32.to_radians())
+                    .shape(CompassMarkerShape::RightArrow)
+                    .label("C"),
+                CompassMarker::new(270.0f32.to_radians())
+                    .shape(CompassMarkerShape::UpArrow)
+                    .label("D"),
+                CompassMarker::new(280.0f32.to_radians())
+                    .shape(CompassMarkerShape::LeftArrow)
+                    .label("E"),
+                CompassMarker::new(290.0f32.to_radians())
+                    .shape(CompassMarkerShape::DownArrow)
+                    .label("F"),
+                CompassMarker::new(300.0f32.to_radians())
+                    .shape(CompassMarkerShape::Diamond)
+                    .label("G"),
+                CompassMarker::new(310.0f32.to_radians())
+                    .shape(CompassMarkerShape::Star(5, 0.5))
+                    .label("H"),
+                CompassMarker::new(320.0f32.to_radians())
+                    .shape(CompassMarkerShape::Emoji('🗿'))
+                    .label("I"),
+                // Transparent colors
+                CompassMarker::new(30.0f32.to_radians())
+                    .shape(CompassMarkerShape::Square)
+                    .label("Near")
+                    .color(Color32::from_rgb(0x40, 0x80, 0x80).linear_multiply(1.0)),
+                CompassMarker::new(40.0f32.to_radians())
+                    .shape(CompassMarkerShape::Square)
+                    .label("Far")
+                    .color(Color32::from_rgb(0x40, 0x80, 0x80).linear_multiply(0.5)),
+                CompassMarker::new(50.0f32.to_radians())
+                    .shape(CompassMarkerShape::Square)
+                    .label("Very far")
+                    .color(Color32::from_rgb(0x40, 0x80, 0x80).linear_multiply(0.25)),
+            ])
+            .show(ui);
         ui.separator();
 
+        self.selected_markers = compass_response.selected_markers;
+        self.target = compass_response.target;
+
+        if compass_response.response.clicked() {
+            self.clicked_marker = compass_response.clicked_marker;
+        }
+
         Grid::new("linear_compass_properties")
             .num_columns(2)
             .spacing([20.0, 10.0])
@@ -167,10 +230,35 @@ impl PageImpl for LinearCompassPage {
                 ui.drag_angle(&mut self.value);
                 ui.end_row();
 
+                ui.label("Selection");
+                ui.label(match self.selection {
+                    Some((start, end)) => format!(
+                        "{:.0}°..={:.0}° ({})",
+                        start.to_degrees(),
+                        end.to_degrees(),
+                        self.selected_markers.iter().join(", ")
+                    ),
+                    None => "Ctrl-drag to select".to_owned(),
+                });
+                ui.end_row();
+
+                ui.label("Clicked marker");
+                ui.label(match self.clicked_marker {
+                    Some(marker_index) => marker_index.to_string(),
+                    None => "None".to_owned(),
+                });
+                ui.end_row();
+
                 ui.label("Interactive");
                 ui.checkbox(&mut self.interactive, "");
                 ui.end_row();
 
+                ui.label("Long press duration");
+                ui.optional_value_widget(&mut self.long_press_duration, |ui, value| {
+                    ui.add(DragValue::new(value).speed(0.01).suffix(" s"))
+                });
+                ui.end_row();
+
                 ui.label("Wrap");
                 ui.horizontal(|ui| {
                     ui.selectable_value_from_iter(&mut self.wrap, WrapMode::iter());
@@ -183,6 +271,10 @@ impl PageImpl for LinearCompassPage {
                 });
                 ui.end_row();
 
+                ui.label("Mirrored");
+                ui.checkbox(&mut self.mirrored, "");
+                ui.end_row();
+
                 ui.label("Width");
                 ui.add(DragValue::new(&mut self.width));
                 ui.end_row();
@@ -195,6 +287,20 @@ impl PageImpl for LinearCompassPage {
                 ui.drag_angle(&mut self.spread);
                 ui.end_row();
 
+                ui.label("Fixed tape");
+                ui.optional_value_widget(&mut self.fixed_tape_start, Ui::drag_angle);
+                ui.end_row();
+
+                ui.label("Drag acceleration");
+                ui.optional_value_widget(&mut self.drag_acceleration, |ui, value| {
+                    ui.add(DragValue::new(value).speed(0.1))
+                });
+                ui.end_row();
+
+                ui.label("Click to set");
+                ui.checkbox(&mut self.click_to_set, "");
+                ui.end_row();
+
                 ui.label("Axis labels");
                 compass_axis_labels_ui(ui, &mut self.axis_labels);
                 ui.end_row();
@@ -219,10 +325,26 @@ impl PageImpl for LinearCompassPage {
                 ui.checkbox(&mut self.animated, "");
                 ui.end_row();
 
+                ui.label("Animation time");
+                ui.add(
+                    DragValue::new(&mut self.animation_time)
+                        .speed(0.01)
+                        .suffix(" s"),
+                );
+                ui.end_row();
+
+                ui.label("Smooth easing");
+                ui.checkbox(&mut self.smooth_easing, "");
+                ui.end_row();
+
                 ui.label("Show cursor");
                 ui.checkbox(&mut self.show_cursor, "");
                 ui.end_row();
 
+                ui.label("Mils");
+                ui.checkbox(&mut self.mils, "");
+                ui.end_row();
+
                 ui.label("Show ticks");
                 ui.checkbox(&mut self.show_ticks, "");
                 ui.end_row();
@@ -231,6 +353,28 @@ impl PageImpl for LinearCompassPage {
                 ui.checkbox(&mut self.show_axes, "");
                 ui.end_row();
 
+                ui.label("Show overflow markers");
+                ui.checkbox(&mut self.show_overflow_markers, "");
+                ui.end_row();
+
+                ui.label("Hover highlight");
+                ui.checkbox(&mut self.hover_highlight, "");
+                ui.end_row();
+
+                ui.label("Cluster markers");
+                ui.optional_value_widget(&mut self.cluster_markers, |ui, value| {
+                    ui.add(DragValue::new(value).suffix(" pt"))
+                });
+                ui.end_row();
+
+                ui.label("Target");
+                ui.optional_value_widget(&mut self.target, Ui::drag_angle);
+                ui.end_row();
+
+                ui.label("Target interactive");
+                ui.checkbox(&mut self.target_interactive, "");
+                ui.end_row();
+
                 ui.label("Default marker color");
                 default_compass_marker_color_ui(ui, &mut self.default_marker_color);
                 ui.end_row();
@@ -240,4 +384,44 @@ impl PageImpl for LinearCompassPage {
                 ui.end_row();
             });
     }
+
+    fn source(&self) -> Option<String> {
+        let axis_labels = format!("{}.into()", self.axis_labels.to_source());
+
+        let source = BuilderSource::new("LinearCompass::new(&mut value)")
+            .call("interactive", self.interactive)
+            .call("long_press_duration", self.long_press_duration)
+            .call("wrap", self.wrap)
+            .call("winding", self.winding)
+            .call("mirrored", self.mirrored)
+            .call("width", self.width)
+            .call("height", self.height)
+            .call("spread", Angle(self.spread))
+            .call("cursor_mode", self.cursor_mode())
+            .call("drag_acceleration", self.drag_acceleration)
+            .call("click_to_set", self.click_to_set)
+            .call("snap", self.snap.map(Angle))
+            .call("axis_labels", Verbatim(&axis_labels))
+            .call("shift_snap", self.shift_snap.map(Angle))
+            .call("min", self.min.map(Angle))
+            .call("max", self.max.map(Angle))
+            .call("animated", self.animated)
+            .call("animation_time", self.animation_time)
+            .call("easing", Verbatim(easing_curve(self.smooth_easing).1))
+            .call("show_cursor", self.show_cursor)
+            .call("ticks", Verbatim(tick_spec(self.mils).1))
+            .call("show_ticks", self.show_ticks)
+            .call("show_axes", self.show_axes)
+            .call("show_overflow_markers", self.show_overflow_markers)
+            .call("hover_highlight", self.hover_highlight)
+            .call("cluster_markers", self.cluster_markers)
+            .call("target", self.target.map(Angle))
+            .call("target_interactive", self.target_interactive)
+            .call("default_marker_color", self.default_marker_color)
+            .call("default_marker_shape", self.default_marker_shape)
+            .call("selection", Verbatim("Some(&mut selection)"))
+            .call("markers", Verbatim("&markers"));
+
+        Some(source.build())
+    }
 }
@@ -1,3 +1,4 @@
+mod source;
 mod ui;
 
 use eframe::egui::Ui;
@@ -20,6 +21,9 @@ use datamatrix_page::DataMatrixPage;
 mod directory_tree_view_page;
 use directory_tree_view_page::DirectoryTreeViewPage;
 
+mod frequency_entry_page;
+use frequency_entry_page::FrequencyEntryPage;
+
 mod hyperlink_with_icon_page;
 use hyperlink_with_icon_page::HyperlinkWithIconPage;
 
@@ -57,6 +61,11 @@ use welcome_page::WelcomePage;
 
 pub trait PageImpl {
     fn ui(&mut self, ui: &mut Ui);
+
+    /// Builder code of the widget as currently configured on the page.
+    fn source(&self) -> Option<String> {
+        None
+    }
 }
 
 #[allow(clippy::enum_variant_names)]
@@ -82,6 +91,10 @@ pub enum PageId {
     #[strum(props(feature = "filesystem"))]
     DirectoryTreeViewPage,
 
+    #[strum(to_string = "FrequencyEntry")]
+    #[strum(props(feature = "knobs"))]
+    FrequencyEntryPage,
+
     #[strum(to_string = "HyperlinkWithIcon")]
     #[strum(props(feature = "ui"))]
     HyperlinkWithIconPage,
@@ -134,6 +147,7 @@ impl PageId {
             PageId::BarcodePage => Box::<BarcodePage>::default(),
             PageId::DataMatrixPage => Box::<DataMatrixPage>::default(),
             PageId::DirectoryTreeViewPage => Box::<DirectoryTreeViewPage>::default(),
+            PageId::FrequencyEntryPage => Box::<FrequencyEntryPage>::default(),
             PageId::HyperlinkWithIconPage => Box::<HyperlinkWithIconPage>::default(),
             PageId::IndicatorButtonPage => Box::<IndicatorButtonPage>::default(),
             PageId::LedDisplayPage => Box::<LedDisplayPage>::default(),
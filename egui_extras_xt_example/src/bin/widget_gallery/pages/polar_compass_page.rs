@@ -2,16 +2,18 @@ use eframe::egui::{DragValue, Grid, Ui};
 use eframe::epaint::Color32;
 use egui_extras_xt::common::{Orientation, Winding, WrapMode};
 use egui_extras_xt::compasses::{
-    CompassAxisLabels, CompassMarker, CompassMarkerShape, DefaultCompassMarkerColor, PolarCompass,
-    PolarCompassOverflow,
+    CompassAxisLabels, CompassMarker, CompassMarkerShape, DefaultCompassMarkerColor, DistanceScale,
+    PolarCompass, PolarCompassLabelVisibility, PolarCompassOverflow,
 };
 use egui_extras_xt::ui::optional_value_widget::OptionalValueWidget;
 use egui_extras_xt::ui::widgets_from_iter::SelectableValueFromIter;
+use itertools::Itertools;
 use strum::IntoEnumIterator;
 
+use crate::pages::source::{Angle, BuilderSource, ToSource, Verbatim};
 use crate::pages::ui::{
     compass_axis_labels_ui, default_compass_marker_color_ui, default_compass_marker_shape_ui,
-    widget_orientation_ui,
+    easing_curve, widget_orientation_ui,
 };
 use crate::pages::PageImpl;
 
@@ -20,7 +22,11 @@ pub struct PolarCompassPage {
     interactive: bool,
     orientation: Orientation,
     winding: Winding,
+    mirrored: bool,
     overflow: PolarCompassOverflow,
+    heading: Option<f32>,
+    rotate_with_heading: bool,
+    rotate_markers: bool,
     diameter: f32,
     wrap: WrapMode,
     min: Option<f32>,
@@ -28,18 +34,27 @@ pub struct PolarCompassPage {
     snap: Option<f32>,
     shift_snap: Option<f32>,
     animated: bool,
+    animation_time: f32,
+    smooth_easing: bool,
     axis_labels: Vec<String>,
     axis_label_height: f32,
     max_distance: f32,
+    distance_scale: DistanceScale,
     scale_log_base: f32,
     scale_log_mult: f32,
     marker_near_size: f32,
     marker_far_size: f32,
     show_axes: bool,
     show_rings: bool,
+    ring_labels: bool,
+    ring_label_bearing: f32,
     show_cursor: bool,
-    show_marker_labels: bool,
+    show_marker_labels: PolarCompassLabelVisibility,
     show_marker_lines: bool,
+    show_marker_tooltips: bool,
+    sector_selection: bool,
+    selected_markers: Vec<usize>,
+    clicked_marker: Option<usize>,
     default_marker_color: DefaultCompassMarkerColor,
     default_marker_shape: CompassMarkerShape,
 }
@@ -51,13 +66,19 @@ impl Default for PolarCompassPage {
             interactive: true,
             orientation: Orientation::Top,
             winding: Winding::Clockwise,
+            mirrored: false,
             overflow: PolarCompassOverflow::Saturate,
+            heading: None,
+            rotate_with_heading: true,
+            rotate_markers: true,
             diameter: 256.0,
             wrap: WrapMode::Unsigned,
             min: None,
             max: None,
             snap: None,
             animated: false,
+            animation_time: 0.1,
+            smooth_easing: false,
             shift_snap: Some(15.0f32.to_radians()),
             axis_labels: vec![
                 "N".to_owned(),
@@ -67,20 +88,23 @@ impl Default for PolarCompassPage {
             ],
             axis_label_height: 24.0,
             max_distance: 10000.0,
+            distance_scale: DistanceScale::Logarithmic,
             scale_log_base: 10.0,
             scale_log_mult: 1.0,
             marker_near_size: 16.0,
             marker_far_size: 8.0,
             show_axes: true,
             show_rings: true,
+            ring_labels: true,
+            ring_label_bearing: 45.0f32.to_radians(),
             show_cursor: true,
-            show_marker_labels: true,
+            show_marker_labels: PolarCompassLabelVisibility::Always,
             show_marker_lines: true,
-            default_marker_color: DefaultCompassMarkerColor::HsvByAngle {
-                hue_phase: 0.0,
-                saturation: 1.0,
-                value: 1.0,
-            },
+            show_marker_tooltips: true,
+            sector_selection: false,
+            selected_markers: Vec::new(),
+            clicked_marker: None,
+            default_marker_color: DefaultCompassMarkerColor::Palette,
             default_marker_shape: CompassMarkerShape::Square,
         }
     }
@@ -88,89 +112,117 @@ impl Default for PolarCompassPage {
 
 impl PageImpl for PolarCompassPage {
     fn ui(&mut self, ui: &mut Ui) {
-        ui.add(
-            PolarCompass::new(&mut self.value)
-                .interactive(self.interactive)
-                .orientation(self.orientation)
-                .winding(self.winding)
-                .overflow(self.overflow)
-                .diameter(self.diameter)
-                .wrap(self.wrap)
-                .min(self.min)
-                .max(self.max)
-                .snap(self.snap)
-                .shift_snap(self.shift_snap)
-                .axis_labels(CompassAxisLabels::from_slice(&self.axis_labels))
-                .animated(self.animated)
-                .axis_label_height(self.axis_label_height)
-                .max_distance(self.max_distance)
-                .scale_log_base(self.scale_log_base)
-                .scale_log_mult(self.scale_log_mult)
-                .marker_near_size(self.marker_near_size)
-                .marker_far_size(self.marker_far_size)
-                .show_axes(self.show_axes)
-                .show_rings(self.show_rings)
-                .show_cursor(self.show_cursor)
-                .show_marker_labels(self.show_marker_labels)
-                .show_marker_lines(self.show_marker_lines)
-                .default_marker_color(self.default_marker_color)
-                .default_marker_shape(self.default_marker_shape)
-                .markers(&[
-                    CompassMarker::new(0.0f32.to_radians())
-                        .distance(10.0)
-                        .color(Color32::from_rgb(0xF0, 0xBF, 0x89))
-                        .shape(CompassMarkerShape::Diamond)
-                        .label("Haibara"),
-                    CompassMarker::new(15.0f32.to_radians())
-                        .distance(100.0)
-                        .color(Color32::from_rgb(0x9C, 0xCF, 0xEE))
-                        .shape(CompassMarkerShape::DownArrow)
-                        .label("Mitsuhiko"),
-                    CompassMarker::new(30.0f32.to_radians())
-                        .distance(1000.0)
-                        .color(Color32::from_rgb(0x8A, 0xDC, 0x71))
-                        .shape(CompassMarkerShape::Circle)
-                        .label("Genta"),
-                    CompassMarker::new(45.0f32.to_radians())
-                        .distance(10000.0)
-                        .color(Color32::from_rgb(0xEF, 0xBB, 0xC4))
-                        .shape(CompassMarkerShape::UpArrow)
-                        .label("Ayumi"),
-                    // Markers with unset colors and shapes
-                    CompassMarker::new(135.0f32.to_radians())
-                        .distance(100.0)
-                        .label("A"),
-                    CompassMarker::new(157.5f32.to_radians())
-                        .distance(200.0)
-                        .label("B"),
-                    CompassMarker::new(180.0f32.to_radians())
-                        .distance(300.0)
-                        .label("C"),
-                    CompassMarker::new(202.5f32.to_radians())
-                        .distance(400.0)
-                        .label("D"),
-                    CompassMarker::new(225.0f32.to_radians())
-                        .distance(500.0)
-                        .label("E"),
-                    CompassMarker::new(247.5f32.to_radians())
-                        .distance(600.0)
-                        .label("F"),
-                    CompassMarker::new(270.0f32.to_radians())
-                        .distance(700.0)
-                        .label("G"),
-                    CompassMarker::new(292.5f32.to_radians())
-                        .distance(800.0)
-                        .label("H"),
-                    CompassMarker::new(315.0f32.to_radians())
-                        .distance(900.0)
-                        .label("I"),
-                    CompassMarker::new(337.5f32.to_radians())
-                        .distance(1000.0)
-                        .label("J"),
-                ]),
-        );
+        let markers = [
+            CompassMarker::new(0.0f32.to_radians())
+                .distance(10.0)
+                .color(Color32::from_rgb(0xF0, 0xBF, 0x89))
+                .shape(CompassMarkerShape::Diamond)
+                .label("Haibara"),
+            CompassMarker::new(15.0f32.to_radians())
+                .distance(100.0)
+                .color(Color32::from_rgb(0x9C, 0xCF, 0xEE))
+                .shape(CompassMarkerShape::DownArrow)
+                .label("Mitsuhiko"),
+            CompassMarker::new(30.0f32.to_radians())
+                .distance(1000.0)
+                .color(Color32::from_rgb(0x8A, 0xDC, 0x71))
+                .shape(CompassMarkerShape::Circle)
+                .label("Genta"),
+            CompassMarker::new(45.0f32.to_radians())
+                .distance(10000.0)
+                .color(Color32::from_rgb(0xEF, 0xBB, 0xC4))
+                .shape(CompassMarkerShape::UpArrow)
+                .label("Ayumi"),
+            // Markers with unset colors and shapes
+            CompassMarker::new(135.0f32.to_radians())
+                .distance(100.0)
+                .label("A"),
+            CompassMarker::new(157.5f32.to_radians())
+                .distance(200.0)
+                .label("B"),
+            CompassMarker::new(180.0f32.to_radians())
+                .distance(300.0)
+                .label("C"),
+            CompassMarker::new(202.5f32.to_radians())
+                .distance(400.0)
+                .label("D"),
+            CompassMarker::new(225.0f32.to_radians())
+                .distance(500.0)
+                .label("E"),
+            CompassMarker::new(247.5f32.to_radians())
+                .distance(600.0)
+                .label("F"),
+            CompassMarker::new(270.0f32.to_radians())
+                .distance(700.0)
+                .label("G"),
+            CompassMarker::new(292.5f32.to_radians())
+                .distance(800.0)
+                .label("H"),
+            CompassMarker::new(315.0f32.to_radians())
+                .distance(900.0)
+                .label("I"),
+            CompassMarker::new(337.5f32.to_radians())
+                .distance(1000.0)
+                .label("J"),
+        ];
+
+        let mut polar_compass = PolarCompass::new(&mut self.value)
+            .interactive(self.interactive)
+            .orientation(self.orientation)
+            .winding(self.winding)
+            .mirrored(self.mirrored)
+            .overflow(self.overflow)
+            .rotate_with_heading(self.rotate_with_heading)
+            .rotate_markers(self.rotate_markers)
+            .diameter(self.diameter)
+            .wrap(self.wrap)
+            .min(self.min)
+            .max(self.max)
+            .snap(self.snap)
+            .shift_snap(self.shift_snap)
+            .axis_labels(CompassAxisLabels::from_slice(&self.axis_labels))
+            .animated(self.animated)
+            .animation_time(self.animation_time)
+            .easing(easing_curve(self.smooth_easing).0)
+            .axis_label_height(self.axis_label_height)
+            .max_distance(self.max_distance)
+            .distance_scale(self.distance_scale)
+            .scale_log_base(self.scale_log_base)
+            .scale_log_mult(self.scale_log_mult)
+            .marker_near_size(self.marker_near_size)
+            .marker_far_size(self.marker_far_size)
+            .show_axes(self.show_axes)
+            .show_rings(self.show_rings)
+            .show_cursor(self.show_cursor)
+            .show_marker_labels(self.show_marker_labels)
+            .show_marker_lines(self.show_marker_lines)
+            .show_marker_tooltips(self.show_marker_tooltips)
+            .sector_selection(self.sector_selection)
+            .default_marker_color(self.default_marker_color)
+            .default_marker_shape(self.default_marker_shape)
+            .markers(&markers);
+
+        if let Some(heading) = self.heading {
+            polar_compass = polar_compass.heading(heading);
+        }
+
+        if self.ring_labels {
+            polar_compass = polar_compass
+                .ring_labels(|_, distance| format!("{distance:.0} m"))
+                .ring_label_bearing(self.ring_label_bearing);
+        }
+
+        let compass_response = polar_compass.show(ui);
         ui.separator();
 
+        if let Some(selected_markers) = compass_response.selected_markers {
+            self.selected_markers = selected_markers;
+        }
+
+        if compass_response.response.clicked() {
+            self.clicked_marker = compass_response.clicked_marker;
+        }
+
         Grid::new("polar_compass_properties")
             .num_columns(2)
             .spacing([20.0, 10.0])
@@ -180,6 +232,13 @@ impl PageImpl for PolarCompassPage {
                 ui.drag_angle(&mut self.value);
                 ui.end_row();
 
+                ui.label("Clicked marker");
+                ui.label(match self.clicked_marker {
+                    Some(marker_index) => marker_index.to_string(),
+                    None => "None".to_owned(),
+                });
+                ui.end_row();
+
                 ui.label("Interactive");
                 ui.checkbox(&mut self.interactive, "");
                 ui.end_row();
@@ -194,12 +253,28 @@ impl PageImpl for PolarCompassPage {
                 });
                 ui.end_row();
 
+                ui.label("Mirrored");
+                ui.checkbox(&mut self.mirrored, "");
+                ui.end_row();
+
                 ui.label("Overflow");
                 ui.horizontal(|ui| {
                     ui.selectable_value_from_iter(&mut self.overflow, PolarCompassOverflow::iter());
                 });
                 ui.end_row();
 
+                ui.label("Heading");
+                ui.optional_value_widget(&mut self.heading, Ui::drag_angle);
+                ui.end_row();
+
+                ui.label("Rotate with heading");
+                ui.checkbox(&mut self.rotate_with_heading, "");
+                ui.end_row();
+
+                ui.label("Rotate markers");
+                ui.checkbox(&mut self.rotate_markers, "");
+                ui.end_row();
+
                 ui.label("Diameter");
                 ui.add(DragValue::new(&mut self.diameter));
                 ui.end_row();
@@ -230,6 +305,18 @@ impl PageImpl for PolarCompassPage {
                 ui.checkbox(&mut self.animated, "");
                 ui.end_row();
 
+                ui.label("Animation time");
+                ui.add(
+                    DragValue::new(&mut self.animation_time)
+                        .speed(0.01)
+                        .suffix(" s"),
+                );
+                ui.end_row();
+
+                ui.label("Smooth easing");
+                ui.checkbox(&mut self.smooth_easing, "");
+                ui.end_row();
+
                 ui.label("Axis labels");
                 compass_axis_labels_ui(ui, &mut self.axis_labels);
                 ui.end_row();
@@ -242,6 +329,12 @@ impl PageImpl for PolarCompassPage {
                 ui.add(DragValue::new(&mut self.max_distance));
                 ui.end_row();
 
+                ui.label("Distance scale");
+                ui.horizontal(|ui| {
+                    ui.selectable_value_from_iter(&mut self.distance_scale, DistanceScale::iter());
+                });
+                ui.end_row();
+
                 ui.label("Scale logarithm base");
                 ui.add(DragValue::new(&mut self.scale_log_base));
                 ui.end_row();
@@ -266,18 +359,44 @@ impl PageImpl for PolarCompassPage {
                 ui.checkbox(&mut self.show_rings, "");
                 ui.end_row();
 
+                ui.label("Ring labels");
+                ui.horizontal(|ui| {
+                    ui.checkbox(&mut self.ring_labels, "");
+                    ui.add_enabled_ui(self.ring_labels, |ui| {
+                        ui.drag_angle(&mut self.ring_label_bearing);
+                    });
+                });
+                ui.end_row();
+
                 ui.label("Show cursor");
                 ui.checkbox(&mut self.show_cursor, "");
                 ui.end_row();
 
                 ui.label("Show marker labels");
-                ui.checkbox(&mut self.show_marker_labels, "");
+                ui.horizontal(|ui| {
+                    ui.selectable_value_from_iter(
+                        &mut self.show_marker_labels,
+                        PolarCompassLabelVisibility::iter(),
+                    );
+                });
                 ui.end_row();
 
                 ui.label("Show marker lines");
                 ui.checkbox(&mut self.show_marker_lines, "");
                 ui.end_row();
 
+                ui.label("Show marker tooltips");
+                ui.checkbox(&mut self.show_marker_tooltips, "");
+                ui.end_row();
+
+                ui.label("Sector selection");
+                ui.checkbox(&mut self.sector_selection, "");
+                ui.end_row();
+
+                ui.label("Selected markers");
+                ui.label(self.selected_markers.iter().join(", "));
+                ui.end_row();
+
                 ui.label("Default marker color");
                 default_compass_marker_color_ui(ui, &mut self.default_marker_color);
                 ui.end_row();
@@ -287,4 +406,63 @@ impl PageImpl for PolarCompassPage {
                 ui.end_row();
             });
     }
+
+    fn source(&self) -> Option<String> {
+        let axis_labels = format!("{}.into()", self.axis_labels.to_source());
+
+        let mut source = BuilderSource::new("PolarCompass::new(&mut value)")
+            .call("interactive", self.interactive)
+            .call("orientation", self.orientation)
+            .call("winding", self.winding)
+            .call("mirrored", self.mirrored)
+            .call("overflow", self.overflow);
+
+        if let Some(heading) = self.heading {
+            source = source.call("heading", Angle(heading));
+        }
+
+        source = source
+            .call("rotate_with_heading", self.rotate_with_heading)
+            .call("rotate_markers", self.rotate_markers)
+            .call("diameter", self.diameter)
+            .call("wrap", self.wrap)
+            .call("min", self.min.map(Angle))
+            .call("max", self.max.map(Angle))
+            .call("snap", self.snap.map(Angle))
+            .call("shift_snap", self.shift_snap.map(Angle))
+            .call("axis_labels", Verbatim(&axis_labels))
+            .call("animated", self.animated)
+            .call("animation_time", self.animation_time)
+            .call("easing", Verbatim(easing_curve(self.smooth_easing).1))
+            .call("axis_label_height", self.axis_label_height)
+            .call("max_distance", self.max_distance)
+            .call("distance_scale", self.distance_scale)
+            .call("scale_log_base", self.scale_log_base)
+            .call("scale_log_mult", self.scale_log_mult)
+            .call("marker_near_size", self.marker_near_size)
+            .call("marker_far_size", self.marker_far_size)
+            .call("show_axes", self.show_axes)
+            .call("show_rings", self.show_rings);
+
+        if self.ring_labels {
+            source = source
+                .call(
+                    "ring_labels",
+                    Verbatim("|_, distance| format!(\"{distance:.0} m\")"),
+                )
+                .call("ring_label_bearing", Angle(self.ring_label_bearing));
+        }
+
+        source = source
+            .call("show_cursor", self.show_cursor)
+            .call("show_marker_labels", self.show_marker_labels)
+            .call("show_marker_lines", self.show_marker_lines)
+            .call("show_marker_tooltips", self.show_marker_tooltips)
+            .call("sector_selection", self.sector_selection)
+            .call("default_marker_color", self.default_marker_color)
+            .call("default_marker_shape", self.default_marker_shape)
+            .call("markers", Verbatim("&markers"));
+
+        Some(source.build())
+    }
 }
@@ -2,6 +2,7 @@ use eframe::egui::{DragValue, Grid, Ui};
 use eframe::epaint::Color32;
 use egui_extras_xt::barcodes::QrCodeWidget;
 
+use crate::pages::source::{BuilderSource, ToSource};
 use crate::pages::PageImpl;
 
 pub struct QrCodePage {
@@ -61,4 +62,14 @@ impl PageImpl for QrCodePage {
                 ui.end_row();
             });
     }
+
+    fn source(&self) -> Option<String> {
+        let source = BuilderSource::new(&format!("QrCodeWidget::new({})", self.value.to_source()))
+            .call("module_size", self.module_size)
+            .call("quiet_zone", self.quiet_zone)
+            .call("foreground_color", self.foreground_color)
+            .call("background_color", self.background_color);
+
+        Some(source.build())
+    }
 }
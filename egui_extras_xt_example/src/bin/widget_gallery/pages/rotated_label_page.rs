@@ -1,6 +1,7 @@
 use eframe::egui::{Grid, TextStyle, Ui};
 use egui_extras_xt::ui::rotated_label::RotatedLabel;
 
+use crate::pages::source::{Angle, BuilderSource, ToSource};
 use crate::pages::PageImpl;
 
 pub struct RotatedLabelPage {
@@ -39,4 +40,11 @@ impl PageImpl for RotatedLabelPage {
                 ui.end_row();
             });
     }
+
+    fn source(&self) -> Option<String> {
+        let source = BuilderSource::new(&format!("RotatedLabel::new({})", self.text.to_source()))
+            .call("angle", Angle(self.angle));
+
+        Some(source.build())
+    }
 }
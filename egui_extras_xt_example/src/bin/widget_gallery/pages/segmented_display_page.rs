@@ -1,11 +1,14 @@
-use eframe::egui::{DragValue, Grid, Ui};
+use eframe::egui::{Color32, DragValue, Grid, TextEdit, Ui};
 use egui_extras_xt::displays::segmented_display::DisplayMetricsPreset;
 use egui_extras_xt::displays::{
-    DisplayKind, DisplayMetrics, DisplayStyle, DisplayStylePreset, SegmentedDisplayWidget,
+    DisplayAlignment, DisplayKind, DisplayMetrics, DisplayStyle, DisplayStylePreset,
+    SegmentedDisplayWidget,
 };
+use egui_extras_xt::ui::optional_value_widget::OptionalValueWidget;
 use egui_extras_xt::ui::widgets_from_iter::SelectableValueFromIter;
 use strum::IntoEnumIterator;
 
+use crate::pages::source::{BuilderSource, ToSource, Verbatim};
 use crate::pages::ui::{display_metrics_ui, display_style_ui};
 use crate::pages::PageImpl;
 
@@ -17,9 +20,21 @@ pub struct SegmentedDisplayPage {
     metrics_preset: DisplayMetricsPreset,
     style: DisplayStyle,
     style_preset: DisplayStylePreset,
+    system_style: bool,
     show_dots: bool,
     show_colons: bool,
     show_apostrophes: bool,
+    glow: f32,
+    blink: bool,
+    blink_period: f32,
+    first_digit_color: Option<Color32>,
+    max_digits: Option<usize>,
+    alignment: DisplayAlignment,
+    overflow_character: Option<char>,
+    scroll_offset: f32,
+    marquee: bool,
+    marquee_speed: f32,
+    mirrored: bool,
 }
 
 impl Default for SegmentedDisplayPage {
@@ -32,23 +47,52 @@ impl Default for SegmentedDisplayPage {
             metrics_preset: DisplayMetricsPreset::Default,
             style: DisplayStylePreset::Default.style(),
             style_preset: DisplayStylePreset::Default,
+            system_style: false,
             show_dots: true,
             show_colons: true,
             show_apostrophes: true,
+            glow: 0.0,
+            blink: false,
+            blink_period: 1.0,
+            first_digit_color: None,
+            max_digits: None,
+            alignment: DisplayAlignment::Left,
+            overflow_character: Some('E'),
+            scroll_offset: 0.0,
+            marquee: false,
+            marquee_speed: 4.0,
+            mirrored: false,
         }
     }
 }
 
 impl PageImpl for SegmentedDisplayPage {
     fn ui(&mut self, ui: &mut Ui) {
+        let style = if self.system_style {
+            DisplayStyle::system_style(ui)
+        } else {
+            self.style
+        };
+
         ui.add(
             SegmentedDisplayWidget::new(self.display_kind)
                 .digit_height(self.digit_height)
                 .metrics(self.metrics)
-                .style(self.style)
+                .style(style)
                 .show_dots(self.show_dots)
                 .show_colons(self.show_colons)
                 .show_apostrophes(self.show_apostrophes)
+                .glow(self.glow)
+                .blink(&vec![self.blink; self.value.len()])
+                .blink_period(self.blink_period)
+                .digit_colors(&[self.first_digit_color])
+                .max_digits(self.max_digits)
+                .alignment(self.alignment)
+                .overflow_character(self.overflow_character)
+                .scroll_offset(self.scroll_offset)
+                .marquee(self.marquee)
+                .marquee_speed(self.marquee_speed)
+                .mirrored(self.mirrored)
                 .push_string(&self.value),
         );
         ui.separator();
@@ -80,6 +124,10 @@ impl PageImpl for SegmentedDisplayPage {
                 display_style_ui(ui, &mut self.style, &mut self.style_preset);
                 ui.end_row();
 
+                ui.label("System style");
+                ui.checkbox(&mut self.system_style, "");
+                ui.end_row();
+
                 ui.label("Show dots");
                 ui.checkbox(&mut self.show_dots, "");
                 ui.end_row();
@@ -91,6 +139,102 @@ impl PageImpl for SegmentedDisplayPage {
                 ui.label("Show apostrophes");
                 ui.checkbox(&mut self.show_apostrophes, "");
                 ui.end_row();
+
+                ui.label("Glow");
+                ui.add(
+                    DragValue::new(&mut self.glow)
+                        .speed(0.01)
+                        .clamp_range(0.0..=1.0),
+                );
+                ui.end_row();
+
+                ui.label("Blink");
+                ui.checkbox(&mut self.blink, "");
+                ui.end_row();
+
+                ui.label("Blink period");
+                ui.add(DragValue::new(&mut self.blink_period).clamp_range(0.1..=10.0));
+                ui.end_row();
+
+                ui.label("First digit color");
+                ui.optional_value_widget(&mut self.first_digit_color, |ui, value| {
+                    ui.color_edit_button_srgba(value)
+                });
+                ui.end_row();
+
+                ui.label("Maximum digits");
+                ui.optional_value_widget(&mut self.max_digits, |ui, value| {
+                    ui.add(DragValue::new(value))
+                });
+                ui.end_row();
+
+                ui.label("Alignment");
+                ui.horizontal(|ui| {
+                    ui.selectable_value_from_iter(&mut self.alignment, DisplayAlignment::iter());
+                });
+                ui.end_row();
+
+                ui.label("Overflow character");
+                ui.optional_value_widget(&mut self.overflow_character, |ui, value| {
+                    let mut text = value.to_string();
+                    let response = ui.add(TextEdit::singleline(&mut text).desired_width(20.0));
+                    if let Some(c) = text.chars().last() {
+                        *value = c;
+                    }
+                    response
+                });
+                ui.end_row();
+
+                ui.label("Scroll offset");
+                ui.add(DragValue::new(&mut self.scroll_offset).speed(0.1));
+                ui.end_row();
+
+                ui.label("Marquee");
+                ui.checkbox(&mut self.marquee, "");
+                ui.end_row();
+
+                ui.label("Marquee speed");
+                ui.add(DragValue::new(&mut self.marquee_speed).clamp_range(0.1..=100.0));
+                ui.end_row();
+
+                ui.label("Mirrored");
+                ui.checkbox(&mut self.mirrored, "");
+                ui.end_row();
             });
     }
+
+    fn source(&self) -> Option<String> {
+        let blink = format!("&[{}; {}]", self.blink, self.value.len());
+        let digit_colors = format!("&[{}]", self.first_digit_color.to_source());
+        let style = if self.system_style {
+            "DisplayStyle::system_style(ui)".to_owned()
+        } else {
+            self.style.to_source()
+        };
+
+        let source = BuilderSource::new(&format!(
+            "SegmentedDisplayWidget::new({})",
+            self.display_kind.to_source()
+        ))
+        .call("digit_height", self.digit_height)
+        .call("metrics", self.metrics)
+        .call("style", Verbatim(&style))
+        .call("show_dots", self.show_dots)
+        .call("show_colons", self.show_colons)
+        .call("show_apostrophes", self.show_apostrophes)
+        .call("glow", self.glow)
+        .call("blink", Verbatim(&blink))
+        .call("blink_period", self.blink_period)
+        .call("digit_colors", Verbatim(&digit_colors))
+        .call("max_digits", self.max_digits)
+        .call("alignment", self.alignment)
+        .call("overflow_character", self.overflow_character)
+        .call("scroll_offset", self.scroll_offset)
+        .call("marquee", self.marquee)
+        .call("marquee_speed", self.marquee_speed)
+        .call("mirrored", self.mirrored)
+        .call("push_string", &self.value);
+
+        Some(source.build())
+    }
 }
@@ -0,0 +1,342 @@
+use std::fmt::Debug;
+use std::ops::RangeInclusive;
+use std::path::PathBuf;
+
+use eframe::epaint::{Color32, Stroke};
+use egui_extras_xt::barcodes::BarcodeKind;
+use egui_extras_xt::common::{Orientation, WidgetShape, Winding, WrapMode};
+use egui_extras_xt::compasses::{
+    CompassCursorMode, CompassMarkerShape, DefaultCompassMarkerColor, DistanceScale,
+    PolarCompassLabelVisibility, PolarCompassOverflow,
+};
+use egui_extras_xt::displays::{
+    DisplayAlignment, DisplayKind, DisplayMetrics, DisplayStyle, IndicatorButtonBehavior,
+};
+use egui_extras_xt::knobs::{
+    DragAxis, KnobIndicator, KnobInteraction, Taper, ThumbstickDeadZone, ThumbstickSnap,
+};
+use egui_extras_xt::ui::standard_buttons::ButtonKind;
+use itertools::Itertools;
+
+// ----------------------------------------------------------------------------
+
+/// Rust expression evaluating to the value, as it would be passed to a builder method.
+pub trait ToSource {
+    fn to_source(&self) -> String;
+}
+
+impl ToSource for bool {
+    fn to_source(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl ToSource for usize {
+    fn to_source(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl ToSource for f32 {
+    fn to_source(&self) -> String {
+        format!("{self:?}")
+    }
+}
+
+impl ToSource for char {
+    fn to_source(&self) -> String {
+        format!("{self:?}")
+    }
+}
+
+impl ToSource for str {
+    fn to_source(&self) -> String {
+        format!("{self:?}")
+    }
+}
+
+impl ToSource for String {
+    fn to_source(&self) -> String {
+        self.as_str().to_source()
+    }
+}
+
+impl ToSource for PathBuf {
+    fn to_source(&self) -> String {
+        format!("PathBuf::from({:?})", self.display().to_string())
+    }
+}
+
+impl<T: ToSource> ToSource for Option<T> {
+    fn to_source(&self) -> String {
+        match self {
+            Some(value) => format!("Some({})", value.to_source()),
+            None => "None".to_owned(),
+        }
+    }
+}
+
+impl<T: ToSource> ToSource for RangeInclusive<T> {
+    fn to_source(&self) -> String {
+        format!("{}..={}", self.start().to_source(), self.end().to_source())
+    }
+}
+
+impl<T: ToSource> ToSource for [T] {
+    fn to_source(&self) -> String {
+        format!("[{}]", self.iter().map(T::to_source).join(", "))
+    }
+}
+
+impl<T: ToSource> ToSource for Vec<T> {
+    fn to_source(&self) -> String {
+        self.as_slice().to_source()
+    }
+}
+
+impl<T: ToSource + ?Sized> ToSource for &T {
+    fn to_source(&self) -> String {
+        (*self).to_source()
+    }
+}
+
+/// Angle stored in radians, written in degrees like the rest of the examples.
+pub struct Angle(pub f32);
+
+impl ToSource for Angle {
+    fn to_source(&self) -> String {
+        let degrees = (self.0.to_degrees() * 100.0).round() / 100.0;
+        format!("{degrees:?}f32.to_radians()")
+    }
+}
+
+/// Expression passed to the builder verbatim, e.g. a variable name.
+pub struct Verbatim<'a>(pub &'a str);
+
+impl ToSource for Verbatim<'_> {
+    fn to_source(&self) -> String {
+        self.0.to_owned()
+    }
+}
+
+impl ToSource for Color32 {
+    fn to_source(&self) -> String {
+        let [r, g, b, a] = self.to_array();
+        if *self == Color32::TRANSPARENT {
+            "Color32::TRANSPARENT".to_owned()
+        } else if a == 0xFF {
+            format!("Color32::from_rgb(0x{r:02X}, 0x{g:02X}, 0x{b:02X})")
+        } else {
+            format!("Color32::from_rgba_premultiplied(0x{r:02X}, 0x{g:02X}, 0x{b:02X}, 0x{a:02X})")
+        }
+    }
+}
+
+impl ToSource for Stroke {
+    fn to_source(&self) -> String {
+        if *self == Stroke::NONE {
+            return "Stroke::NONE".to_owned();
+        }
+
+        format!(
+            "Stroke::new({}, {})",
+            self.width.to_source(),
+            self.color.to_source()
+        )
+    }
+}
+
+// Enums whose `Debug` output is already valid Rust once prefixed with the type name.
+macro_rules! impl_to_source_with_debug {
+    ($($type:ident),* $(,)?) => {
+        $(
+            impl ToSource for $type {
+                fn to_source(&self) -> String {
+                    debug_to_source(stringify!($type), self)
+                }
+            }
+        )*
+    };
+}
+
+fn debug_to_source(type_name: &str, value: &impl Debug) -> String {
+    format!("{type_name}::{value:?}")
+}
+
+impl_to_source_with_debug!(
+    BarcodeKind,
+    ButtonKind,
+    CompassMarkerShape,
+    DisplayAlignment,
+    DisplayKind,
+    DistanceScale,
+    DragAxis,
+    KnobIndicator,
+    KnobInteraction,
+    Orientation,
+    PolarCompassLabelVisibility,
+    PolarCompassOverflow,
+    Taper,
+    ThumbstickDeadZone,
+    ThumbstickSnap,
+    Winding,
+    WrapMode,
+);
+
+impl ToSource for IndicatorButtonBehavior {
+    fn to_source(&self) -> String {
+        format!("IndicatorButtonBehavior::{self}")
+    }
+}
+
+impl ToSource for WidgetShape {
+    fn to_source(&self) -> String {
+        match self {
+            WidgetShape::Circle => "WidgetShape::Circle".to_owned(),
+            WidgetShape::Square => "WidgetShape::Square".to_owned(),
+            WidgetShape::Squircle(factor) => {
+                format!("WidgetShape::Squircle({})", factor.to_source())
+            }
+            WidgetShape::Polygon(n) => format!("WidgetShape::Polygon({})", n.to_source()),
+            WidgetShape::SuperPolygon(n, factor) => format!(
+                "WidgetShape::SuperPolygon({}, {})",
+                n.to_source(),
+                factor.to_source()
+            ),
+            WidgetShape::Rotated(shape, rotation) => format!(
+                "WidgetShape::Rotated(Box::new({}), {})",
+                shape.to_source(),
+                Angle(*rotation).to_source()
+            ),
+            WidgetShape::Scaled(shape, scale) => format!(
+                "WidgetShape::Scaled(Box::new({}), {})",
+                shape.to_source(),
+                scale.to_source()
+            ),
+            WidgetShape::Mix(a, b, t) => format!(
+                "WidgetShape::Mix(Box::new({}), Box::new({}), {})",
+                a.to_source(),
+                b.to_source(),
+                t.to_source()
+            ),
+            WidgetShape::Min(a, b) => format!(
+                "WidgetShape::Min(Box::new({}), Box::new({}))",
+                a.to_source(),
+                b.to_source()
+            ),
+            WidgetShape::Max(a, b) => format!(
+                "WidgetShape::Max(Box::new({}), Box::new({}))",
+                a.to_source(),
+                b.to_source()
+            ),
+            // Shapes unknown to the gallery fall back to the default shape
+            _ => format!("/* {self} */ WidgetShape::Circle"),
+        }
+    }
+}
+
+impl ToSource for DefaultCompassMarkerColor {
+    fn to_source(&self) -> String {
+        match self {
+            DefaultCompassMarkerColor::Fixed(color) => {
+                format!("DefaultCompassMarkerColor::Fixed({})", color.to_source())
+            }
+            _ => debug_to_source("DefaultCompassMarkerColor", self),
+        }
+    }
+}
+
+impl ToSource for CompassCursorMode {
+    fn to_source(&self) -> String {
+        match self {
+            CompassCursorMode::FixedTape { start } => format!(
+                "CompassCursorMode::FixedTape {{ start: {} }}",
+                Angle(*start).to_source()
+            ),
+            _ => debug_to_source("CompassCursorMode", self),
+        }
+    }
+}
+
+impl ToSource for DisplayStyle {
+    fn to_source(&self) -> String {
+        format!(
+            "DisplayStyle {{
+        background_color: {},
+        active_foreground_color: {},
+        active_foreground_stroke: {},
+        inactive_foreground_color: {},
+        inactive_foreground_stroke: {},
+        brightness: {},
+        flicker: {},
+    }}",
+            self.background_color.to_source(),
+            self.active_foreground_color.to_source(),
+            self.active_foreground_stroke.to_source(),
+            self.inactive_foreground_color.to_source(),
+            self.inactive_foreground_stroke.to_source(),
+            self.brightness.to_source(),
+            self.flicker.to_source(),
+        )
+    }
+}
+
+impl ToSource for DisplayMetrics {
+    fn to_source(&self) -> String {
+        format!(
+            "DisplayMetrics {{
+        segment_spacing: {},
+        segment_thickness: {},
+        digit_median: {},
+        digit_ratio: {},
+        digit_shearing: {},
+        digit_spacing: {},
+        margin_horizontal: {},
+        margin_vertical: {},
+        colon_separation: {},
+    }}",
+            self.segment_spacing.to_source(),
+            self.segment_thickness.to_source(),
+            self.digit_median.to_source(),
+            self.digit_ratio.to_source(),
+            self.digit_shearing.to_source(),
+            self.digit_spacing.to_source(),
+            self.margin_horizontal.to_source(),
+            self.margin_vertical.to_source(),
+            self.colon_separation.to_source(),
+        )
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// Collects the builder calls of a widget into the source code that recreates it.
+pub struct BuilderSource {
+    source: String,
+}
+
+impl BuilderSource {
+    pub fn new(constructor: &str) -> Self {
+        Self {
+            source: constructor.to_owned(),
+        }
+    }
+
+    pub fn call(mut self, method: &str, argument: impl ToSource) -> Self {
+        self.source += &format!("\n    .{method}({})", argument.to_source());
+        self
+    }
+
+    /// Source of the widget added with `ui.add()`.
+    pub fn build(self) -> String {
+        self.build_with("ui.add(")
+    }
+
+    /// Source of the widget added with `add_call`, e.g. `"ui.add_sized([64.0, 64.0],"`.
+    pub fn build_with(self, add_call: &str) -> String {
+        format!(
+            "{add_call}\n    {},\n);",
+            self.source.replace('\n', "\n    ")
+        )
+    }
+}
@@ -3,6 +3,7 @@ use egui_extras_xt::ui::standard_buttons::{ButtonKind, StandardButtons};
 use egui_extras_xt::ui::widgets_from_iter::ComboBoxFromIter;
 use strum::IntoEnumIterator;
 
+use crate::pages::source::ToSource;
 use crate::pages::PageImpl;
 
 pub struct StandardButtonsPage {
@@ -32,4 +33,11 @@ impl PageImpl for StandardButtonsPage {
                 ui.end_row();
             });
     }
+
+    fn source(&self) -> Option<String> {
+        Some(format!(
+            "ui.standard_button({});",
+            self.button_kind.to_source()
+        ))
+    }
 }
@@ -4,6 +4,7 @@ use eframe::egui::{DragValue, Grid, Ui};
 use egui_extras_xt::knobs::{ThumbstickDeadZone, ThumbstickSnap, ThumbstickWidget};
 use egui_extras_xt::ui::drag_rangeinclusive::DragRangeInclusive;
 
+use crate::pages::source::BuilderSource;
 use crate::pages::ui::{thumbstick_dead_zone_ui, thumbstick_snap_ui};
 use crate::pages::PageImpl;
 
@@ -110,4 +111,20 @@ impl PageImpl for ThumbstickWidgetPage {
                 ui.end_row();
             });
     }
+
+    fn source(&self) -> Option<String> {
+        let source = BuilderSource::new("ThumbstickWidget::new(&mut position)")
+            .call("range_x", self.range_x.clone())
+            .call("range_y", self.range_y.clone())
+            .call("precision", self.precision)
+            .call("interactive", self.interactive)
+            .call("diameter", self.diameter)
+            .call("animated", self.animated)
+            .call("auto_center", self.auto_center)
+            .call("show_axes", self.show_axes)
+            .call("snap", self.snap)
+            .call("dead_zone", self.dead_zone);
+
+        Some(source.build())
+    }
 }
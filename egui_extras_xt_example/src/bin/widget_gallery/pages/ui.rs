@@ -3,12 +3,14 @@ use std::str::FromStr;
 
 use eframe::egui::{DragValue, Grid, Response, TextEdit, Ui};
 use eframe::epaint::Color32;
+use itertools::Itertools;
 
 use egui_extras_xt::common::{Orientation, WidgetShape};
-use egui_extras_xt::compasses::{CompassMarkerShape, DefaultCompassMarkerColor};
+use egui_extras_xt::compasses::{CompassMarkerShape, DefaultCompassMarkerColor, TickSpec};
 use egui_extras_xt::displays::segmented_display::DisplayMetricsPreset;
 use egui_extras_xt::displays::{DisplayMetrics, DisplayStyle, DisplayStylePreset};
-use egui_extras_xt::knobs::{ThumbstickDeadZone, ThumbstickSnap};
+use egui_extras_xt::knobs::{Taper, ThumbstickDeadZone, ThumbstickSnap};
+use egui_extras_xt::ui::optional_value_widget::OptionalValueWidget;
 use egui_extras_xt::ui::standard_buttons::StandardButtons;
 use egui_extras_xt::ui::widgets_from_iter::ComboBoxFromIter;
 use egui_extras_xt::ui::widgets_from_slice::{ComboBoxFromSlice, SelectableValueFromSlice};
@@ -67,6 +69,20 @@ pub fn display_style_ui(
                 ui.add(DragValue::new(&mut style.active_foreground_stroke.width));
             });
             ui.end_row();
+
+            ui.label("Brightness");
+            ui.add(
+                DragValue::new(&mut style.brightness)
+                    .speed(0.01)
+                    .clamp_range(0.0..=1.0),
+            );
+            ui.end_row();
+
+            ui.label("Flicker");
+            ui.optional_value_widget(&mut style.flicker, |ui, value| {
+                ui.add(DragValue::new(value).speed(0.01).clamp_range(0.0..=1.0))
+            });
+            ui.end_row();
         });
 }
 
@@ -208,7 +224,9 @@ pub fn widget_shape_ui(ui: &mut Ui, value: &mut WidgetShape) {
                         ui.push_id("shape_b", |ui| widget_shape_ui(ui, shape_b));
                     });
                 }
-                _ => unimplemented!(),
+                _ => {
+                    ui.weak("No options");
+                }
             }
         });
     });
@@ -257,6 +275,7 @@ pub fn default_compass_marker_color_ui(ui: &mut Ui, value: &mut DefaultCompassMa
                 value,
                 &[
                     DefaultCompassMarkerColor::System,
+                    DefaultCompassMarkerColor::Palette,
                     DefaultCompassMarkerColor::Fixed(Color32::default()),
                     DefaultCompassMarkerColor::HsvByAngle {
                         hue_phase: 0.0,
@@ -275,6 +294,7 @@ pub fn default_compass_marker_color_ui(ui: &mut Ui, value: &mut DefaultCompassMa
         #[allow(clippy::match_same_arms)]
         match value {
             DefaultCompassMarkerColor::System => {}
+            DefaultCompassMarkerColor::Palette => {}
             DefaultCompassMarkerColor::Fixed(color) => {
                 ui.color_edit_button_srgba(color);
             }
@@ -296,7 +316,9 @@ pub fn default_compass_marker_color_ui(ui: &mut Ui, value: &mut DefaultCompassMa
                 ui.add(DragValue::new(saturation));
                 ui.add(DragValue::new(value));
             }
-            _ => unimplemented!(),
+            _ => {
+                ui.weak("No options");
+            }
         }
     });
 }
@@ -338,15 +360,43 @@ pub fn default_compass_marker_shape_ui(ui: &mut Ui, value: &mut CompassMarkerSha
             | CompassMarkerShape::LeftArrow
             | CompassMarkerShape::DownArrow
             | CompassMarkerShape::Diamond => {}
-            _ => unimplemented!(),
+            _ => {
+                ui.weak("No options");
+            }
         }
     });
 }
 
 pub fn compass_axis_labels_ui(ui: &mut Ui, axis_labels: &mut Vec<String>) {
     ui.horizontal_centered(|ui| {
+        let mut label_count = axis_labels.len();
+
+        ui.push_id("compass_axis_label_count_combo", |ui| {
+            ui.combobox_from_slice("", &mut label_count, &[4, 8, 16]);
+        });
+
+        if label_count != axis_labels.len() {
+            let cardinals = axis_labels.iter().step_by(axis_labels.len() / 4).cloned();
+
+            *axis_labels = match label_count {
+                4 => cardinals.collect(),
+                8 => cardinals
+                    .interleave(["NE", "SE", "SW", "NW"].map(String::from))
+                    .collect(),
+                16 => cardinals
+                    .interleave(["NE", "SE", "SW", "NW"].map(String::from))
+                    .interleave(
+                        ["NNE", "ENE", "ESE", "SSE", "SSW", "WSW", "WNW", "NNW"].map(String::from),
+                    )
+                    .collect(),
+                _ => unreachable!(),
+            };
+        }
+
+        let label_width = if axis_labels.len() > 8 { 30.0 } else { 50.0 };
+
         for axis_label in axis_labels {
-            ui.add(TextEdit::singleline(axis_label).desired_width(50.0));
+            ui.add(TextEdit::singleline(axis_label).desired_width(label_width));
         }
     });
 }
@@ -379,7 +429,9 @@ pub fn thumbstick_snap_ui(ui: &mut Ui, value: &mut ThumbstickSnap) {
                 ui.drag_angle(rotation);
                 ui.add(DragValue::new(threshold));
             }
-            _ => unimplemented!(),
+            _ => {
+                ui.weak("No options");
+            }
         }
     });
 }
@@ -402,7 +454,39 @@ pub fn thumbstick_dead_zone_ui(ui: &mut Ui, value: &mut ThumbstickDeadZone) {
             ThumbstickDeadZone::ScaledRadial { dead_zone } => {
                 ui.add(DragValue::new(dead_zone));
             }
-            _ => unimplemented!(),
+            _ => {
+                ui.weak("No options");
+            }
+        }
+    });
+}
+
+pub fn taper_ui(ui: &mut Ui, value: &mut Taper) {
+    ui.horizontal_centered(|ui| {
+        ui.push_id("taper_combo", |ui| {
+            ui.combobox_from_slice(
+                "",
+                value,
+                &[
+                    Taper::Linear,
+                    Taper::Logarithmic { base: 10.0 },
+                    Taper::Exponential { exponent: 2.0 },
+                ],
+            );
+        });
+
+        match value {
+            Taper::Linear => {}
+            Taper::Logarithmic { base } => {
+                ui.add(DragValue::new(base).clamp_range(1.01..=1000.0));
+            }
+            Taper::Exponential { exponent } => {
+                ui.add(
+                    DragValue::new(exponent)
+                        .speed(0.01)
+                        .clamp_range(0.01..=10.0),
+                );
+            }
         }
     });
 }
@@ -413,3 +497,40 @@ pub fn pathbuf_ui(ui: &mut Ui, value: &mut PathBuf) -> Response {
     *value = PathBuf::from_str(&tmp).unwrap_or_default();
     response
 }
+
+/// Easing curve offered on the pages of animated widgets, with its source.
+pub fn easing_curve(smooth_easing: bool) -> (fn(f32) -> f32, &'static str) {
+    if smooth_easing {
+        (|t| t * t * (3.0 - 2.0 * t), "|t| t * t * (3.0 - 2.0 * t)")
+    } else {
+        (|t| t, "|t| t")
+    }
+}
+
+/// Green to yellow to red gradient offered for level meter fills, with its source.
+pub fn level_meter_gradient() -> (fn(f32) -> Color32, &'static str) {
+    (
+        |t| {
+            Color32::from_rgb(
+                (510.0 * t).min(255.0) as u8,
+                (510.0 * (1.0 - t)).min(255.0) as u8,
+                0,
+            )
+        },
+        "|t| {
+        Color32::from_rgb(
+            (510.0 * t).min(255.0) as u8,
+            (510.0 * (1.0 - t)).min(255.0) as u8,
+            0,
+        )
+    }",
+    )
+}
+
+pub fn tick_spec(mils: bool) -> (TickSpec<'static>, &'static str) {
+    if mils {
+        (TickSpec::mils(), "TickSpec::mils()")
+    } else {
+        (TickSpec::degrees(), "TickSpec::degrees()")
+    }
+}